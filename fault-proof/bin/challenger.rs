@@ -1,29 +1,236 @@
-use std::{env, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
-use alloy_primitives::{Address, U256};
-use alloy_provider::{Provider, ProviderBuilder};
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{Address, TxHash, B256, I256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::{Filter, Log, TransactionRequest};
+use alloy_sol_types::SolEvent;
 use alloy_transport_http::reqwest::Url;
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
 use fault_proof::{
-    config::ChallengerConfig,
+    cache::CachingL2Provider,
+    config::{load_config_file, ChallengerConfig},
     contract::{
-        DisputeGameFactory::{self, DisputeGameFactoryInstance},
-        OPSuccinctFaultDisputeGame,
+        AccessManager::ProposerPermissionUpdated,
+        DisputeGameFactory::{self, DisputeGameCreated, DisputeGameFactoryInstance},
+        GameStatus,
+        OPSuccinctFaultDisputeGame::{self, Challenged, Proved},
+        ProposalStatus,
+    },
+    correctness::{ProposalCorrectnessMonitor, ResolutionDiscrepancy},
+    game_indices_between,
+    health::{init_health_server, HealthState},
+    notify::{NotificationEvent, WebhookNotifier},
+    output_root::L2OutputRootHasher,
+    prometheus::{record_gas_spent_wei, ChallengerGauge, ChallengerHistogram, TxOperation},
+    reorg::{confirmed_l1_block_number, L1ReorgDetector},
+    rpc_limit::{init_rpc_limits, RpcLimits},
+    utils::{
+        cancel_on_shutdown_signal, claimed_bond_wei, connect_provider, gas_cost_wei,
+        gas_price_paid_gwei, get_logs_paged, jittered_interval, log_dry_run_transaction,
+        send_with_retry, setup_logging, with_1559_fees,
     },
-    prometheus::ChallengerGauge,
-    utils::setup_logging,
-    Action, FactoryTrait, L1Provider, L2Provider, Mode,
+    Action, FactoryTrait, L1Provider, L2Provider, L2ProviderTrait, Mode, ProposerPriority,
+};
+use futures::StreamExt;
+use op_succinct_host_utils::metrics::{
+    init_metrics_with_histograms, MetricsGauge, MetricsHistogram,
 };
-use op_succinct_host_utils::metrics::{init_metrics, MetricsGauge};
 use op_succinct_signer_utils::Signer;
-use rand::Rng;
-use tokio::time;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tokio::{sync::mpsc, time};
+use tokio_util::sync::CancellationToken;
+
+/// The number of L1 blocks behind the tip that are tracked for reorg detection.
+const L1_REORG_DETECTION_DEPTH: u64 = 32;
+
+/// The interval in seconds between checks for proofs submitted to challenged games.
+const PROOF_WATCH_INTERVAL_SECS: u64 = 12;
+
+/// The number of L1 blocks scanned for games challenged by this challenger when watching for
+/// proofs. Roughly one week of L1 blocks, which covers the challenge and prove windows.
+const CHALLENGED_GAMES_LOOKBACK_L1_BLOCKS: u64 = 50_400;
+
+/// The fraction of a proposer's games with an invalid output root above which it is reported as
+/// suspicious.
+const SUSPICIOUS_INVALID_PROPOSAL_RATIO: f64 = 0.5;
+
+/// The smoothing factor of the first response time moving average.
+const FIRST_RESPONSE_TIME_EWMA_ALPHA: f64 = 0.2;
+
+/// The gas used to challenge, resolve and claim the bonds of a single game.
+const ESTIMATED_GAS_PER_CHALLENGE: u64 = 250_000;
+
+/// The seed of the challenge season simulation, so that its results are reproducible.
+const SIMULATION_SEED: u64 = 42;
+
+/// The delay in seconds before resubscribing to game creation events after the subscription
+/// failed or dropped.
+const RESUBSCRIBE_DELAY_SECS: u64 = 5;
 
 #[derive(Parser)]
 struct Args {
     #[arg(long, default_value = ".env.challenger")]
     env_file: String,
+
+    /// A TOML config file. Environment variables, including those of the env file,
+    /// take precedence over its settings.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Simulate the financial outcome of challenging a season of proposals.
+    SimulateSeason {
+        /// The number of proposals created during the season.
+        #[arg(long)]
+        num_proposals: u32,
+        /// The fraction (0.0-1.0) of proposals with an invalid output root.
+        #[arg(long)]
+        fraction_invalid: f64,
+        /// The average L1 gas price, in gwei.
+        #[arg(long)]
+        avg_l1_gas_gwei: f64,
+        /// The fraction (0.0-1.0) of challenged proposals that are nevertheless proven.
+        #[arg(long)]
+        prove_success_rate: f64,
+        /// The bond posted by the proposer of a game, in wei.
+        #[arg(long)]
+        init_bond_wei: U256,
+        /// The bond posted by the challenger of a game, in wei.
+        #[arg(long)]
+        challenger_bond_wei: U256,
+    },
+    /// Challenge a single game and exit.
+    Challenge {
+        /// The index of the game in the factory.
+        #[arg(long)]
+        game_index: U256,
+        /// Challenge the game even if its claim matches the computed output root.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// The scenario of a challenge season simulation.
+#[derive(Debug, Clone, Copy)]
+struct SimulationParams {
+    num_proposals: u32,
+    fraction_invalid: f64,
+    avg_l1_gas_gwei: f64,
+    prove_success_rate: f64,
+    init_bond_wei: U256,
+    challenger_bond_wei: U256,
+}
+
+/// The expected financial outcome of a challenge season.
+#[derive(Debug)]
+struct SimulationReport {
+    expected_challenges: u32,
+    expected_wins: u32,
+    expected_losses: u32,
+    /// The bonds won minus the bonds lost and the gas spent, in wei.
+    expected_pnl_wei: I256,
+    /// The profit as a percentage of the challenger bonds put at stake.
+    roi_pct: f64,
+}
+
+/// The impact of an L1 reorg on the in-progress games the challenger is watching.
+#[derive(Debug, Default)]
+struct ReorgImpactReport {
+    /// Games whose L1 head was part of the reorged blocks.
+    affected_games: Vec<Address>,
+    /// Unchallenged affected games whose claim no longer matches the computed output root.
+    newly_invalid: Vec<Address>,
+    /// Challenged affected games whose claim now matches the computed output root.
+    newly_valid: Vec<Address>,
+}
+
+/// The changes to the proposer whitelist observed during a single sync.
+#[derive(Debug, Default)]
+struct WhitelistSyncResult {
+    /// Proposers that were whitelisted since the previous sync.
+    added: Vec<Address>,
+    /// Proposers that were removed from the whitelist since the previous sync.
+    removed: Vec<Address>,
+    /// All currently whitelisted proposers.
+    current: Vec<Address>,
+}
+
+/// A summary of what a single challenger tick did.
+#[derive(Debug, Default)]
+struct TickReport {
+    /// The number of expired challenged games resolved by the periodic sweep.
+    expired_games_resolved: u32,
+    /// Whether at least one game was challenged.
+    challenged: bool,
+    /// The number of games resolved.
+    games_resolved: u32,
+    /// Whether a bond was claimed.
+    bond_claimed: bool,
+}
+
+/// Proposers that systematically submit invalid output roots.
+#[derive(Debug, Default)]
+struct CollusionReport {
+    /// Suspicious proposers and the fraction of their games with an invalid output root.
+    suspicious_proposers: Vec<(Address, f64)>,
+    total_invalid_proposals: u32,
+}
+
+/// Tracks how long it takes the challenger to first check a game after it appears on-chain.
+#[derive(Debug, Default)]
+struct FirstResponseTracker {
+    /// The time each pending game was first seen in the scan window.
+    first_seen_at: HashMap<Address, Instant>,
+    /// The latest game index that has been observed.
+    last_seen_game_index: Option<U256>,
+    /// The moving average of the first response time, in seconds.
+    avg_first_response_time_secs: Option<f64>,
+    /// The maximum observed first response time, in seconds.
+    max_first_response_time_secs: f64,
+}
+
+impl FirstResponseTracker {
+    /// Records the first response time for a pending game, if it is still pending.
+    fn record(&mut self, game_address: Address) {
+        let Some(first_seen_at) = self.first_seen_at.remove(&game_address) else {
+            return;
+        };
+        let elapsed_secs = first_seen_at.elapsed().as_secs_f64();
+
+        let avg = match self.avg_first_response_time_secs {
+            Some(avg) => {
+                FIRST_RESPONSE_TIME_EWMA_ALPHA * elapsed_secs +
+                    (1.0 - FIRST_RESPONSE_TIME_EWMA_ALPHA) * avg
+            }
+            None => elapsed_secs,
+        };
+        self.avg_first_response_time_secs = Some(avg);
+        self.max_first_response_time_secs = self.max_first_response_time_secs.max(elapsed_secs);
+
+        ChallengerGauge::AvgFirstResponseTimeSecs.set(avg);
+        ChallengerGauge::MaxFirstResponseTimeSecs.set(self.max_first_response_time_secs);
+    }
+
+    /// Records the first response time for all pending games.
+    fn record_all(&mut self) {
+        let pending: Vec<Address> = self.first_seen_at.keys().copied().collect();
+        for game_address in pending {
+            self.record(game_address);
+        }
+    }
 }
 
 struct OPSuccinctChallenger<P>
@@ -35,8 +242,28 @@ where
     signer: Signer,
     l1_provider: L1Provider,
     l2_provider: L2Provider,
+    caching_l2_provider: CachingL2Provider,
+    secondary_l2_provider: Option<L2Provider>,
     factory: DisputeGameFactoryInstance<P>,
+    init_bond: U256,
     challenger_bond: U256,
+    l1_reorg_detector: L1ReorgDetector,
+    proposer_whitelist: HashSet<Address>,
+    whitelist_synced_block: Option<u64>,
+    first_response_tracker: Mutex<FirstResponseTracker>,
+    proof_watch_synced_block: Option<u64>,
+    /// The lowest index of a game still in progress at the last sweep, where the next sweep
+    /// starts. All earlier games are resolved.
+    sweep_start_index: U256,
+    correctness_monitor: ProposalCorrectnessMonitor,
+    ticks: u64,
+    /// The liveness and readiness reported by the health endpoints.
+    health: Arc<HealthState>,
+    /// The seeded RNG deciding which valid games to challenge maliciously, if a seed is
+    /// configured. Otherwise the thread RNG is used.
+    malicious_challenge_rng: Option<Mutex<StdRng>>,
+    /// Posts notifications of key events to the configured webhook, if any.
+    notifier: Option<WebhookNotifier>,
 }
 
 impl<P> OPSuccinctChallenger<P>
@@ -52,36 +279,251 @@ where
         factory: DisputeGameFactoryInstance<P>,
     ) -> Result<Self> {
         let config = ChallengerConfig::from_env()?;
+        let l2_provider = L2Provider::new(
+            connect_provider(&config.l2_rpc).await?,
+            config.l2_to_l1_message_passer,
+            Mode::Challenger,
+            *factory.address(),
+        );
+        config.validate(&l1_provider, &l2_provider, &factory, signer.address()).await?;
+        let l2_node_provider = match config.l2_node_rpc.as_ref() {
+            Some(url) if config.prefer_output_at_block_rpc => Some(connect_provider(url).await?),
+            _ => None,
+        };
+        let secondary_l2_provider = match config.secondary_l2_rpc.as_ref() {
+            Some(url) => Some(L2Provider::new(
+                connect_provider(url).await?,
+                config.l2_to_l1_message_passer,
+                Mode::Challenger,
+                *factory.address(),
+            )),
+            None => None,
+        };
 
         Ok(Self {
             config: config.clone(),
             challenger_address,
             signer,
             l1_provider: l1_provider.clone(),
-            l2_provider: ProviderBuilder::default().connect_http(config.l2_rpc.clone()),
+            l2_provider: l2_provider.clone(),
+            caching_l2_provider: CachingL2Provider::new(
+                l2_provider,
+                l2_node_provider,
+                Mode::Challenger,
+                *factory.address(),
+                config.output_root_cache_size,
+            )?,
+            secondary_l2_provider,
             factory: factory.clone(),
+            init_bond: factory.fetch_init_bond(config.game_type).await?,
             challenger_bond: factory.fetch_challenger_bond(config.game_type).await?,
+            l1_reorg_detector: L1ReorgDetector::new(L1_REORG_DETECTION_DEPTH),
+            proposer_whitelist: HashSet::new(),
+            whitelist_synced_block: None,
+            first_response_tracker: Mutex::new(FirstResponseTracker::default()),
+            proof_watch_synced_block: None,
+            sweep_start_index: U256::ZERO,
+            correctness_monitor: ProposalCorrectnessMonitor::default(),
+            ticks: 0,
+            health: Arc::new(HealthState::new(Duration::from_secs(config.health_staleness_secs))),
+            malicious_challenge_rng: config
+                .malicious_challenge_seed
+                .map(|seed| Mutex::new(StdRng::seed_from_u64(seed))),
+            notifier: config
+                .notification_webhook_url
+                .clone()
+                .map(|url| WebhookNotifier::spawn(url, config.notification_events.clone())),
         })
     }
 
+    /// Posts a notification of the event to the webhook, if enabled.
+    fn notify(&self, event: NotificationEvent, message: String) {
+        if let Some(notifier) = &self.notifier {
+            notifier.notify(event, message);
+        }
+    }
+
     /// Challenges a specific game at the given address.
-    async fn challenge_game(&self, game_address: Address) -> Result<()> {
+    ///
+    /// Returns the hash of the challenge transaction. In dry run mode, the transaction is only
+    /// logged and `None` is returned.
+    async fn challenge_game(&self, game_address: Address) -> Result<Option<TxHash>> {
         let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
 
         let transaction_request =
             game.challenge().value(self.challenger_bond).into_transaction_request();
+        if self.config.dry_run {
+            log_dry_run_transaction("challenge", &transaction_request);
+            return Ok(None);
+        }
 
-        let receipt = self
-            .signer
-            .send_transaction_request(self.config.l1_rpc.clone(), transaction_request)
-            .await?;
+        let transaction_request = with_1559_fees(
+            &self.l1_provider,
+            transaction_request,
+            self.config.l1_fee_percentile,
+            self.config.max_fee_per_gas_gwei,
+        )
+        .await?;
+        let receipt = send_with_retry(
+            &self.signer,
+            self.config.l1_rpc.clone(),
+            transaction_request,
+            &self.config.tx_retry_policy,
+            Mode::Challenger,
+            *self.factory.address(),
+            TxOperation::Challenge,
+        )
+        .await?;
+        ChallengerHistogram::L1GasPricePaidGwei.record(gas_price_paid_gwei(&receipt));
+        record_gas_spent_wei(
+            ChallengerGauge::TotalGasSpentWei,
+            TxOperation::Challenge,
+            gas_cost_wei(&receipt),
+        );
 
         tracing::info!(
             "Successfully challenged game {:?} with tx {:?}",
             game_address,
             receipt.transaction_hash
         );
+        self.notify(
+            NotificationEvent::GameChallenged,
+            format!(
+                "Challenger challenged game {game_address} with tx {}",
+                receipt.transaction_hash
+            ),
+        );
+        self.first_response_tracker.lock().unwrap().record(game_address);
+        ChallengerGauge::GamesChallenged.increment(1.0);
+
+        Ok(Some(receipt.transaction_hash))
+    }
+
+    /// Challenges the game at `game_index` once, outside of the challenge loop.
+    ///
+    /// The game must be unchallenged, and its claim must differ from the computed output root
+    /// unless `force` is set. Returns the hash of the challenge transaction, if one was sent, and
+    /// the resulting status of the game.
+    async fn challenge_game_at_index(
+        &self,
+        game_index: U256,
+        force: bool,
+    ) -> Result<(Option<TxHash>, ProposalStatus)> {
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            bail!("No games exist");
+        };
+        if game_index > latest_game_index {
+            bail!(
+                "Game index {} does not exist, the latest game index is {}",
+                game_index,
+                latest_game_index
+            );
+        }
 
+        let game_address = self.factory.fetch_game_address_by_index(game_index).await?;
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        let claim_data = game.claimData().call().await?;
+        if claim_data.status != ProposalStatus::Unchallenged {
+            bail!(
+                "Game {:?} cannot be challenged, its status is {:?}",
+                game_address,
+                claim_data.status
+            );
+        }
+
+        let l2_block_number = game.l2BlockNumber().call().await?;
+        let game_claim = game.rootClaim().call().await?;
+        let output_root = self
+            .l2_provider
+            .compute_output_root_at_block_timed(
+                l2_block_number,
+                Mode::Challenger,
+                *self.factory.address(),
+            )
+            .await?;
+        if output_root == game_claim {
+            if !force {
+                bail!(
+                    "Game {:?} claims the correct output root {:?}, use --force to challenge it anyway",
+                    game_address,
+                    output_root
+                );
+            }
+            tracing::warn!("Challenging game {:?} with a correct output root", game_address);
+        }
+
+        let tx_hash = self.challenge_game(game_address).await?;
+        let status = game.claimData().call().await?.status;
+        Ok((tx_hash, status))
+    }
+
+    /// Confirms with the secondary L2 RPC, if configured, that the game's claim is invalid.
+    ///
+    /// Returns `false` if the secondary RPC computes the claimed output root, so that a single
+    /// L2 node returning bad data cannot cause a valid game to be challenged.
+    async fn cross_validate_with_rpc_fallback(&self, game_address: Address) -> Result<bool> {
+        let Some(secondary_l2_provider) = &self.secondary_l2_provider else {
+            return Ok(true);
+        };
+
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        let l2_block_number = game.l2BlockNumber().call().await?;
+        let game_claim = game.rootClaim().call().await?;
+        let secondary_output_root =
+            secondary_l2_provider.compute_output_root_at_block(l2_block_number).await?;
+        if secondary_output_root == game_claim {
+            let primary_output_root =
+                self.l2_provider.compute_output_root_at_block(l2_block_number).await?;
+            tracing::error!(
+                "L2 RPCs disagree on game {:?} at L2 block {:?}: primary output root {:?}, secondary output root {:?}, claim {:?}",
+                game_address,
+                l2_block_number,
+                primary_output_root,
+                secondary_output_root,
+                game_claim
+            );
+            ChallengerGauge::RpcDisagreementsDetected.increment(1.0);
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Logs the L2 state root, message passer storage root and block hash the local output root of
+    /// an invalid game is computed from, alongside the game's claim, if `verbose_diagnostics` is
+    /// enabled.
+    ///
+    /// Comparing the components against another L2 node tells a malicious proposer apart from an
+    /// L2 node that diverged.
+    async fn log_output_root_diagnostics(&self, game_address: Address) -> Result<()> {
+        if !self.config.verbose_diagnostics {
+            return Ok(());
+        }
+
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        let l2_block_number = game.l2BlockNumber().call().await?;
+        let game_claim = game.rootClaim().call().await?;
+        let block_number = BlockNumberOrTag::Number(l2_block_number.to::<u64>());
+        let l2_block = self.l2_provider.get_l2_block_by_number(block_number).await?;
+        let l2_storage_root = self
+            .l2_provider
+            .get_l2_storage_root(self.config.l2_to_l1_message_passer, block_number)
+            .await?;
+        let output_root = L2OutputRootHasher::hash(
+            l2_block.header.state_root,
+            l2_storage_root,
+            l2_block.header.hash,
+        );
+        tracing::warn!(
+            "Output root mismatch for game {:?} at L2 block {}: state root {:?}, message passer storage root {:?}, block hash {:?}, computed output root {:?}, claim {:?}",
+            game_address,
+            l2_block_number,
+            l2_block.header.state_root,
+            l2_storage_root,
+            l2_block.header.hash,
+            output_root,
+            game_claim
+        );
         Ok(())
     }
 
@@ -93,36 +535,131 @@ where
 
         self.factory
             .get_oldest_game_address(
+                Mode::Challenger,
                 self.config.max_games_to_check_for_challenge,
-                self.l2_provider.clone(),
+                self.config.game_fetch_batch_size,
+                self.config.scan_concurrency,
+                self.caching_l2_provider.clone(),
                 |status| status == ProposalStatus::Unchallenged,
                 |output_root, game_claim| output_root == game_claim, /* Valid games (opposite of
                                                                       * honest challenger) */
+                |_| Some(0),
                 "Oldest valid game for malicious challenge",
             )
             .await
     }
 
+    /// Whether the expected net reward of a challenge at the current L1 gas price reaches
+    /// `min_net_reward_wei`. Always true if no minimum is configured.
+    ///
+    /// The net reward is the proposer's bond forfeited to the challenger, minus the challenger bond
+    /// at risk and the estimated gas of the challenge.
+    async fn is_challenge_profitable(&self) -> Result<bool> {
+        let Some(min_net_reward_wei) = self.config.min_net_reward_wei else {
+            return Ok(true);
+        };
+
+        let gas_price = self.l1_provider.get_gas_price().await?;
+        let gas_cost_wei = U256::from(ESTIMATED_GAS_PER_CHALLENGE) * U256::from(gas_price);
+        let net_reward_wei = I256::from_raw(self.init_bond) -
+            I256::from_raw(self.challenger_bond) -
+            I256::from_raw(gas_cost_wei);
+        tracing::debug!(
+            "Expected net reward of a challenge: {} wei (minimum {} wei)",
+            net_reward_wei,
+            min_net_reward_wei
+        );
+
+        Ok(net_reward_wei >= min_net_reward_wei)
+    }
+
     /// Handles challenging of invalid games by scanning recent games for potential challenges.
     /// Also supports malicious challenging of valid games for testing defense mechanisms when
     /// configured.
     async fn handle_game_challenging(&self) -> Result<Action> {
         let _span = tracing::info_span!("[[Challenging]]").entered();
 
-        // Challenge invalid games (honest challenger behavior)
-        if let Some(game_address) = self
+        // Challenge invalid games (honest challenger behavior), oldest first, up to the
+        // configured number of challenges per proposer. A game that can't be challenged this
+        // tick only defers that game.
+        let game_addresses = self
             .factory
-            .get_oldest_challengable_game_address(
+            .get_challengable_game_addresses(
                 self.config.max_games_to_check_for_challenge,
-                self.l2_provider.clone(),
+                self.config.game_fetch_batch_size,
+                self.config.scan_concurrency,
+                self.caching_l2_provider.clone(),
+                &self.config.proposer_priority,
             )
-            .await?
-        {
+            .await?;
+
+        // The bonds are the same for every game, so profitability is checked once per tick.
+        let profitable = game_addresses.is_empty() ||
+            self.config.always_challenge_invalid ||
+            self.is_challenge_profitable().await?;
+
+        let mut challenges_per_proposer: HashMap<Address, u32> = HashMap::new();
+        let mut challenged = false;
+        for game_address in game_addresses {
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            let proposer = game.gameCreator().call().await?;
+            let limit =
+                self.config.max_challenges_per_proposer.get(&proposer).copied().unwrap_or(u32::MAX);
+            let challenges = challenges_per_proposer.entry(proposer).or_default();
+            if *challenges >= limit {
+                tracing::info!(
+                    "Reached the limit of {} challenges per tick for proposer {:?}, deferring game {:?}",
+                    limit,
+                    proposer,
+                    game_address
+                );
+                continue;
+            }
+
+            let game_age_secs = SystemTime::now()
+                .duration_since(UNIX_EPOCH)?
+                .as_secs()
+                .saturating_sub(game.createdAt().call().await?);
+            if game_age_secs < self.config.skip_proposals_newer_than_secs {
+                tracing::info!(
+                    "Game {:?} was created {}s ago, deferring challenge to leave time for a proof",
+                    game_address,
+                    game_age_secs
+                );
+                continue;
+            }
+
+            if !profitable {
+                tracing::info!(
+                    "Skipping challenge of invalid game {:?} below the minimum net reward",
+                    game_address
+                );
+                continue;
+            }
+
+            if !self.cross_validate_with_rpc_fallback(game_address).await? {
+                tracing::warn!(
+                    "Skipping challenge of game {:?} due to RPC disagreement",
+                    game_address
+                );
+                continue;
+            }
+
+            if let Err(e) = self.log_output_root_diagnostics(game_address).await {
+                tracing::debug!("Failed to log output root diagnostics: {:?}", e);
+            }
             tracing::info!(
                 "\x1b[32m[CHALLENGE]\x1b[0m Attempting to challenge invalid game {:?}",
                 game_address
             );
-            self.challenge_game(game_address).await?;
+            if self.challenge_game(game_address).await?.is_none() {
+                // The game remains unchallenged in dry run mode.
+                continue;
+            }
+            *challenges += 1;
+            challenged = true;
+        }
+        if challenged {
             return Ok(Action::Performed);
         }
 
@@ -131,18 +668,27 @@ where
             tracing::debug!("Checking for valid games to challenge maliciously...");
             if let Some(game_address) = self.get_oldest_valid_game_for_malicious_challenge().await?
             {
-                let mut rng = rand::rng();
-                let should_challenge =
-                    rng.random_range(0.0..100.0) <= self.config.malicious_challenge_percentage;
+                let roll: f64 = match &self.malicious_challenge_rng {
+                    Some(rng) => rng.lock().unwrap().random_range(0.0..100.0),
+                    None => rand::rng().random_range(0.0..100.0),
+                };
+                let should_challenge = roll <= self.config.malicious_challenge_percentage;
 
-                if should_challenge {
+                if should_challenge && !self.is_challenge_profitable().await? {
+                    tracing::info!(
+                        "Skipping malicious challenge of valid game {:?} below the minimum net reward",
+                        game_address
+                    );
+                } else if should_challenge {
                     tracing::warn!(
                         "\x1b[31m[MALICIOUS CHALLENGE]\x1b[0m Attempting to challenge valid game {:?} for testing ({}% chance)",
                         game_address,
                         self.config.malicious_challenge_percentage
                     );
-                    self.challenge_game(game_address).await?;
-                    return Ok(Action::Performed);
+                    return match self.challenge_game(game_address).await? {
+                        Some(_) => Ok(Action::Performed),
+                        None => Ok(Action::Skipped),
+                    };
                 } else {
                     tracing::debug!(
                         "Found valid game {:?} but skipping malicious challenge ({}% chance)",
@@ -158,22 +704,202 @@ where
         Ok(Action::Skipped)
     }
 
+    /// Checks a game for a challenge as soon as its creation event is received, instead of waiting
+    /// for the next scan.
+    ///
+    /// The per-proposer challenge limit only applies to the scan. Games that are deferred or fail
+    /// to be checked here are picked up by the scan. Games created in an L1 block with fewer than
+    /// `event_confirmations` confirmations are deferred, as the block may still be reorged away.
+    async fn handle_created_game(&self, game_address: Address, l1_block_number: u64) -> Result<()> {
+        let confirmed_l1_block_number =
+            confirmed_l1_block_number(&self.l1_provider, self.config.event_confirmations).await?;
+        if l1_block_number > confirmed_l1_block_number {
+            tracing::debug!(
+                "Game {:?} was created in unconfirmed L1 block {}, deferring challenge to the scan",
+                game_address,
+                l1_block_number
+            );
+            return Ok(());
+        }
+
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        self.first_response_tracker
+            .lock()
+            .unwrap()
+            .first_seen_at
+            .entry(game_address)
+            .or_insert_with(Instant::now);
+        if game.claimData().call().await?.status != ProposalStatus::Unchallenged {
+            return Ok(());
+        }
+
+        let game_age_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)?
+            .as_secs()
+            .saturating_sub(game.createdAt().call().await?);
+        if game_age_secs < self.config.skip_proposals_newer_than_secs {
+            tracing::debug!(
+                "Game {:?} was created {}s ago, deferring challenge to the scan",
+                game_address,
+                game_age_secs
+            );
+            return Ok(());
+        }
+
+        let l2_block_number = game.l2BlockNumber().call().await?;
+        let game_claim = game.rootClaim().call().await?;
+        let output_root = self
+            .caching_l2_provider
+            .compute_output_root_at_block_timed(
+                l2_block_number,
+                Mode::Challenger,
+                *self.factory.address(),
+            )
+            .await?;
+        if output_root == game_claim {
+            tracing::debug!("New game {:?} claims the correct output root", game_address);
+            self.first_response_tracker.lock().unwrap().record(game_address);
+            return Ok(());
+        }
+
+        if !self.config.always_challenge_invalid && !self.is_challenge_profitable().await? {
+            tracing::info!(
+                "Skipping challenge of new invalid game {:?} below the minimum net reward",
+                game_address
+            );
+            return Ok(());
+        }
+
+        if !self.cross_validate_with_rpc_fallback(game_address).await? {
+            tracing::warn!("Skipping challenge of game {:?} due to RPC disagreement", game_address);
+            return Ok(());
+        }
+
+        if let Err(e) = self.log_output_root_diagnostics(game_address).await {
+            tracing::debug!("Failed to log output root diagnostics: {:?}", e);
+        }
+        tracing::info!(
+            "\x1b[32m[CHALLENGE]\x1b[0m Attempting to challenge new invalid game {:?}",
+            game_address
+        );
+        self.challenge_game(game_address).await?;
+        Ok(())
+    }
+
+    /// Starts tracking the first response time of games created since the last check.
+    async fn track_new_games(&self) -> Result<()> {
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            return Ok(());
+        };
+        let oldest_game_index = latest_game_index
+            .saturating_sub(U256::from(self.config.max_games_to_check_for_challenge));
+
+        let last_seen_game_index = self.first_response_tracker.lock().unwrap().last_seen_game_index;
+        let first_new_game_index = match last_seen_game_index {
+            Some(index) => (index + U256::from(1)).max(oldest_game_index),
+            // Games that already existed at startup were not seen as they appeared.
+            None => latest_game_index + U256::from(1),
+        };
+
+        let new_game_indices = game_indices_between(first_new_game_index, latest_game_index);
+        let new_games = self
+            .factory
+            .fetch_game_addresses_by_index(&new_game_indices, self.config.game_fetch_batch_size)
+            .await?;
+
+        let mut tracker = self.first_response_tracker.lock().unwrap();
+        let now = Instant::now();
+        for game_address in new_games {
+            tracker.first_seen_at.entry(game_address).or_insert(now);
+        }
+        tracker.last_seen_game_index = Some(latest_game_index);
+
+        Ok(())
+    }
+
     /// Handles resolution of challenged games that are ready to be resolved.
-    async fn handle_game_resolution(&self) -> Result<()> {
+    async fn handle_game_resolution(&self) -> Result<u32> {
         let _span = tracing::info_span!("[[Resolving]]").entered();
 
         self.factory
             .resolve_games(
                 Mode::Challenger,
                 self.config.max_games_to_check_for_resolution,
+                self.config.defend_period_grace_secs,
+                self.config.dry_run,
                 self.signer.clone(),
                 self.config.l1_rpc.clone(),
+                &self.config.tx_retry_policy,
                 self.l1_provider.clone(),
                 self.l2_provider.clone(),
             )
             .await
     }
 
+    /// Resolves every expired challenged game, including those outside the resolution window.
+    ///
+    /// Games older than the resolution window are never revisited by
+    /// [`Self::handle_game_resolution`], so an expired challenge there would leave both bonds
+    /// locked. The sweep starts at the lowest game still in progress at the last sweep, as
+    /// resolved games stay resolved. Returns the number of games resolved.
+    async fn sweep_expired_challenged_games(&mut self) -> Result<u32> {
+        let _span = tracing::info_span!("[[Sweeping]]").entered();
+
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            return Ok(0);
+        };
+
+        let mut resolved = 0;
+        let mut lowest_in_progress_index = None;
+        let mut index = self.sweep_start_index;
+        while index <= latest_game_index {
+            let game_address = self.factory.fetch_game_address_by_index(index).await?;
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            if game.status().call().await? != GameStatus::IN_PROGRESS {
+                index += U256::from(1);
+                continue;
+            }
+
+            match self
+                .factory
+                .try_resolve_games(
+                    index,
+                    Mode::Challenger,
+                    self.config.defend_period_grace_secs,
+                    self.config.dry_run,
+                    self.signer.clone(),
+                    self.config.l1_rpc.clone(),
+                    &self.config.tx_retry_policy,
+                    self.l1_provider.clone(),
+                    self.l2_provider.clone(),
+                )
+                .await
+            {
+                Ok(Action::Performed) => {
+                    resolved += 1;
+                    ChallengerGauge::SweepResolutions.increment(1.0);
+                    ChallengerGauge::GamesResolved.increment(1.0);
+                    // Games resolved in dry run mode are still in progress.
+                    if self.config.dry_run {
+                        lowest_in_progress_index.get_or_insert(index);
+                    }
+                }
+                Ok(Action::Skipped) => {
+                    lowest_in_progress_index.get_or_insert(index);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to resolve game at index {:?}: {:?}", index, e);
+                    lowest_in_progress_index.get_or_insert(index);
+                }
+            }
+            index += U256::from(1);
+        }
+
+        self.sweep_start_index =
+            lowest_in_progress_index.unwrap_or(latest_game_index + U256::from(1));
+        Ok(resolved)
+    }
+
     /// Handles claiming bonds from resolved games.
     pub async fn handle_bond_claiming(&self) -> Result<Action> {
         let _span = tracing::info_span!("[[Claiming Bonds]]").entered();
@@ -192,14 +918,28 @@ where
             // Create a contract instance for the game
             let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
 
+            // The credit is paid out to the address that accrued it, so it can only be forwarded
+            // to the bond recipient after the claim.
+            let credit = game.credit(self.challenger_address).call().await?;
+
             // Create a transaction to claim credit
             let transaction_request =
                 game.claimCredit(self.challenger_address).into_transaction_request();
+            if self.config.dry_run {
+                log_dry_run_transaction("claim bond", &transaction_request);
+                return Ok(Action::Performed);
+            }
 
-            match self
-                .signer
-                .send_transaction_request(self.config.l1_rpc.clone(), transaction_request)
-                .await
+            match send_with_retry(
+                &self.signer,
+                self.config.l1_rpc.clone(),
+                transaction_request,
+                &self.config.tx_retry_policy,
+                Mode::Challenger,
+                *self.factory.address(),
+                TxOperation::ClaimBond,
+            )
+            .await
             {
                 Ok(receipt) => {
                     tracing::info!(
@@ -207,14 +947,36 @@ where
                         game_address,
                         receipt.transaction_hash
                     );
+                    record_gas_spent_wei(
+                        ChallengerGauge::TotalGasSpentWei,
+                        TxOperation::ClaimBond,
+                        gas_cost_wei(&receipt),
+                    );
+
+                    if let Some(recipient) = self.config.bond_recipient {
+                        let amount = claimed_bond_wei(
+                            &self.l1_provider,
+                            self.challenger_address,
+                            &receipt,
+                            credit,
+                        )
+                        .await?;
+                        self.forward_claimed_bond(game_address, recipient, amount).await?;
+                    }
 
                     Ok(Action::Performed)
                 }
-                Err(e) => Err(anyhow::anyhow!(
-                    "Failed to claim bond from game {:?}: {:?}",
-                    game_address,
-                    e
-                )),
+                Err(e) => {
+                    self.notify(
+                        NotificationEvent::BondClaimFailed,
+                        format!("Challenger failed to claim bond from game {game_address}: {e}"),
+                    );
+                    Err(anyhow::anyhow!(
+                        "Failed to claim bond from game {:?}: {:?}",
+                        game_address,
+                        e
+                    ))
+                }
             }
         } else {
             tracing::info!("No new games to claim bonds from");
@@ -223,10 +985,599 @@ where
         }
     }
 
-    /// Runs the challenger in an infinite loop, periodically checking for games to challenge and
-    /// resolve.
-    async fn run(&mut self) -> Result<()> {
+    /// Sends the bond claimed from a game from the signer to `recipient`.
+    async fn forward_claimed_bond(
+        &self,
+        game_address: Address,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<()> {
+        let transaction_request = with_1559_fees(
+            &self.l1_provider,
+            TransactionRequest::default().to(recipient).value(amount),
+            self.config.l1_fee_percentile,
+            self.config.max_fee_per_gas_gwei,
+        )
+        .await?;
+        let receipt = send_with_retry(
+            &self.signer,
+            self.config.l1_rpc.clone(),
+            transaction_request,
+            &self.config.tx_retry_policy,
+            Mode::Challenger,
+            *self.factory.address(),
+            TxOperation::ForwardBond,
+        )
+        .await
+        .with_context(|| {
+            format!("Failed to forward bond claimed from game {game_address:?} to {recipient:?}")
+        })?;
+        tracing::info!(
+            "Forwarded bond of {} wei claimed from game {:?} to {:?} with tx {:?}",
+            amount,
+            game_address,
+            recipient,
+            receipt.transaction_hash
+        );
+        record_gas_spent_wei(
+            ChallengerGauge::TotalGasSpentWei,
+            TxOperation::ForwardBond,
+            gas_cost_wei(&receipt),
+        );
+
+        Ok(())
+    }
+
+    /// Re-evaluates in-progress games whose L1 head was part of a reorg.
+    ///
+    /// A game is affected if its L1 head block can no longer be found by hash, or if the block
+    /// number it maps to was reported as reorged. For every affected game the output root is
+    /// recomputed and compared against the claim, so that games which flipped validity are
+    /// surfaced to the operator. The regular challenge scan picks up newly invalid games on its
+    /// own.
+    async fn recheck_challenges_after_reorg(
+        &self,
+        reorged_blocks: &[u64],
+    ) -> Result<ReorgImpactReport> {
+        let mut report = ReorgImpactReport::default();
+
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            return Ok(report);
+        };
+        let oldest_game_index = latest_game_index
+            .saturating_sub(U256::from(self.config.max_games_to_check_for_challenge));
+
+        let mut game_index = oldest_game_index;
+        while game_index <= latest_game_index {
+            // The RPC answering this call may be behind the one that returned the game count.
+            let Some(game_address) =
+                self.factory.try_fetch_game_address_by_index(game_index).await?
+            else {
+                break;
+            };
+            game_index += U256::from(1);
+
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            if game.status().call().await? != GameStatus::IN_PROGRESS {
+                continue;
+            }
+
+            let l1_head = game.l1Head().call().await?;
+            let is_affected = match self.l1_provider.get_block_by_hash(l1_head).await? {
+                Some(block) => reorged_blocks.contains(&block.header.number),
+                None => true,
+            };
+            if !is_affected {
+                continue;
+            }
+            report.affected_games.push(game_address);
+
+            let l2_block_number = game.l2BlockNumber().call().await?;
+            let game_claim = game.rootClaim().call().await?;
+            let output_root = self
+                .l2_provider
+                .compute_output_root_at_block_timed(
+                    l2_block_number,
+                    Mode::Challenger,
+                    *self.factory.address(),
+                )
+                .await?;
+            let claim_data = game.claimData().call().await?;
+
+            match claim_data.status {
+                ProposalStatus::Unchallenged if output_root != game_claim => {
+                    report.newly_invalid.push(game_address)
+                }
+                ProposalStatus::Challenged if output_root == game_claim => {
+                    report.newly_valid.push(game_address)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Polls the L1 reorg detector and re-evaluates games affected by any detected reorg.
+    async fn handle_l1_reorgs(&mut self) -> Result<()> {
+        let reorged_blocks = self.l1_reorg_detector.poll(&self.l1_provider).await?;
+        if reorged_blocks.is_empty() {
+            return Ok(());
+        }
+
+        let _span = tracing::info_span!("[[L1 Reorg]]").entered();
+        tracing::warn!("Detected L1 reorg affecting blocks {:?}", reorged_blocks);
+
+        let report = self.recheck_challenges_after_reorg(&reorged_blocks).await?;
+        ChallengerGauge::ReorgAffectedGames.increment(report.affected_games.len() as f64);
+
+        if !report.affected_games.is_empty() {
+            tracing::warn!(
+                "L1 reorg affected games {:?} (newly invalid: {:?}, newly valid: {:?})",
+                report.affected_games,
+                report.newly_invalid,
+                report.newly_valid
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Refreshes the cached proposer whitelist from the access manager.
+    ///
+    /// Replays all `ProposerPermissionUpdated` events emitted since the last synced L1 block, up to
+    /// the latest block with `event_confirmations` confirmations. The first sync replays the full
+    /// event history of the access manager.
+    async fn sync_whitelist(&mut self) -> Result<WhitelistSyncResult> {
+        let access_manager_address =
+            self.factory.get_access_manager_address(self.config.game_type).await?;
+        let latest_l1_block_number =
+            confirmed_l1_block_number(&self.l1_provider, self.config.event_confirmations).await?;
+        let from_block = self.whitelist_synced_block.map_or(0, |block| block + 1);
+        if from_block > latest_l1_block_number {
+            return Ok(WhitelistSyncResult {
+                current: self.proposer_whitelist.iter().copied().collect(),
+                ..Default::default()
+            });
+        }
+
+        let filter = Filter::new()
+            .address(access_manager_address)
+            .event_signature(ProposerPermissionUpdated::SIGNATURE_HASH)
+            .from_block(from_block)
+            .to_block(latest_l1_block_number);
+
+        let previous = self.proposer_whitelist.clone();
+        for log in self.l1_provider.get_logs(&filter).await? {
+            let event = ProposerPermissionUpdated::decode_log(&log.inner)?;
+            if event.allowed {
+                self.proposer_whitelist.insert(event.proposer);
+            } else {
+                self.proposer_whitelist.remove(&event.proposer);
+            }
+        }
+        self.whitelist_synced_block = Some(latest_l1_block_number);
+
+        Ok(WhitelistSyncResult {
+            added: self.proposer_whitelist.difference(&previous).copied().collect(),
+            removed: previous.difference(&self.proposer_whitelist).copied().collect(),
+            current: self.proposer_whitelist.iter().copied().collect(),
+        })
+    }
+
+    /// Syncs the proposer whitelist and updates the whitelist metrics.
+    async fn handle_whitelist_sync(&mut self) -> Result<()> {
+        let result = self.sync_whitelist().await?;
+        ChallengerGauge::WhitelistedProposerCount.set(result.current.len() as f64);
+
+        if !result.added.is_empty() || !result.removed.is_empty() {
+            tracing::info!(
+                "Proposer whitelist updated (added: {:?}, removed: {:?}, current: {:?})",
+                result.added,
+                result.removed,
+                result.current
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the challenger metrics.
+    async fn fetch_challenger_metrics(&self) -> Result<()> {
+        // Update metrics for bonds locked in in-progress games.
+        let bond_escrow_state = self
+            .factory
+            .compute_total_bonds_locked(
+                self.config.game_type,
+                self.config.max_games_to_check_for_challenge,
+                self.challenger_address,
+            )
+            .await?;
+        ChallengerGauge::TotalBondsLockedWei
+            .set(bond_escrow_state.total_locked.saturating_to::<u128>() as f64);
+        ChallengerGauge::BondsLockedWei
+            .set(bond_escrow_state.own_bonds_locked.saturating_to::<u128>() as f64);
+
+        // Update metrics for the signer balance, and warn before it gets too low to post bonds.
+        let signer_balance = self.l1_provider.get_balance(self.challenger_address).await?;
+        ChallengerGauge::WalletBalanceWei.set(signer_balance.saturating_to::<u128>() as f64);
+        if signer_balance < self.config.min_balance_wei {
+            tracing::warn!(
+                "Challenger balance of {} wei is below the minimum of {} wei",
+                signer_balance,
+                self.config.min_balance_wei
+            );
+            ChallengerGauge::LowBalance.increment(1.0);
+        }
+
+        self.update_seconds_until_deadline().await?;
+
+        Ok(())
+    }
+
+    /// Updates the time until the challenge deadline of the oldest challengeable game, and warns
+    /// when it drops below `deadline_alert_threshold_secs`. Games of ignored proposers are left
+    /// out, as they are never challenged.
+    async fn update_seconds_until_deadline(&self) -> Result<()> {
+        let proposer_priority = ProposerPriority {
+            prioritized: HashSet::new(),
+            ignored: self.config.proposer_priority.ignored.clone(),
+        };
+        let Some(game_address) = self
+            .factory
+            .get_oldest_challengable_game_address(
+                self.config.max_games_to_check_for_challenge,
+                self.config.game_fetch_batch_size,
+                self.config.scan_concurrency,
+                self.caching_l2_provider.clone(),
+                &proposer_priority,
+            )
+            .await?
+        else {
+            ChallengerGauge::SecondsUntilDeadline.set(-1.0);
+            return Ok(());
+        };
+
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        let deadline = game.claimData().call().await?.deadline;
+        let current_timestamp = self
+            .l2_provider
+            .get_l2_block_by_number(BlockNumberOrTag::Latest)
+            .await?
+            .header
+            .timestamp;
+        let secs_until_deadline = deadline.saturating_sub(current_timestamp);
+        ChallengerGauge::SecondsUntilDeadline.set(secs_until_deadline as f64);
+        if secs_until_deadline < self.config.deadline_alert_threshold_secs {
+            tracing::warn!(
+                "Invalid game {:?} is {} seconds from its challenge deadline and still isn't challenged",
+                game_address,
+                secs_until_deadline
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Updates the metrics for proposers submitting systematically invalid output roots.
+    ///
+    /// This computes the output root of every game in the challenge window, so
+    /// [`Self::run`] calls it on its own, slower interval instead of with the other metrics.
+    async fn update_suspicious_proposers(&self) -> Result<()> {
+        let collusion_report = self
+            .detect_colluding_proposers(self.config.max_games_to_check_for_challenge as u32)
+            .await?;
+        ChallengerGauge::SuspiciousProposerCount
+            .set(collusion_report.suspicious_proposers.len() as f64);
+        tracing::debug!(
+            "{} invalid games in the challenge window",
+            collusion_report.total_invalid_proposals
+        );
+        Ok(())
+    }
+
+    /// Flags proposers for which more than half of the games among the last `window_size` games
+    /// have an invalid output root.
+    ///
+    /// Several such proposers may be colluding to drain challenger bonds faster than games can be
+    /// challenged. This is a heuristic: a single misconfigured proposer is flagged as well.
+    async fn detect_colluding_proposers(&self, window_size: u32) -> Result<CollusionReport> {
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            return Ok(CollusionReport::default());
+        };
+
+        // The number of games and invalid games created by each proposer.
+        let mut proposals: HashMap<Address, (u32, u32)> = HashMap::new();
+        let mut total_invalid_proposals = 0;
+        let mut index = latest_game_index.saturating_sub(U256::from(window_size));
+        while index <= latest_game_index {
+            // The RPC answering this call may be behind the one that returned the game count.
+            let Some(game_address) = self.factory.try_fetch_game_address_by_index(index).await?
+            else {
+                break;
+            };
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            let output_root = self
+                .caching_l2_provider
+                .compute_output_root_at_block(game.l2BlockNumber().call().await?)
+                .await?;
+            let is_invalid = output_root != game.rootClaim().call().await?;
+
+            let (total, invalid) = proposals.entry(game.gameCreator().call().await?).or_default();
+            *total += 1;
+            if is_invalid {
+                *invalid += 1;
+                total_invalid_proposals += 1;
+            }
+            index += U256::from(1);
+        }
+
+        let suspicious_proposers: Vec<(Address, f64)> = proposals
+            .into_iter()
+            .map(|(proposer, (total, invalid))| (proposer, invalid as f64 / total as f64))
+            .filter(|(_, invalid_ratio)| *invalid_ratio > SUSPICIOUS_INVALID_PROPOSAL_RATIO)
+            .collect();
+        if !suspicious_proposers.is_empty() {
+            tracing::error!(
+                "Possible colluding proposers with a majority of invalid games: {:?} ({} invalid games in the last {} games)",
+                suspicious_proposers,
+                total_invalid_proposals,
+                window_size
+            );
+        }
+
+        Ok(CollusionReport { suspicious_proposers, total_invalid_proposals })
+    }
+
+    /// Verifies the outcome of games resolved since the last check against the local output root.
+    async fn verify_resolved_games(&mut self) -> Result<()> {
+        let discrepancies = self
+            .correctness_monitor
+            .check_resolved_games(
+                &self.factory,
+                self.config.max_games_to_check_for_resolution,
+                &self.l1_provider,
+                &self.l2_provider,
+            )
+            .await?;
+        for (_, discrepancy) in discrepancies {
+            if discrepancy == ResolutionDiscrepancy::SpuriousChallengerWin {
+                ChallengerGauge::SpuriousChallengerWins.increment(1.0);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolves games challenged by this challenger as soon as a proof is submitted for them.
+    ///
+    /// Scans the L1 blocks produced since the last check for `Proved` events emitted by games
+    /// this challenger challenged, ignoring games not created by the factory with the configured
+    /// game type. Only blocks with `event_confirmations` confirmations are scanned. The first
+    /// check only starts watching from the confirmed L1 tip.
+    async fn handle_proven_games(&mut self) -> Result<()> {
+        let latest_l1_block_number =
+            confirmed_l1_block_number(&self.l1_provider, self.config.event_confirmations).await?;
+        let Some(synced_block) = self.proof_watch_synced_block else {
+            self.proof_watch_synced_block = Some(latest_l1_block_number);
+            return Ok(());
+        };
+        if synced_block >= latest_l1_block_number {
+            return Ok(());
+        }
+
+        // Find the games challenged by this challenger within the challenge window.
+        let challenged_filter = Filter::new()
+            .event_signature(Challenged::SIGNATURE_HASH)
+            .topic1(self.challenger_address.into_word());
+        let challenged_games: HashSet<Address> = get_logs_paged(
+            &self.l1_provider,
+            &challenged_filter,
+            latest_l1_block_number.saturating_sub(CHALLENGED_GAMES_LOOKBACK_L1_BLOCKS),
+            latest_l1_block_number,
+        )
+        .await?
+        .iter()
+        .map(|log| log.address())
+        .collect();
+        if challenged_games.is_empty() {
+            self.proof_watch_synced_block = Some(latest_l1_block_number);
+            return Ok(());
+        }
+
+        // Any contract can emit a `Challenged` event, so only keep the games this factory created
+        // with the configured game type. A game is created before it is challenged, so the
+        // creation scan reaches one more window back.
+        let creation_filter = Filter::new()
+            .address(*self.factory.address())
+            .event_signature(DisputeGameCreated::SIGNATURE_HASH)
+            .topic1(challenged_games.iter().map(|game| game.into_word()).collect::<Vec<_>>())
+            .topic2(B256::from(U256::from(self.config.game_type)));
+        let factory_games: Vec<Address> = get_logs_paged(
+            &self.l1_provider,
+            &creation_filter,
+            latest_l1_block_number.saturating_sub(2 * CHALLENGED_GAMES_LOOKBACK_L1_BLOCKS),
+            latest_l1_block_number,
+        )
+        .await?
+        .iter()
+        .filter_map(|log| DisputeGameCreated::decode_log(&log.inner).ok())
+        .map(|event| event.disputeProxy)
+        .filter(|game| challenged_games.contains(game))
+        .collect();
+        if factory_games.is_empty() {
+            self.proof_watch_synced_block = Some(latest_l1_block_number);
+            return Ok(());
+        }
+
+        let proved_filter =
+            Filter::new().address(factory_games).event_signature(Proved::SIGNATURE_HASH);
+        for log in get_logs_paged(
+            &self.l1_provider,
+            &proved_filter,
+            synced_block + 1,
+            latest_l1_block_number,
+        )
+        .await?
+        {
+            let game_address = log.address();
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            if game.status().call().await? != GameStatus::IN_PROGRESS {
+                continue;
+            }
+
+            tracing::info!("Proof submitted for challenged game {:?}, resolving", game_address);
+            let transaction_request = game.resolve().into_transaction_request();
+            if self.config.dry_run {
+                log_dry_run_transaction("resolve", &transaction_request);
+                ChallengerGauge::GamesResolved.increment(1.0);
+                continue;
+            }
+            let transaction_request = with_1559_fees(
+                &self.l1_provider,
+                transaction_request,
+                self.config.l1_fee_percentile,
+                self.config.max_fee_per_gas_gwei,
+            )
+            .await?;
+            match send_with_retry(
+                &self.signer,
+                self.config.l1_rpc.clone(),
+                transaction_request,
+                &self.config.tx_retry_policy,
+                Mode::Challenger,
+                *self.factory.address(),
+                TxOperation::Resolve,
+            )
+            .await
+            {
+                Ok(receipt) => {
+                    tracing::info!(
+                        "\x1b[1mSuccessfully resolved proven game {:?} with tx {:?}\x1b[0m",
+                        game_address,
+                        receipt.transaction_hash
+                    );
+                    self.notify(
+                        NotificationEvent::GameResolved,
+                        format!(
+                            "Challenger resolved proven game {game_address} with tx {}",
+                            receipt.transaction_hash
+                        ),
+                    );
+                    ChallengerGauge::GamesResolved.increment(1.0);
+                    record_gas_spent_wei(
+                        ChallengerGauge::TotalGasSpentWei,
+                        TxOperation::Resolve,
+                        gas_cost_wei(&receipt),
+                    );
+                }
+                // The parent game may not be resolved yet, in which case the regular resolution
+                // scan picks this game up later.
+                Err(e) => {
+                    tracing::warn!("Failed to resolve proven game {:?}: {:?}", game_address, e)
+                }
+            }
+        }
+
+        self.proof_watch_synced_block = Some(latest_l1_block_number);
+        Ok(())
+    }
+
+    /// Runs a single challenger cycle: sweeps expired challenged games, challenges invalid games,
+    /// resolves games and claims bonds.
+    ///
+    /// [`Self::run`] calls this on every interval. Proof watching runs on its own interval and is
+    /// not part of a tick.
+    pub async fn tick(&mut self) -> Result<TickReport> {
+        let mut report = TickReport::default();
+
+        if self.ticks % self.config.sweep_interval_ticks.max(1) == 0 {
+            match self.sweep_expired_challenged_games().await {
+                Ok(resolved) if resolved > 0 => {
+                    tracing::info!("Sweep resolved {} expired challenged games", resolved);
+                    self.notify(
+                        NotificationEvent::GameResolved,
+                        format!("Challenger resolved {resolved} expired challenged games"),
+                    );
+                    report.expired_games_resolved = resolved;
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Failed to sweep expired challenged games: {:?}", e),
+            }
+        }
+        self.ticks += 1;
+
+        if let Err(e) = self.handle_l1_reorgs().await {
+            tracing::warn!("Failed to handle L1 reorgs: {:?}", e);
+        }
+
+        if let Err(e) = self.handle_whitelist_sync().await {
+            tracing::warn!("Failed to sync proposer whitelist: {:?}", e);
+        }
+
+        if let Err(e) = self.track_new_games().await {
+            tracing::warn!("Failed to track new games: {:?}", e);
+        }
+
+        match self.handle_game_challenging().await {
+            Ok(Action::Performed) => report.challenged = true,
+            Ok(Action::Skipped) => {
+                // The whole scan window was checked without finding a game to challenge.
+                self.first_response_tracker.lock().unwrap().record_all();
+            }
+            Err(e) => {
+                tracing::warn!("Failed to handle game challenging: {:?}", e);
+                ChallengerGauge::GameChallengingError.increment(1.0);
+            }
+        }
+
+        match self.handle_game_resolution().await {
+            Ok(resolved) => {
+                if resolved > 0 {
+                    self.notify(
+                        NotificationEvent::GameResolved,
+                        format!("Challenger resolved {resolved} challenged games"),
+                    );
+                }
+                report.games_resolved = resolved;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to handle game resolution: {:?}", e);
+                ChallengerGauge::GameResolutionError.increment(1.0);
+            }
+        }
+
+        match self.handle_bond_claiming().await {
+            Ok(Action::Performed) => {
+                report.bond_claimed = true;
+                ChallengerGauge::GamesBondsClaimed.increment(1.0);
+            }
+            Ok(Action::Skipped) => {}
+            Err(e) => {
+                tracing::warn!("Failed to handle bond claiming: {:?}", e);
+                ChallengerGauge::BondClaimingError.increment(1.0);
+            }
+        }
+
+        if let Err(e) = self.verify_resolved_games().await {
+            tracing::warn!("Failed to verify resolved games: {:?}", e);
+        }
+
+        if let Err(e) = self.fetch_challenger_metrics().await {
+            tracing::warn!("Failed to fetch challenger metrics: {:?}", e);
+        }
+
+        Ok(report)
+    }
+
+    /// Runs the challenger until `shutdown` is cancelled or the process receives SIGINT or SIGTERM,
+    /// periodically checking for games to challenge and resolve.
+    ///
+    /// Shutdown only happens between ticks, so a transaction that was sent is always awaited.
+    async fn run(&mut self, shutdown: Option<CancellationToken>) -> Result<()> {
         tracing::info!("OP Succinct Challenger running...");
+        let shutdown = shutdown.unwrap_or_default();
+        cancel_on_shutdown_signal(shutdown.clone());
         if self.config.malicious_challenge_percentage > 0.0 {
             tracing::warn!(
                 "\x1b[33mMalicious challenging enabled: {}% of valid games will be challenged for testing\x1b[0m",
@@ -236,54 +1587,257 @@ where
             tracing::info!("Honest challenger mode (malicious challenging disabled)");
         }
         let mut interval = time::interval(Duration::from_secs(self.config.fetch_interval));
+        let mut proof_watch_interval =
+            time::interval(Duration::from_secs(PROOF_WATCH_INTERVAL_SECS));
+        let mut collusion_check_interval =
+            time::interval(Duration::from_secs(self.config.collusion_check_interval_secs));
 
-        // Each loop, check the oldest challengeable game and challenge it if it exists.
-        // Eventually, all games will be challenged (as long as the rate at which games are being
-        // created is slower than the fetch interval).
-        loop {
-            interval.tick().await;
+        let (created_games_tx, mut created_games) = mpsc::unbounded_channel();
+        let subscription = self.config.use_event_subscriptions.then(|| {
+            tokio::spawn(watch_created_games(
+                self.l1_provider.clone(),
+                self.config.factory_address,
+                self.config.game_type,
+                created_games_tx,
+            ))
+        });
 
-            match self.handle_game_challenging().await {
-                Ok(Action::Performed) => {
-                    ChallengerGauge::GamesChallenged.increment(1.0);
+        // Each loop, challenge the challengeable games from oldest to newest, up to the configured
+        // number of challenges per proposer. Games deferred by the limit are challenged on a later
+        // loop.
+        loop {
+            tokio::select! {
+                _ = proof_watch_interval.tick(), if self.config.proof_watching_enabled => {
+                    if let Err(e) = self.handle_proven_games().await {
+                        tracing::warn!("Failed to handle proven games: {:?}", e);
+                    }
+                    continue;
                 }
-                Ok(Action::Skipped) => {}
-                Err(e) => {
-                    tracing::warn!("Failed to handle game challenging: {:?}", e);
-                    ChallengerGauge::GameChallengingError.increment(1.0);
+                _ = collusion_check_interval.tick() => {
+                    if let Err(e) = self.update_suspicious_proposers().await {
+                        tracing::warn!("Failed to check for colluding proposers: {:?}", e);
+                    }
+                    continue;
                 }
+                Some((game_address, l1_block_number)) = created_games.recv(), if subscription.is_some() => {
+                    if let Err(e) = self.handle_created_game(game_address, l1_block_number).await {
+                        tracing::warn!("Failed to check new game {:?}: {:?}", game_address, e);
+                    }
+                    continue;
+                }
+                _ = interval.tick() => {}
+                _ = shutdown.cancelled() => break,
             }
 
-            if let Err(e) = self.handle_game_resolution().await {
-                tracing::warn!("Failed to handle game resolution: {:?}", e);
-                ChallengerGauge::GameResolutionError.increment(1.0);
+            match self.tick().await {
+                Ok(report) => {
+                    self.health.record_successful_tick();
+                    tracing::debug!(
+                        "Challenger tick: challenged: {}, resolved {} games ({} expired), claimed bond: {}",
+                        report.challenged,
+                        report.games_resolved,
+                        report.expired_games_resolved,
+                        report.bond_claimed
+                    );
+                }
+                Err(e) => tracing::warn!("Challenger tick failed: {:?}", e),
             }
 
-            match self.handle_bond_claiming().await {
-                Ok(Action::Performed) => {
-                    ChallengerGauge::GamesBondsClaimed.increment(1.0);
-                }
-                Ok(Action::Skipped) => {}
-                Err(e) => {
-                    tracing::warn!("Failed to handle bond claiming: {:?}", e);
-                    ChallengerGauge::BondClaimingError.increment(1.0);
-                }
+            if self.config.fetch_interval_jitter_pct > 0.0 {
+                interval.reset_after(jittered_interval(
+                    Duration::from_secs(self.config.fetch_interval),
+                    self.config.fetch_interval_jitter_pct,
+                ));
             }
         }
+
+        if let Some(subscription) = subscription {
+            subscription.abort();
+        }
+        if let Err(e) = self.fetch_challenger_metrics().await {
+            tracing::warn!("Failed to fetch challenger metrics: {:?}", e);
+        }
+        tracing::info!("OP Succinct Challenger shut down");
+        Ok(())
     }
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    setup_logging();
+/// Sends the address of every game of `game_type` created by the factory to `created_games`, along
+/// with the L1 block it was created in.
+///
+/// When the subscription fails or drops, it is re-established and the events emitted in the
+/// meantime are backfilled from the last seen L1 block. Returns once the receiver is dropped.
+async fn watch_created_games(
+    l1_provider: L1Provider,
+    factory_address: Address,
+    game_type: u32,
+    created_games: mpsc::UnboundedSender<(Address, u64)>,
+) {
+    let filter = Filter::new()
+        .address(factory_address)
+        .event_signature(DisputeGameCreated::SIGNATURE_HASH)
+        .topic2(B256::from(U256::from(game_type)));
+    let mut next_block = None;
+    loop {
+        match stream_created_games(&l1_provider, &filter, &created_games, &mut next_block).await {
+            Ok(()) => tracing::warn!("Game creation subscription dropped, resubscribing"),
+            Err(e) => tracing::warn!("Game creation subscription failed, resubscribing: {:?}", e),
+        }
+        if created_games.is_closed() {
+            return;
+        }
+        time::sleep(Duration::from_secs(RESUBSCRIBE_DELAY_SECS)).await;
+    }
+}
 
+/// Subscribes to game creation events, backfills the events since `next_block` and streams them
+/// until the subscription ends.
+///
+/// `next_block` is the first L1 block whose events may not have been sent yet. Events of that
+/// block can be sent twice, which is harmless as a game is only challenged once.
+async fn stream_created_games(
+    l1_provider: &L1Provider,
+    filter: &Filter,
+    created_games: &mpsc::UnboundedSender<(Address, u64)>,
+    next_block: &mut Option<u64>,
+) -> Result<()> {
+    let mut stream = l1_provider.subscribe_logs(filter).await?.into_stream();
+
+    // Subscribe first, so that no event falls between the backfill and the subscription.
+    let latest_block = l1_provider.get_block_number().await?;
+    if let Some(from_block) = *next_block {
+        let backfill_filter = filter.clone().from_block(from_block).to_block(latest_block);
+        let logs = l1_provider.get_logs(&backfill_filter).await?;
+        tracing::info!(
+            "Backfilled {} game creation events since L1 block {}",
+            logs.len(),
+            from_block
+        );
+        for log in logs {
+            send_created_game(&log, created_games)?;
+        }
+    }
+    *next_block = Some(latest_block + 1);
+
+    while let Some(log) = stream.next().await {
+        let Some(block_number) = log.block_number else {
+            continue;
+        };
+        // Events of reorged blocks and events already backfilled are skipped.
+        if log.removed || next_block.is_some_and(|next_block| block_number < next_block) {
+            continue;
+        }
+        send_created_game(&log, created_games)?;
+        *next_block = Some(block_number);
+    }
+    Ok(())
+}
+
+/// Simulates the financial outcome of a season of proposals.
+///
+/// Every invalid proposal is challenged. A challenge is lost, and the challenger bond
+/// forfeited, if the proposal is nevertheless proven, and won otherwise, earning the init
+/// bond. The simulation uses a fixed seed and makes no RPC calls.
+fn simulate_challenge_season(params: SimulationParams) -> SimulationReport {
+    let mut rng = StdRng::seed_from_u64(SIMULATION_SEED);
+    let mut report = SimulationReport {
+        expected_challenges: 0,
+        expected_wins: 0,
+        expected_losses: 0,
+        expected_pnl_wei: I256::ZERO,
+        roi_pct: 0.0,
+    };
+
+    let gas_cost_wei = (ESTIMATED_GAS_PER_CHALLENGE as f64 * params.avg_l1_gas_gwei * 1e9) as u128;
+    let init_bond = I256::from_raw(params.init_bond_wei);
+    let challenger_bond = I256::from_raw(params.challenger_bond_wei);
+    for _ in 0..params.num_proposals {
+        if !rng.random_bool(params.fraction_invalid.clamp(0.0, 1.0)) {
+            continue;
+        }
+
+        report.expected_challenges += 1;
+        report.expected_pnl_wei -= I256::from_raw(U256::from(gas_cost_wei));
+        if rng.random_bool(params.prove_success_rate.clamp(0.0, 1.0)) {
+            report.expected_losses += 1;
+            report.expected_pnl_wei -= challenger_bond;
+        } else {
+            report.expected_wins += 1;
+            report.expected_pnl_wei += init_bond;
+        }
+    }
+
+    let capital_at_stake_wei = params.challenger_bond_wei.saturating_to::<u128>() as f64 *
+        report.expected_challenges as f64;
+    if capital_at_stake_wei > 0.0 {
+        let saturated_pnl_wei =
+            if report.expected_pnl_wei.is_negative() { i128::MIN } else { i128::MAX };
+        let pnl_wei = i128::try_from(report.expected_pnl_wei).unwrap_or(saturated_pnl_wei) as f64;
+        report.roi_pct = pnl_wei / capital_at_stake_wei * 100.0;
+    }
+
+    report
+}
+
+/// Decodes a game creation event and sends the address of the created game, along with its L1
+/// block.
+fn send_created_game(
+    log: &Log,
+    created_games: &mpsc::UnboundedSender<(Address, u64)>,
+) -> Result<()> {
+    let event = log.log_decode::<DisputeGameCreated>()?;
+    let block_number = log.block_number.context("Game creation event without a block number")?;
+    created_games.send((event.inner.data.disputeProxy, block_number))?;
+    Ok(())
+}
+
+fn main() -> Result<()> {
     let args = Args::parse();
-    dotenv::from_filename(args.env_file).ok();
+
+    // The simulation only depends on its parameters, so it runs without any configuration.
+    if let Some(Command::SimulateSeason {
+        num_proposals,
+        fraction_invalid,
+        avg_l1_gas_gwei,
+        prove_success_rate,
+        init_bond_wei,
+        challenger_bond_wei,
+    }) = args.command
+    {
+        let report = simulate_challenge_season(SimulationParams {
+            num_proposals,
+            fraction_invalid,
+            avg_l1_gas_gwei,
+            prove_success_rate,
+            init_bond_wei,
+            challenger_bond_wei,
+        });
+        println!("Expected challenges: {}", report.expected_challenges);
+        println!("Expected wins:       {}", report.expected_wins);
+        println!("Expected losses:     {}", report.expected_losses);
+        println!("Expected PnL:        {} wei", report.expected_pnl_wei);
+        println!("ROI:                 {:.2}%", report.roi_pct);
+        return Ok(());
+    }
+
+    // Setting environment variables isn't thread-safe, so the env file and config file are loaded
+    // before the runtime starts its worker threads.
+    dotenv::from_filename(&args.env_file).ok();
+    if let Some(config) = &args.config {
+        load_config_file(config)?;
+    }
+
+    tokio::runtime::Runtime::new()?.block_on(run(args))
+}
+
+async fn run(args: Args) -> Result<()> {
+    setup_logging();
 
     let challenger_signer = Signer::from_env()?;
+    init_rpc_limits(RpcLimits::from_env()?);
 
-    let l1_provider = ProviderBuilder::default()
-        .connect_http(env::var("L1_RPC").unwrap().parse::<Url>().unwrap());
+    let l1_provider: L1Provider =
+        connect_provider(&env::var("L1_RPC").unwrap().parse::<Url>().unwrap()).await?;
 
     let factory = DisputeGameFactory::new(
         env::var("FACTORY_ADDRESS")
@@ -302,16 +1856,104 @@ async fn main() -> Result<()> {
     .await
     .unwrap();
 
-    // Initialize challenger gauges.
+    match args.command {
+        Some(Command::Challenge { game_index, force }) => {
+            let (tx_hash, status) = challenger.challenge_game_at_index(game_index, force).await?;
+            match tx_hash {
+                Some(tx_hash) => println!("Challenge tx: {tx_hash:?}"),
+                None => println!("Challenge tx: not sent (dry run)"),
+            }
+            println!("Game status:  {status:?}");
+            return Ok(());
+        }
+        Some(Command::SimulateSeason { .. }) | None => {}
+    }
+
+    // Initialize challenger gauges and histograms.
     ChallengerGauge::register_all();
+    ChallengerHistogram::register_all();
 
     // Initialize metrics exporter.
-    init_metrics(&challenger.config.metrics_port);
+    init_metrics_with_histograms::<ChallengerHistogram>(&challenger.config.metrics_port);
+    init_health_server(challenger.config.health_port, challenger.health.clone(), Vec::new());
 
     // Initialize the metrics gauges.
     ChallengerGauge::init_all();
 
-    challenger.run().await.expect("Runs in an infinite loop");
+    challenger.run(None).await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INIT_BOND_WEI: u128 = 1_000_000_000_000_000_000;
+    const CHALLENGER_BOND_WEI: u128 = 2_000_000_000_000_000_000;
+
+    fn params(fraction_invalid: f64, prove_success_rate: f64) -> SimulationParams {
+        SimulationParams {
+            num_proposals: 10,
+            fraction_invalid,
+            avg_l1_gas_gwei: 1.0,
+            prove_success_rate,
+            init_bond_wei: U256::from(INIT_BOND_WEI),
+            challenger_bond_wei: U256::from(CHALLENGER_BOND_WEI),
+        }
+    }
+
+    /// The gas cost of a challenge at 1 gwei, in wei.
+    fn gas_cost_wei() -> i128 {
+        ESTIMATED_GAS_PER_CHALLENGE as i128 * 1_000_000_000
+    }
+
+    #[test]
+    fn test_no_invalid_proposals() {
+        let report = simulate_challenge_season(params(0.0, 0.5));
+        assert_eq!(report.expected_challenges, 0);
+        assert_eq!(report.expected_wins, 0);
+        assert_eq!(report.expected_losses, 0);
+        assert_eq!(report.expected_pnl_wei, I256::ZERO);
+        assert_eq!(report.roi_pct, 0.0);
+    }
+
+    #[test]
+    fn test_all_challenges_won() {
+        let report = simulate_challenge_season(params(1.0, 0.0));
+        assert_eq!(report.expected_challenges, 10);
+        assert_eq!(report.expected_wins, 10);
+        assert_eq!(report.expected_losses, 0);
+
+        let pnl_wei = 10 * (INIT_BOND_WEI as i128 - gas_cost_wei());
+        assert_eq!(report.expected_pnl_wei, I256::try_from(pnl_wei).unwrap());
+        let roi_pct = pnl_wei as f64 / (10.0 * CHALLENGER_BOND_WEI as f64) * 100.0;
+        assert!((report.roi_pct - roi_pct).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_all_challenges_lost() {
+        let report = simulate_challenge_season(params(1.0, 1.0));
+        assert_eq!(report.expected_challenges, 10);
+        assert_eq!(report.expected_wins, 0);
+        assert_eq!(report.expected_losses, 10);
+
+        let pnl_wei = -10 * (CHALLENGER_BOND_WEI as i128 + gas_cost_wei());
+        assert_eq!(report.expected_pnl_wei, I256::try_from(pnl_wei).unwrap());
+        let roi_pct = pnl_wei as f64 / (10.0 * CHALLENGER_BOND_WEI as f64) * 100.0;
+        assert!((report.roi_pct - roi_pct).abs() < 1e-9);
+        assert!(report.roi_pct < -100.0);
+    }
+
+    #[test]
+    fn test_roi_of_out_of_range_loss_is_negative() {
+        let report = simulate_challenge_season(SimulationParams {
+            num_proposals: 1,
+            challenger_bond_wei: U256::from(u128::MAX),
+            ..params(1.0, 1.0)
+        });
+        assert_eq!(report.expected_losses, 1);
+        assert!(i128::try_from(report.expected_pnl_wei).is_err());
+        assert!(report.roi_pct < 0.0);
+    }
+}
@@ -1,17 +1,24 @@
-use std::{env, sync::Arc};
+use std::{env, path::PathBuf, sync::Arc, time::Duration};
 
-use alloy_primitives::Address;
-use alloy_provider::ProviderBuilder;
+use alloy_primitives::{Address, B256, U256};
 use alloy_transport_http::reqwest::Url;
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
 use fault_proof::{
-    contract::DisputeGameFactory, prometheus::ProposerGauge, proposer::OPSuccinctProposer,
-    utils::setup_logging,
+    audit::read_audit_entries,
+    config::{load_config_file, ProposerConfig},
+    contract::DisputeGameFactory,
+    health::{init_health_server, Route, RouteBody},
+    prometheus::{ProposerGauge, ProposerHistogram},
+    proposer::OPSuccinctProposer,
+    rpc_limit::{init_rpc_limits, RpcLimits},
+    utils::{connect_provider, setup_logging},
+    FactoryTrait, L1Provider,
 };
+use futures::{future::try_join_all, FutureExt};
 use op_succinct_host_utils::{
     fetcher::OPSuccinctDataFetcher,
-    metrics::{init_metrics, MetricsGauge},
+    metrics::{init_metrics_with_histograms, MetricsGauge, MetricsHistogram},
 };
 use op_succinct_proof_utils::initialize_host;
 use op_succinct_signer_utils::Signer;
@@ -20,27 +27,134 @@ use op_succinct_signer_utils::Signer;
 struct Args {
     #[arg(long, default_value = ".env.proposer")]
     env_file: String,
+
+    /// A TOML config file. Environment variables, including those of the env file,
+    /// take precedence over its settings.
+    #[arg(long)]
+    config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    setup_logging();
+#[derive(Subcommand)]
+enum Command {
+    /// Benchmark witness generation for a range of L2 blocks without proving.
+    BenchmarkWitness {
+        /// The first L2 block of the range.
+        #[arg(long)]
+        from: u64,
+        /// The last L2 block of the range.
+        #[arg(long)]
+        to: u64,
+    },
+    /// Rank all proposers of the game type by their on-chain activity.
+    Leaderboard {
+        /// The L1 block to start scanning events from.
+        #[arg(long)]
+        from_block: u64,
+    },
+    /// Estimate the maximum L2 throughput the fault proof system can support.
+    ThroughputEstimate,
+    /// Create, optionally prove, and resolve a number of games in succession for load testing.
+    StressTest {
+        /// The number of games to create.
+        #[arg(long)]
+        count: u32,
+        /// The number of L2 blocks between the games, overriding PROPOSAL_INTERVAL_IN_BLOCKS.
+        #[arg(long)]
+        interval_blocks: u64,
+        /// Prove the games with mock proofs.
+        #[arg(long)]
+        mock: bool,
+        /// How long to wait for the games to be resolved after the last one was created, in
+        /// seconds.
+        #[arg(long, default_value_t = 7200)]
+        resolve_timeout_secs: u64,
+    },
+    /// Print the ETH financial position of the proposer.
+    FinancialSummary,
+    /// Check whether the proposer can be restarted with new circuit verification keys.
+    CheckUpgradeReadiness {
+        /// The new range verification key commitment.
+        #[arg(long)]
+        new_range_vk: B256,
+        /// The new aggregation verification key.
+        #[arg(long)]
+        new_agg_vk: B256,
+    },
+    /// Estimate how many days the proposer must operate to recoup its deployment costs.
+    BreakEven {
+        /// The deployment cost to recoup, in wei.
+        #[arg(long)]
+        deployment_cost_wei: U256,
+    },
+    /// Generate a mock proof for the game at an L2 block and simulate proving it, without
+    /// broadcasting.
+    TestMockProof {
+        /// The L2 block number proposed by the game.
+        #[arg(long)]
+        l2_block: u64,
+    },
+    /// Generate and submit a proof for a game, e.g. to defend a challenged game after a prover
+    /// outage.
+    Prove {
+        /// The index of the game in the factory.
+        #[arg(long)]
+        game_index: U256,
+        /// Prove the game with a mock proof.
+        #[arg(long)]
+        mock: bool,
+    },
+    /// Print the chain of games from the root game to a game, following their parent indices.
+    GameChain {
+        /// The index of the game in the factory.
+        #[arg(long)]
+        game_index: U256,
+    },
+    /// Resolve every resolvable game, e.g. to clear the backlog after resolution was disabled,
+    /// and exit.
+    ResolveAll,
+    /// Print the audit trail entries recorded since a timestamp as JSON lines.
+    AuditExport {
+        /// The unix timestamp in seconds to export entries from.
+        #[arg(long, default_value_t = 0)]
+        from_timestamp: u64,
+    },
+}
 
+fn main() -> Result<()> {
     let args = Args::parse();
-    dotenv::from_filename(args.env_file).ok();
+
+    // Setting environment variables isn't thread-safe, so the env file and config file are loaded
+    // before the runtime starts its worker threads.
+    dotenv::from_filename(&args.env_file).ok();
+    if let Some(config) = &args.config {
+        load_config_file(config)?;
+    }
+
+    tokio::runtime::Runtime::new()?.block_on(run(args))
+}
+
+async fn run(args: Args) -> Result<()> {
+    setup_logging();
+
+    if let Some(Command::AuditExport { from_timestamp }) = args.command {
+        let path = env::var("AUDIT_LOG_PATH").context("AUDIT_LOG_PATH is not set")?;
+        for entry in read_audit_entries(path.as_ref(), from_timestamp)? {
+            println!("{}", serde_json::to_string(&entry)?);
+        }
+        return Ok(());
+    }
 
     let proposer_signer = Signer::from_env()?;
+    init_rpc_limits(RpcLimits::from_env()?);
 
-    let l1_provider =
-        ProviderBuilder::new().connect_http(env::var("L1_RPC").unwrap().parse::<Url>().unwrap());
+    let l1_provider: L1Provider =
+        connect_provider(&env::var("L1_RPC").unwrap().parse::<Url>().unwrap()).await?;
 
-    let factory = DisputeGameFactory::new(
-        env::var("FACTORY_ADDRESS")
-            .expect("FACTORY_ADDRESS must be set")
-            .parse::<Address>()
-            .unwrap(),
-        l1_provider.clone(),
-    );
+    let factory =
+        DisputeGameFactory::new(ProposerConfig::from_env()?.factory_address, l1_provider.clone());
 
     // Use PROVER_ADDRESS from env if available, otherwise use wallet's default signer address from
     // the private key.
@@ -51,22 +165,194 @@ async fn main() -> Result<()> {
 
     let fetcher = OPSuccinctDataFetcher::new_with_rollup_config().await?;
     let host = initialize_host(Arc::new(fetcher.clone()));
-    let proposer = Arc::new(
+    let mut proposer =
         OPSuccinctProposer::new(prover_address, proposer_signer, factory, Arc::new(fetcher), host)
             .await
-            .unwrap(),
-    );
+            .unwrap();
+    if let Some(Command::StressTest { interval_blocks, mock, .. }) = args.command {
+        // Games are proved explicitly by the stress test, and only with mock proofs.
+        proposer.config.proposal_interval_in_blocks = interval_blocks;
+        proposer.config.mock_mode = mock;
+        proposer.config.fast_finality_mode = false;
+    }
+    if let Some(Command::Prove { mock: true, .. }) = args.command {
+        proposer.config.mock_mode = true;
+    }
+    let proposer = Arc::new(proposer);
+
+    match args.command {
+        Some(Command::BenchmarkWitness { from, to }) => {
+            let benchmark = proposer.benchmark_witness_generation(from, to).await?;
+            println!("Witness generation benchmark for L2 blocks {from}..={to}:");
+            println!("  Fetch duration:   {:?}", benchmark.fetch_duration);
+            println!("  Run duration:     {:?}", benchmark.run_duration);
+            println!("  Total duration:   {:?}", benchmark.total_duration);
+            println!("  Estimated cycles: {}", benchmark.estimated_cycles);
+            return Ok(());
+        }
+        Some(Command::Leaderboard { from_block }) => {
+            let leaderboard = proposer.get_proposer_leaderboard(from_block).await?;
+            println!(
+                "{:<42} {:>9} {:>9} {:>10} {:>6} {:>24}",
+                "Proposer", "Submitted", "Resolved", "Challenged", "Lost", "Net bond outcome (wei)"
+            );
+            for stats in leaderboard {
+                println!(
+                    "{:<42} {:>9} {:>9} {:>10} {:>6} {:>24}",
+                    stats.address.to_string(),
+                    stats.proposals_submitted,
+                    stats.proposals_resolved,
+                    stats.proposals_challenged,
+                    stats.proposals_lost,
+                    stats.net_bond_outcome_wei.to_string()
+                );
+            }
+            return Ok(());
+        }
+        Some(Command::ThroughputEstimate) => {
+            let report = proposer.compute_theoretical_max_tps().await?;
+            println!("Max L2 blocks per day: {}", report.max_l2_blocks_per_day);
+            println!("Max transactions per day: {}", report.max_txs_per_day);
+            println!("Bottleneck: {:?}", report.bottleneck);
+            return Ok(());
+        }
+        Some(Command::StressTest { count, resolve_timeout_secs, .. }) => {
+            let report =
+                proposer.stress_test(count, Duration::from_secs(resolve_timeout_secs)).await?;
+            println!("Stress test of {} games:", report.proposals_created);
+            println!("  Average create latency:  {:?}", report.avg_create_latency);
+            if let Some(avg_prove_latency) = report.avg_prove_latency {
+                println!("  Average prove latency:   {avg_prove_latency:?}");
+            }
+            println!("  Average resolve latency: {:?}", report.avg_resolve_latency);
+            println!("  Total gas spent:         {} wei", report.total_gas_spent_wei);
+            if !report.unresolved_games.is_empty() {
+                println!("  Unresolved games:        {:?}", report.unresolved_games);
+            }
+            return Ok(());
+        }
+        Some(Command::CheckUpgradeReadiness { new_range_vk, new_agg_vk }) => {
+            let report = proposer.check_circuit_upgrade_readiness(new_range_vk, new_agg_vk).await?;
+            println!(
+                "Range vkey commitment: {} -> {}",
+                report.current_range_vk, report.new_range_vk
+            );
+            println!("Aggregation vkey:      {} -> {}", report.current_agg_vk, report.new_agg_vk);
+            println!("Pending proofs:        {}", report.has_pending_proofs);
+            println!("Can upgrade now:       {} ({})", report.can_upgrade_now, report.reason);
+            return Ok(());
+        }
+        Some(Command::BreakEven { deployment_cost_wei }) => {
+            let report = proposer.compute_break_even_period(deployment_cost_wei).await?;
+            println!("Deployment cost:          {} wei", report.deployment_cost_wei);
+            println!("Expected daily gas cost:  {} wei", report.expected_daily_gas_cost_wei);
+            println!("Expected daily earnings:  {} wei", report.expected_daily_bond_earnings_wei);
+            match report.break_even_days {
+                Some(days) => println!("Break-even period:        {days:.1} days"),
+                None => println!("Break-even period:        never, daily costs exceed earnings"),
+            }
+            return Ok(());
+        }
+        Some(Command::FinancialSummary) => {
+            let summary = proposer.get_financial_summary().await?;
+            println!("Signer balance:            {} wei", summary.signer_balance);
+            println!("Bonds in active proposals: {} wei", summary.bonds_in_active_proposals);
+            println!("Unclaimed credit:          {} wei", summary.unclaimed_credit);
+            println!("Total gas spent:           {} wei", summary.total_gas_spent);
+            println!("Net position:              {} wei", summary.net_position);
+            return Ok(());
+        }
+        Some(Command::TestMockProof { l2_block }) => {
+            match proposer.test_mock_proof_generation(l2_block).await {
+                Ok(()) => println!("PASS: mock proof for L2 block {l2_block} would be accepted"),
+                Err(e) => println!("FAIL: mock proof for L2 block {l2_block}: {e:?}"),
+            }
+            return Ok(());
+        }
+        Some(Command::Prove { game_index, .. }) => {
+            let tx_hash = proposer.prove_game_at_index(game_index).await?;
+            println!("Proved game at index {game_index} with tx {tx_hash}");
+            return Ok(());
+        }
+        Some(Command::GameChain { game_index }) => {
+            let chain = proposer.factory.get_game_chain(game_index).await?;
+            println!(
+                "{:>6} {:<42} {:>12} {:<42} {:<42} {:<32} {:<15}",
+                "Index",
+                "Game",
+                "L2 block",
+                "Proposer",
+                "Challenger",
+                "Proposal status",
+                "Game status"
+            );
+            for entry in chain {
+                let challenger = match entry.claim_data.counteredBy {
+                    Address::ZERO => "-".to_string(),
+                    challenger => challenger.to_string(),
+                };
+                // Derived `Debug` ignores the width, so the statuses are padded as strings.
+                let proposal_status = format!("{:?}", entry.claim_data.status);
+                let game_status = format!("{:?}", entry.status);
+                println!(
+                    "{:>6} {:<42} {:>12} {:<42} {:<42} {:<32} {:<15}",
+                    entry.index.to_string(),
+                    entry.address.to_string(),
+                    entry.l2_block_number.to_string(),
+                    entry.game_creator.to_string(),
+                    challenger,
+                    proposal_status,
+                    game_status
+                );
+            }
+            return Ok(());
+        }
+        Some(Command::ResolveAll) => {
+            let resolved = proposer.resolve_all().await?;
+            println!("Resolved {resolved} games");
+            return Ok(());
+        }
+        Some(Command::AuditExport { .. }) | None => {}
+    }
 
-    // Initialize proposer gauges.
+    // Initialize proposer gauges and histograms.
     ProposerGauge::register_all();
+    ProposerHistogram::register_all();
 
     // Initialize metrics exporter.
-    init_metrics(&proposer.config.metrics_port);
+    init_metrics_with_histograms::<ProposerHistogram>(&proposer.config.metrics_port);
+    let financial_summary: RouteBody = {
+        let proposer = proposer.clone();
+        Arc::new(move || {
+            let proposer = proposer.clone();
+            async move { Ok(serde_json::to_string(&proposer.get_financial_summary().await?)?) }
+                .boxed()
+        })
+    };
+    let proposal_metrics: RouteBody = {
+        let proposer = proposer.clone();
+        Arc::new(move || {
+            let proposer = proposer.clone();
+            async move { Ok(proposer.render_proposal_metrics().await) }.boxed()
+        })
+    };
+    init_health_server(
+        proposer.config.health_port,
+        proposer.health.clone(),
+        vec![
+            Route::json("/financial-summary", financial_summary),
+            Route::prometheus("/proposal-metrics", proposal_metrics),
+        ],
+    );
 
-    // Initialize the metrics gauges.
-    ProposerGauge::init_all();
+    // Propose to the additional factories from the same process, sharing the providers and prover.
+    let mut proposers = vec![proposer.clone()];
+    for &factory_address in &proposer.config.additional_factory_addresses {
+        let factory = DisputeGameFactory::new(factory_address, l1_provider.clone());
+        proposers.push(Arc::new(proposer.for_factory(factory).await?));
+    }
 
-    proposer.run().await.expect("Runs in an infinite loop");
+    try_join_all(proposers.into_iter().map(|proposer| proposer.run(None))).await?;
 
     Ok(())
 }
@@ -0,0 +1,137 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::{Address, TxHash};
+use alloy_rpc_types_eth::TransactionReceipt;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// The number of audit entries buffered before new entries are dropped.
+const AUDIT_CHANNEL_CAPACITY: usize = 1024;
+
+/// An action taken by the proposer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditAction {
+    Submit,
+    Challenge,
+    Prove,
+    Resolve,
+    ClaimBond,
+    Skip,
+}
+
+/// A single entry of the audit trail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// The unix timestamp in seconds at which the action was taken.
+    pub timestamp: u64,
+    pub action: AuditAction,
+    /// The address of the game the action was taken on.
+    pub game_address: Address,
+    pub operator_address: Address,
+    pub tx_hash: Option<TxHash>,
+    pub reason_skipped: Option<String>,
+    pub gas_used: Option<u64>,
+}
+
+impl AuditEntry {
+    /// Creates an entry for an action performed with the given transaction.
+    pub fn performed(
+        action: AuditAction,
+        game_address: Address,
+        operator_address: Address,
+        receipt: &TransactionReceipt,
+    ) -> Self {
+        Self {
+            timestamp: unix_timestamp(),
+            action,
+            game_address,
+            operator_address,
+            tx_hash: Some(receipt.transaction_hash),
+            reason_skipped: None,
+            gas_used: Some(receipt.gas_used),
+        }
+    }
+
+    /// Creates an entry for an action that was skipped.
+    pub fn skipped(game_address: Address, operator_address: Address, reason: String) -> Self {
+        Self {
+            timestamp: unix_timestamp(),
+            action: AuditAction::Skip,
+            game_address,
+            operator_address,
+            tx_hash: None,
+            reason_skipped: Some(reason),
+            gas_used: None,
+        }
+    }
+}
+
+/// An append-only audit trail of proposer actions, stored as newline-delimited JSON.
+///
+/// Entries are handed to a background task that appends them to the log file, so that recording
+/// an entry never blocks on disk I/O.
+#[derive(Debug, Clone)]
+pub struct ProposalAuditTrail {
+    sender: mpsc::Sender<AuditEntry>,
+}
+
+impl ProposalAuditTrail {
+    /// Opens the audit log at `path` for appending and spawns the background writer task.
+    pub fn spawn(path: PathBuf) -> Result<Self> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open audit log {}", path.display()))?;
+
+        let (sender, mut receiver) = mpsc::channel::<AuditEntry>(AUDIT_CHANNEL_CAPACITY);
+        tokio::spawn(async move {
+            while let Some(entry) = receiver.recv().await {
+                let result = serde_json::to_string(&entry)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|line| writeln!(file, "{line}").map_err(anyhow::Error::from));
+                if let Err(e) = result {
+                    tracing::warn!("Failed to write audit entry {:?}: {:?}", entry, e);
+                }
+            }
+        });
+
+        Ok(Self { sender })
+    }
+
+    /// Records an entry in the audit trail.
+    pub fn record(&self, entry: AuditEntry) {
+        if let Err(e) = self.sender.try_send(entry) {
+            tracing::warn!("Failed to record audit entry: {:?}", e);
+        }
+    }
+}
+
+/// Reads the entries of the audit log at `path` recorded at or after `from_timestamp`.
+pub fn read_audit_entries(path: &Path, from_timestamp: u64) -> Result<Vec<AuditEntry>> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open audit log {}", path.display()))?;
+
+    let mut entries = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: AuditEntry = serde_json::from_str(&line)?;
+        if entry.timestamp >= from_timestamp {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
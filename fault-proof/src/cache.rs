@@ -0,0 +1,260 @@
+use std::{future::Future, num::NonZeroUsize, sync::Arc};
+
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{Address, FixedBytes, B256, U256};
+use alloy_rpc_types_eth::Block;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use lru::LruCache;
+use op_alloy_rpc_types::Transaction;
+use op_succinct_host_utils::metrics::MetricsGauge;
+use tokio::sync::Mutex;
+
+use crate::{
+    fetch_output_root_at_block,
+    prometheus::{ChallengerGauge, ProposerGauge, ProposerMetrics},
+    L2NodeProvider, L2Provider, L2ProviderTrait, Mode,
+};
+
+/// An L2 provider that caches the output roots it computes for finalized blocks.
+///
+/// Scanning games computes the output root of the same blocks on every tick. The output root of a
+/// finalized block never changes, so cached entries are never invalidated. Output roots of blocks
+/// above the finalized head are computed but not cached, as they may still be reorged.
+///
+/// If a rollup node provider is given, output roots are fetched from it with
+/// `optimism_outputAtBlock` first, and only computed from the L2 RPC if that fails.
+#[derive(Debug, Clone)]
+pub struct CachingL2Provider {
+    inner: L2Provider,
+    l2_node_provider: Option<L2NodeProvider>,
+    /// The mode whose metrics cache hits and misses are recorded in.
+    mode: Mode,
+    /// The factory whose proposer metrics cache hits and misses are labeled with.
+    factory: Address,
+    output_roots: Arc<Mutex<OutputRootCache>>,
+}
+
+impl CachingL2Provider {
+    pub fn new(
+        inner: L2Provider,
+        l2_node_provider: Option<L2NodeProvider>,
+        mode: Mode,
+        factory: Address,
+        capacity: usize,
+    ) -> Result<Self> {
+        let capacity =
+            NonZeroUsize::new(capacity).context("Output root cache size must be non-zero")?;
+        Ok(Self {
+            inner,
+            l2_node_provider,
+            mode,
+            factory,
+            output_roots: Arc::new(Mutex::new(OutputRootCache::new(capacity))),
+        })
+    }
+
+    /// Returns a provider sharing the cached output roots of this one, whose metrics are labeled
+    /// with `factory` instead.
+    pub fn for_factory(&self, factory: Address) -> Self {
+        Self { inner: self.inner.for_factory(factory), factory, ..self.clone() }
+    }
+
+    /// Returns the cached output root at `l2_block_number`, or computes it with `compute` and
+    /// caches it if the block is finalized.
+    async fn get_or_compute<F>(&self, l2_block_number: U256, compute: F) -> Result<B256>
+    where
+        F: Future<Output = Result<B256>> + Send,
+    {
+        let block_number = l2_block_number.to::<u64>();
+        if let Some(output_root) = self.output_roots.lock().await.get(block_number) {
+            match self.mode {
+                Mode::Proposer => ProposerMetrics::new(self.factory)
+                    .increment(ProposerGauge::OutputRootCacheHits, 1.0),
+                Mode::Challenger => ChallengerGauge::OutputRootCacheHits.increment(1.0),
+            }
+            return Ok(output_root);
+        }
+
+        match self.mode {
+            Mode::Proposer => ProposerMetrics::new(self.factory)
+                .increment(ProposerGauge::OutputRootCacheMisses, 1.0),
+            Mode::Challenger => ChallengerGauge::OutputRootCacheMisses.increment(1.0),
+        }
+        let output_root = compute.await?;
+        self.update_finalized(block_number).await?;
+        self.output_roots.lock().await.insert(block_number, output_root);
+        Ok(output_root)
+    }
+
+    /// Fetches the output root from the rollup node, if one is configured, falling back to `compute`
+    /// on error.
+    async fn fetch_or_compute<F>(&self, l2_block_number: U256, compute: F) -> Result<B256>
+    where
+        F: Future<Output = Result<B256>> + Send,
+    {
+        if let Some(l2_node_provider) = &self.l2_node_provider {
+            match fetch_output_root_at_block(l2_node_provider, l2_block_number).await {
+                Ok(output_root) => {
+                    tracing::debug!(
+                        "Fetched output root at block {} with optimism_outputAtBlock",
+                        l2_block_number
+                    );
+                    return Ok(output_root);
+                }
+                Err(e) => tracing::debug!(
+                    "optimism_outputAtBlock failed at block {}, computing the output root locally: {:?}",
+                    l2_block_number,
+                    e
+                ),
+            }
+        }
+
+        tracing::debug!("Computing output root at block {} locally", l2_block_number);
+        compute.await
+    }
+
+    /// Re-fetches the finalized head if the L2 block is above the highest finalized block seen so
+    /// far.
+    async fn update_finalized(&self, block_number: u64) -> Result<()> {
+        if self.output_roots.lock().await.is_finalized(block_number) {
+            return Ok(());
+        }
+
+        let finalized_block_number =
+            self.inner.get_l2_block_by_number(BlockNumberOrTag::Finalized).await?.header.number;
+        self.output_roots.lock().await.update_finalized(finalized_block_number);
+        Ok(())
+    }
+}
+
+/// The output roots of finalized L2 blocks, evicting the least recently used one when full.
+#[derive(Debug)]
+struct OutputRootCache {
+    output_roots: LruCache<u64, B256>,
+    /// The highest finalized L2 block number seen so far.
+    finalized_block_number: u64,
+}
+
+impl OutputRootCache {
+    fn new(capacity: NonZeroUsize) -> Self {
+        Self { output_roots: LruCache::new(capacity), finalized_block_number: 0 }
+    }
+
+    fn get(&mut self, block_number: u64) -> Option<B256> {
+        self.output_roots.get(&block_number).copied()
+    }
+
+    /// Whether the L2 block is at or below the highest finalized block seen so far.
+    fn is_finalized(&self, block_number: u64) -> bool {
+        block_number <= self.finalized_block_number
+    }
+
+    /// Records the finalized head. The finalized head never moves back, even if a lagging node
+    /// reports an older one.
+    fn update_finalized(&mut self, finalized_block_number: u64) {
+        self.finalized_block_number = self.finalized_block_number.max(finalized_block_number);
+    }
+
+    /// Caches the output root of the L2 block if it is finalized.
+    fn insert(&mut self, block_number: u64, output_root: B256) {
+        if self.is_finalized(block_number) {
+            self.output_roots.put(block_number, output_root);
+        }
+    }
+}
+
+#[async_trait]
+impl L2ProviderTrait for CachingL2Provider {
+    fn l2_to_l1_message_passer(&self) -> Address {
+        self.inner.l2_to_l1_message_passer()
+    }
+
+    async fn get_l2_block_by_number(
+        &self,
+        block_number: BlockNumberOrTag,
+    ) -> Result<Block<Transaction>> {
+        self.inner.get_l2_block_by_number(block_number).await
+    }
+
+    async fn get_l2_storage_root(
+        &self,
+        address: Address,
+        block_number: BlockNumberOrTag,
+    ) -> Result<B256> {
+        self.inner.get_l2_storage_root(address, block_number).await
+    }
+
+    async fn compute_output_root_at_block(&self, l2_block_number: U256) -> Result<FixedBytes<32>> {
+        self.get_or_compute(
+            l2_block_number,
+            self.fetch_or_compute(
+                l2_block_number,
+                self.inner.compute_output_root_at_block(l2_block_number),
+            ),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(capacity: usize, finalized_block_number: u64) -> OutputRootCache {
+        let mut cache = OutputRootCache::new(NonZeroUsize::new(capacity).unwrap());
+        cache.update_finalized(finalized_block_number);
+        cache
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let mut cache = cache(2, 100);
+        cache.insert(1, B256::repeat_byte(1));
+        cache.insert(2, B256::repeat_byte(2));
+
+        // Reading block 1 makes block 2 the least recently used.
+        assert_eq!(cache.get(1), Some(B256::repeat_byte(1)));
+        cache.insert(3, B256::repeat_byte(3));
+
+        assert_eq!(cache.get(1), Some(B256::repeat_byte(1)));
+        assert_eq!(cache.get(2), None);
+        assert_eq!(cache.get(3), Some(B256::repeat_byte(3)));
+    }
+
+    #[test]
+    fn test_caches_only_finalized_blocks() {
+        let mut cache = cache(10, 100);
+        cache.insert(99, B256::repeat_byte(1));
+        cache.insert(100, B256::repeat_byte(2));
+        cache.insert(101, B256::repeat_byte(3));
+
+        assert_eq!(cache.get(99), Some(B256::repeat_byte(1)));
+        assert_eq!(cache.get(100), Some(B256::repeat_byte(2)));
+        assert_eq!(cache.get(101), None);
+
+        // Once the block is finalized, its output root is cached.
+        cache.update_finalized(101);
+        cache.insert(101, B256::repeat_byte(3));
+        assert_eq!(cache.get(101), Some(B256::repeat_byte(3)));
+    }
+
+    #[test]
+    fn test_finalized_head_never_moves_back() {
+        let mut cache = cache(10, 100);
+        cache.update_finalized(50);
+
+        assert!(cache.is_finalized(100));
+        assert!(!cache.is_finalized(101));
+    }
+
+    #[test]
+    fn test_nothing_is_finalized_initially() {
+        let mut cache = OutputRootCache::new(NonZeroUsize::new(10).unwrap());
+        assert!(cache.is_finalized(0));
+        assert!(!cache.is_finalized(1));
+
+        cache.insert(1, B256::repeat_byte(1));
+        assert_eq!(cache.get(1), None);
+    }
+}
@@ -1,8 +1,39 @@
-use std::env;
+use std::{
+    collections::{HashMap, HashSet},
+    env, fs,
+    path::{Path, PathBuf},
+};
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256, I256, U256};
+use alloy_provider::Provider;
 use alloy_transport_http::reqwest::Url;
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
+use op_alloy_network::Network;
+use sp1_sdk::{network::FulfillmentStrategy, SP1ProofMode};
+use strum::IntoEnumIterator;
+use strum_macros::EnumString;
+use toml::{Table, Value};
+
+use crate::{
+    contract::DisputeGameFactory::DisputeGameFactoryInstance, notify::NotificationEvent,
+    rpc_limit::RpcLimits, utils::RetryPolicy, FactoryTrait, L1Provider, L2Provider,
+    ProposerPriority, ValidProposalSearchStrategy, DEFAULT_NETWORK_PRIVATE_KEY,
+    L2_TO_L1_MESSAGE_PASSER,
+};
+
+/// How rigorously the proposer verifies the output root of a new game before proposing it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum OutputRootVerificationMode {
+    /// Compute the output root from the L2 block header and the message passer storage root.
+    #[default]
+    Full,
+    /// Use the rollup node's output root after checking its block hash matches the L2 block.
+    BlockHashOnly,
+    /// Use the rollup node's output root without any verification. Only for testing.
+    #[cfg(feature = "testing")]
+    Skip,
+}
 
 #[derive(Debug, Clone)]
 pub struct ProposerConfig {
@@ -12,9 +43,17 @@ pub struct ProposerConfig {
     /// The L2 RPC URL.
     pub l2_rpc: Url,
 
+    /// The address of the `L2ToL1MessagePasser` whose storage root is committed to in output roots.
+    /// Only differs from the predeploy on chains that remap it.
+    pub l2_to_l1_message_passer: Address,
+
     /// The address of the factory contract.
     pub factory_address: Address,
 
+    /// The addresses of further factory contracts proposed to by the same process, sharing its
+    /// providers and prover.
+    pub additional_factory_addresses: Vec<Address>,
+
     /// Whether to use mock mode.
     pub mock_mode: bool,
 
@@ -24,6 +63,10 @@ pub struct ProposerConfig {
     /// The interval in blocks between proposing new games.
     pub proposal_interval_in_blocks: u64,
 
+    /// The maximum number of games created in succession per tick, each chained off the previous
+    /// one, to catch up when the finalized L2 head is several proposal intervals ahead.
+    pub max_proposals_per_tick: u64,
+
     /// The interval in seconds between checking for new proposals and game resolution.
     /// During each interval, the proposer:
     /// 1. Checks the safe L2 head block number
@@ -32,12 +75,52 @@ pub struct ProposerConfig {
     /// 4. Optionally attempts to resolve unchallenged games
     pub fetch_interval: u64,
 
+    /// The lower bound in seconds for the dynamic interval between ticks.
+    pub min_fetch_interval: u64,
+
+    /// The upper bound in seconds for the dynamic interval between ticks. When the finalized L2
+    /// head is far behind the next proposal block, the proposer waits up to this long between
+    /// ticks. Setting it equal to `min_fetch_interval` disables the dynamic interval.
+    pub max_fetch_interval: u64,
+
+    /// The maximum random jitter added to the interval between ticks, as a percentage of it.
+    pub fetch_interval_jitter_pct: f64,
+
+    /// The number of seconds to wait before retrying to create a game for the same L2 block after
+    /// a failed attempt.
+    pub creation_retry_backoff_secs: u64,
+
+    /// The number of consecutive failed game creations after which game creation is paused for
+    /// `creation_circuit_breaker_cooldown_secs`. Zero disables the circuit breaker.
+    pub max_consecutive_creation_failures: u32,
+
+    /// The number of seconds game creation stays paused once the circuit breaker trips.
+    pub creation_circuit_breaker_cooldown_secs: u64,
+
+    /// The minimum number of seconds between the last proposal of the game type, as tracked by
+    /// the access manager, and a new game. Zero disables the check.
+    pub min_proposal_interval_secs: u64,
+
     /// The type of game to propose.
     pub game_type: u32,
 
     /// The number of games to check for defense.
     pub max_games_to_check_for_defense: u64,
 
+    /// The number of recent games counted by proposal status for the metrics.
+    pub max_games_to_count_by_status: u64,
+
+    /// How often, in seconds, the access manager is asked whether the signer is allowed to
+    /// propose. Game creation is skipped while it isn't.
+    pub whitelist_check_interval_secs: u64,
+
+    /// The maximum number of games checked when searching for the latest valid proposal before
+    /// failing, so that a long run of invalid games can not stall each tick.
+    pub max_games_to_check_for_valid_proposal: u64,
+
+    /// How the games are searched for the latest valid proposal.
+    pub valid_proposal_search_strategy: ValidProposalSearchStrategy,
+
     /// Whether to enable game resolution.
     /// When game resolution is not enabled, the proposer will only propose new games.
     pub enable_game_resolution: bool,
@@ -52,18 +135,126 @@ pub struct ProposerConfig {
 
     /// Whether to fallback to timestamp-based L1 head estimation even though SafeDB is not
     /// activated for op-node.
+    ///
+    /// The host only estimates an L1 head when it isn't given one, i.e. when benchmarking witness
+    /// generation. Proofs of games use the L1 head of the game, unless the host derives its own.
     pub safe_db_fallback: bool,
 
+    /// The `safe_db_fallback` used when fetching the witness to prove a game, which defaults to
+    /// `safe_db_fallback`. Proving historical games may need the fallback where nothing else
+    /// does.
+    pub prove_safe_db_fallback: bool,
+
     /// The metrics port.
     pub metrics_port: u16,
+
+    /// The port to serve the `/healthz` and `/readyz` health check endpoints on.
+    pub health_port: u16,
+
+    /// The number of seconds after the last successful tick after which `/healthz` reports the
+    /// service as unhealthy.
+    pub health_staleness_secs: u64,
+
+    /// The expected L1 chain ID. When set, game creation is paused if the L1 RPC reports a
+    /// different chain ID.
+    pub expected_l1_chain_id: Option<u64>,
+
+    /// The expected L2 chain ID. When set, game creation is paused if the L2 RPC reports a
+    /// different chain ID, e.g. because the L2 node was restarted against a fork.
+    pub expected_l2_chain_id: Option<u64>,
+
+    /// How the output root of a new game is verified before proposing it.
+    pub output_root_verification_mode: OutputRootVerificationMode,
+
+    /// Whether to fetch output roots from the rollup node with `optimism_outputAtBlock` when
+    /// scanning games, falling back to computing them from the L2 RPC on error.
+    pub prefer_output_at_block_rpc: bool,
+
+    /// The rollup node RPC URL. Required if `prefer_output_at_block_rpc` is set.
+    pub l2_node_rpc: Option<Url>,
+
+    /// The maximum size in bytes of the range proof stdin. Witnesses above this size are rejected
+    /// before being submitted to the prover network.
+    pub max_stdin_bytes: usize,
+    /// The maximum time in seconds to wait for a range or aggregation proof from the prover
+    /// network.
+    pub proof_timeout_secs: u64,
+    /// The fulfillment strategy of range proof requests to the prover network.
+    pub range_proof_strategy: FulfillmentStrategy,
+    /// The proof system of the aggregation proof, which must match the verifier the game uses.
+    /// Either Groth16 or PLONK.
+    pub agg_proof_mode: SP1ProofMode,
+    /// The aggregation verification key the game was deployed with, which the embedded
+    /// aggregation ELF is checked against at startup.
+    pub aggregation_vkey: Option<B256>,
+    /// The range verification key commitment the game was deployed with, which the embedded
+    /// range ELF is checked against at startup.
+    pub range_vkey_commitment: Option<B256>,
+    /// The maximum number of cycles a range proof may use.
+    pub range_proof_cycle_limit: u64,
+    /// Whether to skip simulating range proofs before requesting them, which also skips
+    /// estimating their cycle count.
+    pub skip_simulation: bool,
+    /// The path of the newline-delimited JSON file the proposer appends its actions to. The audit
+    /// trail is disabled when unset.
+    pub audit_log_path: Option<PathBuf>,
+    /// The directory the journal of in-flight operations is kept in, so that they can be resumed
+    /// after a crash. The journal is disabled when unset.
+    pub state_dir: Option<PathBuf>,
+    /// The number of seconds without a new L1 block after which the L1 chain is considered
+    /// halted, and all proposer operations are paused.
+    pub l1_halt_threshold_secs: u64,
+    /// The expected maximum size in bytes of a submitted aggregation proof. Larger proofs are
+    /// logged, as they increase the calldata cost of proving.
+    pub max_expected_proof_size_bytes: u64,
+    /// How transient failures of transaction submission are retried.
+    pub tx_retry_policy: RetryPolicy,
+    /// The percentile of recent priority fees used as the max priority fee of L1 transactions.
+    pub l1_fee_percentile: f64,
+    /// The ceiling in gwei of the max fee per gas of L1 transactions.
+    pub max_fee_per_gas_gwei: u64,
+    /// The maximum number of output roots of finalized L2 blocks kept in memory.
+    pub output_root_cache_size: usize,
+    /// The number of game addresses fetched concurrently when scanning a window of games.
+    pub game_fetch_batch_size: usize,
+    /// The number of games checked concurrently when searching for the oldest game to challenge or
+    /// defend.
+    pub scan_concurrency: usize,
+    /// The limits on the requests sent to the L1 and L2 RPCs, shared by all providers.
+    pub rpc_limits: RpcLimits,
+    /// Whether to log transactions instead of sending them. Actions that depend on the receipt of
+    /// a transaction, such as discovering the address of a created game, are skipped.
+    pub dry_run: bool,
+    /// The signer balance in wei below which a low balance warning is logged. Zero disables it.
+    pub min_balance_wei: U256,
+    /// The number of seconds before the prove deadline of a challenged game below which a warning
+    /// is logged, if the game still isn't proven.
+    pub deadline_alert_threshold_secs: u64,
+    /// The address claimed bonds are forwarded to, e.g. a cold treasury. Bonds stay with the
+    /// signer when unset.
+    pub bond_recipient: Option<Address>,
+    /// The Slack or Discord compatible webhook notifications are posted to. Notifications are
+    /// disabled when unset.
+    pub notification_webhook_url: Option<Url>,
+    /// The events notifications are posted for.
+    pub notification_events: HashSet<NotificationEvent>,
 }
 
 impl ProposerConfig {
     pub fn from_env() -> Result<Self> {
+        let fetch_interval = env::var("FETCH_INTERVAL").unwrap_or("30".to_string()).parse()?;
+        let prefer_output_at_block_rpc =
+            env::var("PREFER_OUTPUT_AT_BLOCK_RPC").unwrap_or("false".to_string()).parse()?;
+        let safe_db_fallback =
+            env::var("SAFE_DB_FALLBACK").unwrap_or("false".to_string()).parse()?;
+        let mut factory_addresses = parse_factory_addresses(&env::var("FACTORY_ADDRESS")?)?;
+
         Ok(Self {
             l1_rpc: env::var("L1_RPC")?.parse().expect("L1_RPC not set"),
             l2_rpc: env::var("L2_RPC")?.parse().expect("L2_RPC not set"),
-            factory_address: env::var("FACTORY_ADDRESS")?.parse().expect("FACTORY_ADDRESS not set"),
+            l2_to_l1_message_passer: l2_to_l1_message_passer_from_env()?,
+            factory_address: factory_addresses.remove(0),
+            additional_factory_addresses: factory_addresses,
             mock_mode: env::var("MOCK_MODE").unwrap_or("false".to_string()).parse()?,
             fast_finality_mode: env::var("FAST_FINALITY_MODE")
                 .unwrap_or("false".to_string())
@@ -71,11 +262,50 @@ impl ProposerConfig {
             proposal_interval_in_blocks: env::var("PROPOSAL_INTERVAL_IN_BLOCKS")
                 .unwrap_or("1800".to_string())
                 .parse()?,
-            fetch_interval: env::var("FETCH_INTERVAL").unwrap_or("30".to_string()).parse()?,
+            max_proposals_per_tick: env::var("MAX_PROPOSALS_PER_TICK")
+                .unwrap_or("1".to_string())
+                .parse()?,
+            fetch_interval,
+            min_fetch_interval: env::var("MIN_FETCH_INTERVAL")
+                .map_or(Ok(fetch_interval), |interval| interval.parse())?,
+            max_fetch_interval: env::var("MAX_FETCH_INTERVAL")
+                .map_or(Ok(fetch_interval), |interval| interval.parse())?,
+            fetch_interval_jitter_pct: env::var("FETCH_INTERVAL_JITTER_PCT")
+                .unwrap_or("0".to_string())
+                .parse()?,
+            creation_retry_backoff_secs: env::var("CREATION_RETRY_BACKOFF_SECS")
+                .unwrap_or("300".to_string())
+                .parse()?,
+            max_consecutive_creation_failures: env::var("MAX_CONSECUTIVE_CREATION_FAILURES")
+                .unwrap_or("10".to_string())
+                .parse()?,
+            creation_circuit_breaker_cooldown_secs: env::var(
+                "CREATION_CIRCUIT_BREAKER_COOLDOWN_SECS",
+            )
+            .unwrap_or("600".to_string())
+            .parse()?,
+            min_proposal_interval_secs: env::var("MIN_PROPOSAL_INTERVAL_SECS")
+                .unwrap_or("0".to_string())
+                .parse()?,
             game_type: env::var("GAME_TYPE").expect("GAME_TYPE not set").parse()?,
             max_games_to_check_for_defense: env::var("MAX_GAMES_TO_CHECK_FOR_DEFENSE")
                 .unwrap_or("100".to_string())
                 .parse()?,
+            max_games_to_count_by_status: env::var("MAX_GAMES_TO_COUNT_BY_STATUS")
+                .unwrap_or("100".to_string())
+                .parse()?,
+            whitelist_check_interval_secs: env::var("WHITELIST_CHECK_INTERVAL_SECS")
+                .unwrap_or("300".to_string())
+                .parse()?,
+            max_games_to_check_for_valid_proposal: env::var(
+                "MAX_GAMES_TO_CHECK_FOR_VALID_PROPOSAL",
+            )
+            .unwrap_or("1000".to_string())
+            .parse()?,
+            valid_proposal_search_strategy: env::var("VALID_PROPOSAL_SEARCH_STRATEGY")
+                .unwrap_or("linear".to_string())
+                .parse()
+                .context("VALID_PROPOSAL_SEARCH_STRATEGY must be one of linear or exponential")?,
             enable_game_resolution: env::var("ENABLE_GAME_RESOLUTION")
                 .unwrap_or("true".to_string())
                 .parse()?,
@@ -85,25 +315,157 @@ impl ProposerConfig {
             max_games_to_check_for_bond_claiming: env::var("MAX_GAMES_TO_CHECK_FOR_BOND_CLAIMING")
                 .unwrap_or("100".to_string())
                 .parse()?,
-            safe_db_fallback: env::var("SAFE_DB_FALLBACK")
-                .unwrap_or("false".to_string())
-                .parse()?,
+            safe_db_fallback,
+            prove_safe_db_fallback: env::var("PROVE_SAFE_DB_FALLBACK")
+                .ok()
+                .map(|v| v.parse())
+                .transpose()?
+                .unwrap_or(safe_db_fallback),
             metrics_port: env::var("PROPOSER_METRICS_PORT")
                 .unwrap_or("9000".to_string())
                 .parse()?,
+            health_port: env::var("PROPOSER_HEALTH_PORT").unwrap_or("9100".to_string()).parse()?,
+            health_staleness_secs: env::var("HEALTH_STALENESS_SECS")
+                .unwrap_or("300".to_string())
+                .parse()?,
+            expected_l1_chain_id: env::var("EXPECTED_L1_CHAIN_ID")
+                .ok()
+                .map(|id| id.parse())
+                .transpose()?,
+            expected_l2_chain_id: env::var("EXPECTED_L2_CHAIN_ID")
+                .ok()
+                .map(|id| id.parse())
+                .transpose()?,
+            prefer_output_at_block_rpc,
+            l2_node_rpc: l2_node_rpc_from_env(prefer_output_at_block_rpc)?,
+            output_root_verification_mode: env::var("OUTPUT_ROOT_VERIFICATION_MODE")
+                .unwrap_or("full".to_string())
+                .parse()?,
+            max_stdin_bytes: env::var("MAX_STDIN_BYTES")
+                .unwrap_or("1073741824".to_string())
+                .parse()?,
+            proof_timeout_secs: env::var("PROOF_TIMEOUT_SECS")
+                .unwrap_or("14400".to_string())
+                .parse()?,
+            range_proof_strategy: parse_fulfillment_strategy(
+                &env::var("RANGE_PROOF_STRATEGY").unwrap_or("hosted".to_string()),
+            )?,
+            agg_proof_mode: parse_agg_proof_mode(
+                &env::var("AGG_PROOF_MODE").unwrap_or("groth16".to_string()),
+            )?,
+            aggregation_vkey: env::var("AGGREGATION_VKEY").ok().map(|v| v.parse()).transpose()?,
+            range_vkey_commitment: env::var("RANGE_VKEY_COMMITMENT")
+                .ok()
+                .map(|v| v.parse())
+                .transpose()?,
+            range_proof_cycle_limit: env::var("RANGE_PROOF_CYCLE_LIMIT")
+                .unwrap_or("1000000000000".to_string())
+                .parse()?,
+            skip_simulation: env::var("SKIP_SIMULATION").unwrap_or("true".to_string()).parse()?,
+            audit_log_path: env::var("AUDIT_LOG_PATH").ok().map(PathBuf::from),
+            state_dir: env::var("STATE_DIR").ok().map(PathBuf::from),
+            l1_halt_threshold_secs: env::var("L1_HALT_THRESHOLD_SECS")
+                .unwrap_or("120".to_string())
+                .parse()?,
+            max_expected_proof_size_bytes: env::var("MAX_EXPECTED_PROOF_SIZE_BYTES")
+                .unwrap_or("1024".to_string())
+                .parse()?,
+            tx_retry_policy: RetryPolicy::from_env()?,
+            l1_fee_percentile: env::var("L1_FEE_PERCENTILE").unwrap_or("50".to_string()).parse()?,
+            max_fee_per_gas_gwei: env::var("MAX_FEE_PER_GAS_GWEI")
+                .unwrap_or("500".to_string())
+                .parse()?,
+            output_root_cache_size: env::var("OUTPUT_ROOT_CACHE_SIZE")
+                .unwrap_or("1024".to_string())
+                .parse()?,
+            game_fetch_batch_size: env::var("GAME_FETCH_BATCH_SIZE")
+                .unwrap_or("50".to_string())
+                .parse()?,
+            scan_concurrency: env::var("SCAN_CONCURRENCY").unwrap_or("10".to_string()).parse()?,
+            rpc_limits: RpcLimits::from_env()?,
+            dry_run: env::var("DRY_RUN").unwrap_or("false".to_string()).parse()?,
+            min_balance_wei: env::var("MIN_BALANCE_WEI").unwrap_or("0".to_string()).parse()?,
+            deadline_alert_threshold_secs: env::var("DEADLINE_ALERT_THRESHOLD_SECS")
+                .unwrap_or("3600".to_string())
+                .parse()?,
+            bond_recipient: env::var("BOND_RECIPIENT")
+                .ok()
+                .map(|address| address.parse())
+                .transpose()?,
+            notification_webhook_url: env::var("NOTIFICATION_WEBHOOK_URL")
+                .ok()
+                .map(|url| url.parse())
+                .transpose()?,
+            notification_events: notification_events_from_env()?,
         })
     }
+
+    /// Checks the configuration against the chains at startup, so that a misconfiguration fails
+    /// immediately instead of surfacing as errors in the run loop.
+    ///
+    /// Every problem found is listed in the returned error.
+    pub async fn validate<P>(
+        &self,
+        l1_provider: &L1Provider,
+        l2_provider: &L2Provider,
+        factory: &DisputeGameFactoryInstance<P>,
+        signer_address: Address,
+    ) -> Result<()>
+    where
+        P: Provider + Clone,
+    {
+        let mut problems = Vec::new();
+
+        validate_chain_id("L1", l1_provider, self.expected_l1_chain_id, &mut problems).await;
+        validate_chain_id("L2", &**l2_provider, self.expected_l2_chain_id, &mut problems).await;
+        validate_contracts(l1_provider, factory, self.game_type, &mut problems).await;
+        if !self.dry_run {
+            validate_signer_balance(l1_provider, signer_address, &mut problems).await;
+        }
+
+        let network_private_key = env::var("NETWORK_PRIVATE_KEY").ok();
+        if self.mock_mode &&
+            network_private_key.is_some_and(|key| key != DEFAULT_NETWORK_PRIVATE_KEY)
+        {
+            problems.push(
+                "MOCK_MODE is enabled, but NETWORK_PRIVATE_KEY is set: games would be proven \
+                 with mock proofs instead of the Succinct Prover Network"
+                    .to_string(),
+            );
+        }
+
+        into_validation_result(problems)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ChallengerConfig {
     pub l1_rpc: Url,
     pub l2_rpc: Url,
+
+    /// The address of the `L2ToL1MessagePasser` whose storage root is committed to in output roots.
+    /// Only differs from the predeploy on chains that remap it.
+    pub l2_to_l1_message_passer: Address,
+
+    /// A second L2 RPC that must also compute a different output root before a game is
+    /// challenged.
+    pub secondary_l2_rpc: Option<Url>,
+
+    /// Whether to fetch output roots from the rollup node with `optimism_outputAtBlock` when
+    /// scanning games, falling back to computing them from the L2 RPC on error.
+    pub prefer_output_at_block_rpc: bool,
+
+    /// The rollup node RPC URL. Required if `prefer_output_at_block_rpc` is set.
+    pub l2_node_rpc: Option<Url>,
+
     pub factory_address: Address,
 
     /// The interval in seconds between checking for new challenges opportunities.
     pub fetch_interval: u64,
 
+    /// The maximum random jitter added to the interval between ticks, as a percentage of it.
+    pub fetch_interval_jitter_pct: f64,
+
     /// The game type to challenge.
     pub game_type: u32,
 
@@ -124,23 +486,147 @@ pub struct ChallengerConfig {
     /// The maximum number of games to check for bond claiming.
     pub max_games_to_check_for_bond_claiming: u64,
 
+    /// Extra time in seconds past the prove deadline of a challenged game before the challenger
+    /// attempts to resolve it. This avoids racing a proof that has been submitted but not yet
+    /// confirmed.
+    pub defend_period_grace_secs: u64,
+
+    /// The maximum number of games created by a given proposer that are challenged per tick.
+    /// Proposers without an entry are not limited.
+    pub max_challenges_per_proposer: HashMap<Address, u32>,
+
+    /// The proposers whose games are challenged first, and those whose games are never
+    /// challenged. Other proposers' games are challenged oldest first after the prioritized ones.
+    pub proposer_priority: ProposerPriority,
+
+    /// Whether to watch for proofs submitted to games this challenger challenged, and resolve
+    /// those games as soon as the proof lands instead of on the next tick.
+    pub proof_watching_enabled: bool,
+
+    /// Whether to subscribe to game creation events and check new games for a challenge as soon
+    /// as they are created. Periodic scanning keeps running as a backstop. Requires a WebSocket
+    /// or IPC L1 RPC.
+    pub use_event_subscriptions: bool,
+
+    /// The number of ticks between sweeps of all games for expired challenged games, which are
+    /// resolved even if they are outside the resolution window.
+    pub sweep_interval_ticks: u64,
+
+    /// The interval in seconds between checks of the challenge window for proposers submitting
+    /// mostly invalid output roots.
+    pub collusion_check_interval_secs: u64,
+
+    /// Games created less than this many seconds ago are not challenged yet, which leaves a fast
+    /// finality proposer time to prove them first.
+    pub skip_proposals_newer_than_secs: u64,
+
+    /// The number of L1 blocks an event must be buried under before it is acted on, so that
+    /// events of blocks that are reorged away are ignored.
+    pub event_confirmations: u64,
+
+    /// How transient failures of transaction submission are retried.
+    pub tx_retry_policy: RetryPolicy,
+
+    /// The percentile of recent priority fees used as the max priority fee of L1 transactions.
+    pub l1_fee_percentile: f64,
+
+    /// The ceiling in gwei of the max fee per gas of L1 transactions.
+    pub max_fee_per_gas_gwei: u64,
+
+    /// The maximum number of output roots of finalized L2 blocks kept in memory.
+    pub output_root_cache_size: usize,
+
+    /// The number of game addresses fetched concurrently when scanning a window of games.
+    pub game_fetch_batch_size: usize,
+
+    /// The number of games checked concurrently when searching for the oldest game to challenge.
+    pub scan_concurrency: usize,
+
+    /// The limits on the requests sent to the L1 and L2 RPCs, shared by all providers.
+    pub rpc_limits: RpcLimits,
+
+    /// Whether to log transactions instead of sending them. Challenges are skipped, as the game
+    /// remains unchallenged.
+    pub dry_run: bool,
+
+    /// The signer balance in wei below which a low balance warning is logged. Zero disables it.
+    pub min_balance_wei: U256,
+
+    /// The number of seconds before the challenge deadline of an invalid game below which a
+    /// warning is logged, if the game still isn't challenged.
+    pub deadline_alert_threshold_secs: u64,
+
+    /// The address claimed bonds are forwarded to, e.g. a cold treasury. Bonds stay with the
+    /// signer when unset.
+    pub bond_recipient: Option<Address>,
+
+    /// The Slack or Discord compatible webhook notifications are posted to. Notifications are
+    /// disabled when unset.
+    pub notification_webhook_url: Option<Url>,
+
+    /// The events notifications are posted for.
+    pub notification_events: HashSet<NotificationEvent>,
+
     /// The metrics port.
     pub metrics_port: u16,
 
+    /// The port to serve the `/healthz` and `/readyz` health check endpoints on.
+    pub health_port: u16,
+
+    /// The number of seconds after the last successful tick after which `/healthz` reports the
+    /// service as unhealthy.
+    pub health_staleness_secs: u64,
+
     /// Percentage (0.0-100.0) of valid games to challenge maliciously for testing.
     /// Set to 0.0 (default) for production use (honest challenging only).
     /// Set to >0.0 for testing defense mechanisms.
     pub malicious_challenge_percentage: f64,
+
+    /// The seed of the RNG deciding which valid games to challenge maliciously, so that test runs
+    /// challenge the same games. Unseeded (random) if unset.
+    pub malicious_challenge_seed: Option<u64>,
+
+    /// Whether to log the state root, message passer storage root and block hash of the local
+    /// output root of every game found to be invalid.
+    pub verbose_diagnostics: bool,
+
+    /// The minimum expected net reward in wei of a challenge: the proposer's bond, minus the
+    /// challenger bond at risk and the estimated gas. Less profitable challenges of valid games
+    /// are skipped. Unset disables the check.
+    pub min_net_reward_wei: Option<I256>,
+
+    /// Whether invalid games are challenged regardless of `min_net_reward_wei`, as leaving them
+    /// unchallenged threatens the safety of the chain.
+    pub always_challenge_invalid: bool,
 }
 
 impl ChallengerConfig {
     pub fn from_env() -> Result<Self> {
+        let prefer_output_at_block_rpc =
+            env::var("PREFER_OUTPUT_AT_BLOCK_RPC").unwrap_or("false".to_string()).parse()?;
+        let l1_rpc: Url = env::var("L1_RPC")?.parse().expect("L1_RPC not set");
+        let use_event_subscriptions: bool =
+            env::var("USE_EVENT_SUBSCRIPTIONS").unwrap_or("false".to_string()).parse()?;
+        if use_event_subscriptions && !matches!(l1_rpc.scheme(), "ws" | "wss" | "file") {
+            bail!("USE_EVENT_SUBSCRIPTIONS requires a WebSocket or IPC L1_RPC");
+        }
+
         Ok(Self {
-            l1_rpc: env::var("L1_RPC")?.parse().expect("L1_RPC not set"),
+            l1_rpc,
             l2_rpc: env::var("L2_RPC")?.parse().expect("L2_RPC not set"),
+            l2_to_l1_message_passer: l2_to_l1_message_passer_from_env()?,
+            secondary_l2_rpc: env::var("SECONDARY_L2_RPC")
+                .ok()
+                .map(|url| url.parse())
+                .transpose()?,
+            prefer_output_at_block_rpc,
+            l2_node_rpc: l2_node_rpc_from_env(prefer_output_at_block_rpc)?,
             factory_address: env::var("FACTORY_ADDRESS")?.parse().expect("FACTORY_ADDRESS not set"),
             game_type: env::var("GAME_TYPE").expect("GAME_TYPE not set").parse()?,
             fetch_interval: env::var("FETCH_INTERVAL").unwrap_or("30".to_string()).parse()?,
+            fetch_interval_jitter_pct: env::var("FETCH_INTERVAL_JITTER_PCT")
+                .unwrap_or("0".to_string())
+                .parse()?,
             max_games_to_check_for_challenge: env::var("MAX_GAMES_TO_CHECK_FOR_CHALLENGE")
                 .unwrap_or("100".to_string())
                 .parse()?,
@@ -153,12 +639,402 @@ impl ChallengerConfig {
             max_games_to_check_for_bond_claiming: env::var("MAX_GAMES_TO_CHECK_FOR_BOND_CLAIMING")
                 .unwrap_or("100".to_string())
                 .parse()?,
+            defend_period_grace_secs: env::var("DEFEND_PERIOD_GRACE_SECS")
+                .unwrap_or("60".to_string())
+                .parse()?,
+            max_challenges_per_proposer: env::var("MAX_CHALLENGES_PER_PROPOSER")
+                .map_or(Ok(HashMap::new()), |limits| parse_max_challenges_per_proposer(&limits))?,
+            proposer_priority: proposer_priority_from_env()?,
+            proof_watching_enabled: env::var("PROOF_WATCHING_ENABLED")
+                .unwrap_or("false".to_string())
+                .parse()?,
+            use_event_subscriptions,
+            sweep_interval_ticks: env::var("SWEEP_INTERVAL_TICKS")
+                .unwrap_or("10".to_string())
+                .parse()?,
+            collusion_check_interval_secs: env::var("COLLUSION_CHECK_INTERVAL_SECS")
+                .unwrap_or("600".to_string())
+                .parse()?,
+            skip_proposals_newer_than_secs: env::var("SKIP_PROPOSALS_NEWER_THAN_SECS")
+                .unwrap_or("0".to_string())
+                .parse()?,
+            event_confirmations: env::var("EVENT_CONFIRMATIONS")
+                .unwrap_or("3".to_string())
+                .parse()?,
+            tx_retry_policy: RetryPolicy::from_env()?,
+            l1_fee_percentile: env::var("L1_FEE_PERCENTILE").unwrap_or("50".to_string()).parse()?,
+            max_fee_per_gas_gwei: env::var("MAX_FEE_PER_GAS_GWEI")
+                .unwrap_or("500".to_string())
+                .parse()?,
+            output_root_cache_size: env::var("OUTPUT_ROOT_CACHE_SIZE")
+                .unwrap_or("1024".to_string())
+                .parse()?,
+            game_fetch_batch_size: env::var("GAME_FETCH_BATCH_SIZE")
+                .unwrap_or("50".to_string())
+                .parse()?,
+            scan_concurrency: env::var("SCAN_CONCURRENCY").unwrap_or("10".to_string()).parse()?,
+            rpc_limits: RpcLimits::from_env()?,
+            dry_run: env::var("DRY_RUN").unwrap_or("false".to_string()).parse()?,
+            min_balance_wei: env::var("MIN_BALANCE_WEI").unwrap_or("0".to_string()).parse()?,
+            deadline_alert_threshold_secs: env::var("DEADLINE_ALERT_THRESHOLD_SECS")
+                .unwrap_or("3600".to_string())
+                .parse()?,
+            bond_recipient: env::var("BOND_RECIPIENT")
+                .ok()
+                .map(|address| address.parse())
+                .transpose()?,
+            notification_webhook_url: env::var("NOTIFICATION_WEBHOOK_URL")
+                .ok()
+                .map(|url| url.parse())
+                .transpose()?,
+            notification_events: notification_events_from_env()?,
             metrics_port: env::var("CHALLENGER_METRICS_PORT")
                 .unwrap_or("9001".to_string())
                 .parse()?,
+            health_port: env::var("CHALLENGER_HEALTH_PORT")
+                .unwrap_or("9101".to_string())
+                .parse()?,
+            health_staleness_secs: env::var("HEALTH_STALENESS_SECS")
+                .unwrap_or("300".to_string())
+                .parse()?,
             malicious_challenge_percentage: env::var("MALICIOUS_CHALLENGE_PERCENTAGE")
                 .unwrap_or("0.0".to_string())
                 .parse()?,
+            malicious_challenge_seed: env::var("MALICIOUS_CHALLENGE_SEED")
+                .ok()
+                .map(|seed| seed.parse())
+                .transpose()?,
+            verbose_diagnostics: env::var("VERBOSE_DIAGNOSTICS")
+                .unwrap_or("false".to_string())
+                .parse()?,
+            min_net_reward_wei: env::var("MIN_NET_REWARD_WEI")
+                .ok()
+                .map(|reward| reward.parse())
+                .transpose()?,
+            always_challenge_invalid: env::var("ALWAYS_CHALLENGE_INVALID")
+                .unwrap_or("true".to_string())
+                .parse()?,
+        })
+    }
+
+    /// Checks the configuration against the chains at startup, so that a misconfiguration fails
+    /// immediately instead of surfacing as errors in the run loop.
+    ///
+    /// Every problem found is listed in the returned error.
+    pub async fn validate<P>(
+        &self,
+        l1_provider: &L1Provider,
+        l2_provider: &L2Provider,
+        factory: &DisputeGameFactoryInstance<P>,
+        signer_address: Address,
+    ) -> Result<()>
+    where
+        P: Provider + Clone,
+    {
+        let mut problems = Vec::new();
+
+        validate_chain_id("L2", &**l2_provider, None, &mut problems).await;
+        validate_contracts(l1_provider, factory, self.game_type, &mut problems).await;
+        if let Err(e) = factory.fetch_challenger_bond(self.game_type).await {
+            problems.push(format!(
+                "Failed to read the challenger bond of game type {}: {e}",
+                self.game_type
+            ));
+        }
+        if !self.dry_run {
+            validate_signer_balance(l1_provider, signer_address, &mut problems).await;
+        }
+
+        into_validation_result(problems)
+    }
+}
+
+/// Checks that `provider` reports the expected chain ID, if one is configured.
+async fn validate_chain_id<N: Network>(
+    chain: &str,
+    provider: &impl Provider<N>,
+    expected_chain_id: Option<u64>,
+    problems: &mut Vec<String>,
+) {
+    match provider.get_chain_id().await {
+        Ok(chain_id) => {
+            if let Some(expected_chain_id) = expected_chain_id.filter(|&id| id != chain_id) {
+                problems.push(format!(
+                    "{chain} RPC reports chain ID {chain_id}, expected {expected_chain_id}"
+                ));
+            }
+        }
+        Err(e) => problems.push(format!("Failed to fetch the chain ID from the {chain} RPC: {e}")),
+    }
+}
+
+/// Checks that the factory and the game implementation of `game_type` are deployed, and that the
+/// initial bond of the game type is readable.
+async fn validate_contracts<P>(
+    l1_provider: &L1Provider,
+    factory: &DisputeGameFactoryInstance<P>,
+    game_type: u32,
+    problems: &mut Vec<String>,
+) where
+    P: Provider + Clone,
+{
+    let factory_address = *factory.address();
+    match l1_provider.get_code_at(factory_address).await {
+        Ok(code) if code.is_empty() => {
+            problems.push(format!(
+                "Factory {factory_address} has no code, is L1_RPC connected to the right chain?"
+            ));
+            return;
+        }
+        Ok(_) => {}
+        Err(e) => {
+            problems.push(format!("Failed to fetch the code of factory {factory_address}: {e}"));
+            return;
+        }
+    }
+
+    match factory.gameImpls(game_type).call().await {
+        Ok(game_impl) if game_impl == Address::ZERO => problems.push(format!(
+            "Factory {factory_address} has no implementation for game type {game_type}"
+        )),
+        Ok(_) => {}
+        Err(e) => problems
+            .push(format!("Failed to read the implementation of game type {game_type}: {e}")),
+    }
+
+    if let Err(e) = factory.fetch_init_bond(game_type).await {
+        problems.push(format!("Failed to read the initial bond of game type {game_type}: {e}"));
+    }
+}
+
+/// Checks that the signer can pay for transactions.
+async fn validate_signer_balance(
+    l1_provider: &L1Provider,
+    signer_address: Address,
+    problems: &mut Vec<String>,
+) {
+    match l1_provider.get_balance(signer_address).await {
+        Ok(balance) if balance.is_zero() => {
+            problems.push(format!("Signer {signer_address} has no balance to pay for transactions"))
+        }
+        Ok(_) => {}
+        Err(e) => {
+            problems.push(format!("Failed to fetch the balance of signer {signer_address}: {e}"))
+        }
+    }
+}
+
+/// Turns the problems found by a validation into an error listing all of them.
+fn into_validation_result(problems: Vec<String>) -> Result<()> {
+    if problems.is_empty() {
+        return Ok(());
+    }
+    bail!("Invalid configuration:\n  - {}", problems.join("\n  - "))
+}
+
+/// Parses per-proposer challenge limits in the `0x...=3,0x...=5` format.
+fn parse_max_challenges_per_proposer(limits: &str) -> Result<HashMap<Address, u32>> {
+    let mut parsed = HashMap::new();
+    for entry in limits.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let (proposer, limit) = entry
+            .split_once('=')
+            .with_context(|| format!("Invalid MAX_CHALLENGES_PER_PROPOSER entry: {entry}"))?;
+        let limit: u32 = limit.trim().parse()?;
+        if limit == 0 {
+            bail!("MAX_CHALLENGES_PER_PROPOSER limit for {proposer} must be at least 1");
+        }
+        parsed.insert(proposer.trim().parse()?, limit);
+    }
+    Ok(parsed)
+}
+
+/// Reads the proposers to prioritize and ignore from comma-separated address lists.
+fn proposer_priority_from_env() -> Result<ProposerPriority> {
+    let parse_proposers = |var: &str| -> Result<HashSet<Address>> {
+        env::var(var)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|address| !address.is_empty())
+            .map(|address| {
+                address.parse().with_context(|| format!("Invalid {var} entry {address}"))
+            })
+            .collect()
+    };
+    let priority = ProposerPriority {
+        prioritized: parse_proposers("PRIORITY_PROPOSERS")?,
+        ignored: parse_proposers("IGNORED_PROPOSERS")?,
+    };
+    if let Some(proposer) = priority.prioritized.intersection(&priority.ignored).next() {
+        bail!("Proposer {proposer} is in both PRIORITY_PROPOSERS and IGNORED_PROPOSERS");
+    }
+    Ok(priority)
+}
+
+/// Loads the settings of the TOML config file at `path` into the environment, so that the
+/// `from_env` constructors pick them up. Variables that are already set, e.g. by the environment
+/// or the env file, take precedence over the file.
+///
+/// Setting environment variables isn't thread-safe, so this must be called before the async
+/// runtime or any other thread is started.
+pub fn load_config_file(path: &Path) -> Result<()> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file {}", path.display()))?;
+    let settings = parse_config_file(&contents)
+        .with_context(|| format!("Invalid config file {}", path.display()))?;
+
+    for (name, value) in settings {
+        if env::var_os(&name).is_none() {
+            env::set_var(name, value);
+        }
+    }
+    Ok(())
+}
+
+/// Parses the contents of a TOML config file into environment variables.
+///
+/// The file is a flat table keyed by the environment variable names, in any case, e.g.
+/// `l1_rpc = "http://localhost:8545"` sets `L1_RPC`. Arrays are joined with commas.
+fn parse_config_file(contents: &str) -> Result<HashMap<String, String>> {
+    contents
+        .parse::<Table>()?
+        .into_iter()
+        .map(|(key, value)| {
+            let value = config_value_to_string(&value)
+                .with_context(|| format!("Invalid value of {key}"))?;
+            Ok((key.to_uppercase(), value))
         })
+        .collect()
+}
+
+/// Formats a config file value the way the environment variable would be written.
+fn config_value_to_string(value: &Value) -> Result<String> {
+    match value {
+        Value::String(value) => Ok(value.clone()),
+        Value::Integer(_) | Value::Float(_) | Value::Boolean(_) => Ok(value.to_string()),
+        Value::Array(values) => {
+            Ok(values.iter().map(config_value_to_string).collect::<Result<Vec<_>>>()?.join(","))
+        }
+        Value::Datetime(_) | Value::Table(_) => {
+            bail!("expected a string, number, boolean or array")
+        }
+    }
+}
+
+/// Reads the comma-separated events to post notifications for, which default to all events.
+fn notification_events_from_env() -> Result<HashSet<NotificationEvent>> {
+    let Ok(events) = env::var("NOTIFICATION_EVENTS") else {
+        return Ok(NotificationEvent::iter().collect());
+    };
+    events
+        .split(',')
+        .map(str::trim)
+        .filter(|event| !event.is_empty())
+        .map(|event| {
+            event.parse().with_context(|| format!("Invalid NOTIFICATION_EVENTS entry {event}"))
+        })
+        .collect()
+}
+
+/// Parses a prover network fulfillment strategy, one of `hosted`, `reserved` or `auction`.
+fn parse_fulfillment_strategy(strategy: &str) -> Result<FulfillmentStrategy> {
+    match strategy.to_lowercase().as_str() {
+        "hosted" => Ok(FulfillmentStrategy::Hosted),
+        "reserved" => Ok(FulfillmentStrategy::Reserved),
+        "auction" => Ok(FulfillmentStrategy::Auction),
+        _ => bail!(
+            "Invalid RANGE_PROOF_STRATEGY {strategy}, expected one of hosted, reserved or auction"
+        ),
+    }
+}
+
+/// Parses an aggregation proof mode, one of `groth16` or `plonk`.
+fn parse_agg_proof_mode(mode: &str) -> Result<SP1ProofMode> {
+    match mode.to_lowercase().as_str() {
+        "groth16" => Ok(SP1ProofMode::Groth16),
+        "plonk" => Ok(SP1ProofMode::Plonk),
+        _ => bail!("Invalid AGG_PROOF_MODE {mode}, expected one of groth16 or plonk"),
+    }
+}
+
+/// Parses a comma-separated list of factory addresses, which must contain at least one address.
+fn parse_factory_addresses(addresses: &str) -> Result<Vec<Address>> {
+    let parsed = addresses
+        .split(',')
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(|address| {
+            address.parse().with_context(|| format!("Invalid factory address {address}"))
+        })
+        .collect::<Result<Vec<Address>>>()?;
+    if parsed.is_empty() {
+        bail!("FACTORY_ADDRESS must contain at least one address");
+    }
+    Ok(parsed)
+}
+
+/// Reads the `L2ToL1MessagePasser` address, which defaults to the predeploy.
+fn l2_to_l1_message_passer_from_env() -> Result<Address> {
+    let address = env::var("L2_TO_L1_MESSAGE_PASSER")
+        .map_or(Ok(L2_TO_L1_MESSAGE_PASSER), |address| address.parse())?;
+    Ok(address)
+}
+
+/// Reads the rollup node RPC URL, which is required if `prefer_output_at_block_rpc` is set.
+fn l2_node_rpc_from_env(prefer_output_at_block_rpc: bool) -> Result<Option<Url>> {
+    let l2_node_rpc = env::var("L2_NODE_RPC").ok().map(|url| url.parse()).transpose()?;
+    if prefer_output_at_block_rpc && l2_node_rpc.is_none() {
+        bail!("L2_NODE_RPC must be set when PREFER_OUTPUT_AT_BLOCK_RPC is enabled");
+    }
+    Ok(l2_node_rpc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config_file_uppercases_keys() {
+        let settings = parse_config_file(
+            r#"
+            l1_rpc = "http://localhost:8545"
+            Fetch_Interval = 30
+            MOCK_MODE = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings["L1_RPC"], "http://localhost:8545");
+        assert_eq!(settings["FETCH_INTERVAL"], "30");
+        assert_eq!(settings["MOCK_MODE"], "true");
+        assert_eq!(settings.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_config_file_joins_arrays() {
+        let settings = parse_config_file(
+            r#"
+            factory_address = [
+                "0x1111111111111111111111111111111111111111",
+                "0x2222222222222222222222222222222222222222",
+            ]
+            notification_events = ["game_created"]
+            priority_proposers = []
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings["FACTORY_ADDRESS"],
+            "0x1111111111111111111111111111111111111111,0x2222222222222222222222222222222222222222"
+        );
+        assert_eq!(settings["NOTIFICATION_EVENTS"], "game_created");
+        assert_eq!(settings["PRIORITY_PROPOSERS"], "");
+    }
+
+    #[test]
+    fn test_parse_config_file_rejects_tables() {
+        assert!(parse_config_file("[proposer]\nl1_rpc = \"http://localhost:8545\"").is_err());
+        assert!(parse_config_file("retry = { max_attempts = 3 }").is_err());
     }
 }
@@ -1,10 +1,22 @@
 use alloy_sol_macro::sol;
+use alloy_sol_types::SolInterface;
 
 sol! {
     type GameType is uint32;
     type Claim is bytes32;
     type Timestamp is uint64;
     type Hash is bytes32;
+    type Duration is uint64;
+    type GameId is bytes32;
+
+    /// @notice A game returned by `findLatestGames`.
+    struct GameSearchResult {
+        uint256 index;
+        GameId metadata;
+        Timestamp timestamp;
+        Claim rootClaim;
+        bytes extraData;
+    }
 
     #[sol(rpc)]
     #[derive(Debug)]
@@ -26,6 +38,10 @@ sol! {
         ///         at the given index. Each created dispute game increments the underlying index.
         function gameAtIndex(uint256 _index) external view returns (GameType gameType, Timestamp timestamp, IDisputeGame proxy);
 
+        /// @notice Finds the `_n` most recent games of the given type, searching backwards from
+        ///         index `_start`.
+        function findLatestGames(GameType _gameType, uint256 _start, uint256 _n) external view returns (GameSearchResult[] memory games_);
+
         /// @notice Creates a new DisputeGame proxy contract.
         function create(GameType gameType, Claim rootClaim, bytes extraData) external;
     }
@@ -36,12 +52,33 @@ sol! {
 
     #[sol(rpc)]
     contract OPSuccinctFaultDisputeGame {
+        /// @notice Emitted when the game is challenged.
+        event Challenged(address indexed challenger);
+
+        /// @notice Emitted when the game is proved.
+        event Proved(address indexed prover);
+
+        /// @notice Emitted when the game is resolved.
+        event Resolved(GameStatus indexed status);
+
         /// @notice The L2 block number for which this game is proposing an output root.
         function l2BlockNumber() public pure returns (uint256 l2BlockNumber_);
 
         /// @notice Getter for the root claim.
         function rootClaim() public pure returns (Claim rootClaim_);
 
+        /// @notice Getter for the creator of the dispute game.
+        function gameCreator() public pure returns (address creator_);
+
+        /// @notice Getter for the starting L2 block number of the game.
+        function startingBlockNumber() external view returns (uint256 startingBlockNumber_);
+
+        /// @notice Getter for the output root the game starts from.
+        function startingRootHash() external view returns (Hash startingRootHash_);
+
+        /// @notice Getter for the timestamp at which the game was created.
+        function createdAt() external view returns (Timestamp createdAt_);
+
         /// @notice Getter for the parent hash of the L1 block when the dispute game was created.
         function l1Head() public pure returns (Hash l1Head_);
 
@@ -66,9 +103,15 @@ sol! {
         /// @notice Returns the max challenge duration.
         function maxChallengeDuration() external view returns (uint256 maxChallengeDuration_);
 
+        /// @notice Returns the max prove duration.
+        function maxProveDuration() external view returns (Duration maxProveDuration_);
+
         /// @notice Returns the anchor state registry contract.
         function anchorStateRegistry() external view returns (IAnchorStateRegistry registry_);
 
+        /// @notice Returns the access manager contract.
+        function accessManager() external view returns (address accessManager_);
+
         /// @notice Returns the challenger bond amount.
         function challengerBond() external view returns (uint256 challengerBond_);
 
@@ -77,6 +120,57 @@ sol! {
 
         /// @notice Returns the credit balance of a given recipient.
         function credit(address _recipient) external view returns (uint256 credit_);
+
+        /// @notice Thrown when the game has already been initialized.
+        error AlreadyInitialized();
+
+        /// @notice Thrown when the game is initialized by an incorrect factory.
+        error IncorrectDisputeGameFactory();
+
+        /// @notice Thrown when the caller is not allowed to propose or challenge.
+        error BadAuth();
+
+        /// @notice Thrown when the extra data of the game is malformed.
+        error BadExtraData();
+
+        /// @notice Thrown when the parent game is invalid.
+        error InvalidParentGame();
+
+        /// @notice Thrown when the root claim does not match the aggregated output root.
+        error UnexpectedRootClaim(Claim rootClaim);
+
+        /// @notice Thrown when the claim has already been challenged.
+        error ClaimAlreadyChallenged();
+
+        /// @notice Thrown when the game is over.
+        error GameOver();
+
+        /// @notice Thrown when the value sent does not match the challenger bond.
+        error IncorrectBondAmount();
+
+        /// @notice Thrown when the game has already been resolved.
+        error ClaimAlreadyResolved();
+
+        /// @notice Thrown when the parent game is not resolved.
+        error ParentGameNotResolved();
+
+        /// @notice Thrown when the game is not over.
+        error GameNotOver();
+
+        /// @notice Thrown when the proposal status is invalid.
+        error InvalidProposalStatus();
+
+        /// @notice Thrown when the bond distribution mode is invalid.
+        error InvalidBondDistributionMode();
+
+        /// @notice Thrown when the recipient has no credit to claim.
+        error NoCreditToClaim();
+
+        /// @notice Thrown when the transfer of a bond fails.
+        error BondTransferFailed();
+
+        /// @notice Thrown when the game is not finalized.
+        error GameNotFinalized();
     }
 
     #[allow(missing_docs)]
@@ -93,6 +187,31 @@ sol! {
         function isGameFinalized(IDisputeGame _game) public view returns (bool);
     }
 
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    contract AccessManager {
+        /// @notice Event emitted when proposer permissions are updated.
+        event ProposerPermissionUpdated(address indexed proposer, bool allowed);
+
+        /// @notice Event emitted when challenger permissions are updated.
+        event ChallengerPermissionUpdated(address indexed challenger, bool allowed);
+
+        /// @notice Tracks whitelisted proposers.
+        function proposers(address) public view returns (bool);
+
+        /// @notice Tracks whitelisted challengers.
+        function challengers(address) public view returns (bool);
+
+        /// @notice Checks if an address is allowed to propose.
+        function isAllowedProposer(address _proposer) external view returns (bool allowed_);
+
+        /// @notice Checks if an address is allowed to challenge.
+        function isAllowedChallenger(address _challenger) external view returns (bool allowed_);
+
+        /// @notice Returns the last proposal timestamp.
+        function getLastProposalTimestamp() public view returns (uint256);
+    }
+
     #[derive(Debug, PartialEq)]
     /// @notice The current status of the dispute game.
     enum GameStatus {
@@ -137,3 +256,35 @@ sol! {
         bytes32 l2_claim_hash;
     }
 }
+
+/// Decodes the revert data of a failed dispute game call into the declared error it matches.
+pub fn decode_game_error(
+    revert_data: &[u8],
+) -> Option<OPSuccinctFaultDisputeGame::OPSuccinctFaultDisputeGameErrors> {
+    OPSuccinctFaultDisputeGame::OPSuccinctFaultDisputeGameErrors::abi_decode(revert_data).ok()
+}
+
+impl OPSuccinctFaultDisputeGame::OPSuccinctFaultDisputeGameErrors {
+    /// The name of the error, as declared in the contract.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::AlreadyInitialized(_) => "AlreadyInitialized",
+            Self::IncorrectDisputeGameFactory(_) => "IncorrectDisputeGameFactory",
+            Self::BadAuth(_) => "BadAuth",
+            Self::BadExtraData(_) => "BadExtraData",
+            Self::InvalidParentGame(_) => "InvalidParentGame",
+            Self::UnexpectedRootClaim(_) => "UnexpectedRootClaim",
+            Self::ClaimAlreadyChallenged(_) => "ClaimAlreadyChallenged",
+            Self::GameOver(_) => "GameOver",
+            Self::IncorrectBondAmount(_) => "IncorrectBondAmount",
+            Self::ClaimAlreadyResolved(_) => "ClaimAlreadyResolved",
+            Self::ParentGameNotResolved(_) => "ParentGameNotResolved",
+            Self::GameNotOver(_) => "GameNotOver",
+            Self::InvalidProposalStatus(_) => "InvalidProposalStatus",
+            Self::InvalidBondDistributionMode(_) => "InvalidBondDistributionMode",
+            Self::NoCreditToClaim(_) => "NoCreditToClaim",
+            Self::BondTransferFailed(_) => "BondTransferFailed",
+            Self::GameNotFinalized(_) => "GameNotFinalized",
+        }
+    }
+}
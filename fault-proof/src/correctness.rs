@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use alloy_primitives::{Address, U256};
+use alloy_provider::Provider;
+use anyhow::Result;
+
+use crate::{
+    contract::{
+        DisputeGameFactory::DisputeGameFactoryInstance, GameStatus, OPSuccinctFaultDisputeGame,
+    },
+    FactoryTrait, L1Provider, L2Provider, L2ProviderTrait,
+};
+
+/// A resolved game whose outcome contradicts the locally computed output root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionDiscrepancy {
+    /// The game resolved as `DEFENDER_WINS`, but its root claim is incorrect.
+    SpuriousDefenderWin,
+    /// The game resolved as `CHALLENGER_WINS`, but its root claim is correct.
+    SpuriousChallengerWin,
+}
+
+/// Verifies after the fact that resolved games had the outcome their root claim deserved.
+///
+/// A discrepancy points to a bug in the output root computation, the proof verification or the
+/// game contract itself.
+#[derive(Debug, Default)]
+pub struct ProposalCorrectnessMonitor {
+    /// The resolved games within the window that were already verified.
+    verified_games: HashSet<Address>,
+}
+
+impl ProposalCorrectnessMonitor {
+    /// Verifies the games resolved since the last check among the last `max_games_to_check`
+    /// games, and returns those whose outcome contradicts the local output root.
+    pub async fn check_resolved_games<P>(
+        &mut self,
+        factory: &DisputeGameFactoryInstance<P>,
+        max_games_to_check: u64,
+        l1_provider: &L1Provider,
+        l2_provider: &L2Provider,
+    ) -> Result<Vec<(Address, ResolutionDiscrepancy)>>
+    where
+        P: Provider + Clone,
+    {
+        let Some(latest_game_index) = factory.fetch_latest_game_index().await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut discrepancies = Vec::new();
+        let mut verified_games = HashSet::new();
+        let mut index = latest_game_index.saturating_sub(U256::from(max_games_to_check));
+        while index <= latest_game_index {
+            // The RPC answering this call may be behind the one that returned the game count.
+            let Some(game_address) = factory.try_fetch_game_address_by_index(index).await? else {
+                break;
+            };
+            index += U256::from(1);
+            if self.verified_games.contains(&game_address) {
+                verified_games.insert(game_address);
+                continue;
+            }
+
+            let game = OPSuccinctFaultDisputeGame::new(game_address, l1_provider.clone());
+            let status = game.status().call().await?;
+            if status == GameStatus::IN_PROGRESS {
+                continue;
+            }
+
+            let output_root = l2_provider
+                .compute_output_root_at_block(game.l2BlockNumber().call().await?)
+                .await?;
+            let is_root_claim_correct = output_root == game.rootClaim().call().await?;
+            let discrepancy = match status {
+                GameStatus::DEFENDER_WINS if !is_root_claim_correct => {
+                    Some(ResolutionDiscrepancy::SpuriousDefenderWin)
+                }
+                GameStatus::CHALLENGER_WINS if is_root_claim_correct => {
+                    Some(ResolutionDiscrepancy::SpuriousChallengerWin)
+                }
+                _ => None,
+            };
+            if let Some(discrepancy) = discrepancy {
+                tracing::error!(
+                    "Game {:?} resolved as {:?} with a {} root claim, expected output root {:?}",
+                    game_address,
+                    status,
+                    if is_root_claim_correct { "correct" } else { "incorrect" },
+                    output_root
+                );
+                discrepancies.push((game_address, discrepancy));
+            }
+            verified_games.insert(game_address);
+        }
+
+        // Forget the games that fell out of the window.
+        self.verified_games = verified_games;
+        Ok(discrepancies)
+    }
+}
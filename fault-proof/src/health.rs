@@ -0,0 +1,143 @@
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use futures::future::BoxFuture;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+/// The liveness and readiness of a proposer or challenger, as reported by the health endpoints.
+#[derive(Debug)]
+pub struct HealthState {
+    /// How long after the last successful tick the service is still considered live.
+    staleness: Duration,
+    /// The time of the last successful tick. Starts at creation, so that the first tick has a
+    /// full staleness window to complete.
+    last_successful_tick: Mutex<Instant>,
+    /// Whether the contract constants were fetched and the first tick succeeded.
+    ready: AtomicBool,
+}
+
+impl HealthState {
+    pub fn new(staleness: Duration) -> Self {
+        Self {
+            staleness,
+            last_successful_tick: Mutex::new(Instant::now()),
+            ready: AtomicBool::new(false),
+        }
+    }
+
+    /// Records a successful tick, which also marks the service as ready.
+    pub fn record_successful_tick(&self) {
+        *self.last_successful_tick.lock().unwrap() = Instant::now();
+        self.ready.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the last successful tick is within the staleness window.
+    pub fn is_live(&self) -> bool {
+        self.last_successful_tick.lock().unwrap().elapsed() <= self.staleness
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Relaxed)
+    }
+}
+
+/// Produces the body of a route served next to the health endpoints.
+pub type RouteBody = Arc<dyn Fn() -> BoxFuture<'static, Result<String>> + Send + Sync>;
+
+/// A route served next to the health endpoints.
+#[derive(Clone)]
+pub struct Route {
+    pub path: &'static str,
+    pub content_type: &'static str,
+    pub body: RouteBody,
+}
+
+impl Route {
+    /// A route serving a JSON body.
+    pub fn json(path: &'static str, body: RouteBody) -> Self {
+        Self { path, content_type: "application/json", body }
+    }
+
+    /// A route serving metrics in the Prometheus text format.
+    pub fn prometheus(path: &'static str, body: RouteBody) -> Self {
+        Self { path, content_type: "text/plain; version=0.0.4", body }
+    }
+}
+
+/// Serves `/healthz` and `/readyz` on `port` in the background, along with the extra `routes`.
+///
+/// Both health endpoints return 200 when the check passes and 503 otherwise, so that they can be
+/// used as Kubernetes liveness and readiness probes. An extra route returns 500 if producing its
+/// body fails.
+pub fn init_health_server(port: u16, health: Arc<HealthState>, routes: Vec<Route>) {
+    let routes = Arc::new(routes);
+    tokio::spawn(async move {
+        let listener =
+            match TcpListener::bind(SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), port)).await {
+                Ok(listener) => listener,
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to start health server: {}. Will continue without health checks.",
+                        e
+                    );
+                    return;
+                }
+            };
+
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    tokio::spawn(handle_health_request(stream, health.clone(), routes.clone()));
+                }
+                Err(e) => tracing::debug!("Failed to accept health check connection: {:?}", e),
+            }
+        }
+    });
+}
+
+async fn handle_health_request(
+    mut stream: TcpStream,
+    health: Arc<HealthState>,
+    routes: Arc<Vec<Route>>,
+) {
+    let mut buf = [0u8; 1024];
+    let Ok(len) = stream.read(&mut buf).await else {
+        return;
+    };
+
+    // Only the path of the request line matters, e.g. `GET /healthz HTTP/1.1`.
+    let request = String::from_utf8_lossy(&buf[..len]);
+    let path = request.split_whitespace().nth(1);
+    let route = routes.iter().find(|route| Some(route.path) == path);
+    let content_type = route.map_or("application/json", |route| route.content_type);
+    let (status, body) = match (path, route) {
+        (_, Some(route)) => match (route.body)().await {
+            Ok(body) => ("200 OK", body),
+            Err(e) => {
+                tracing::warn!("Failed to serve {}: {:?}", route.path, e);
+                ("500 Internal Server Error", String::new())
+            }
+        },
+        (Some("/healthz"), _) if health.is_live() => ("200 OK", String::new()),
+        (Some("/readyz"), _) if health.is_ready() => ("200 OK", String::new()),
+        (Some("/healthz" | "/readyz"), _) => ("503 Service Unavailable", String::new()),
+        _ => ("404 Not Found", String::new()),
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    if let Err(e) = stream.write_all(response.as_bytes()).await {
+        tracing::debug!("Failed to respond to health check: {:?}", e);
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::{Address, Bytes, U256};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// The name of the journal file within the state directory.
+const JOURNAL_FILE_NAME: &str = "journal.json";
+
+/// An operation the proposer started and may not have completed before a crash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PendingOperation {
+    /// Creating a game for the L2 block.
+    GameCreation { l2_block_number: U256 },
+    /// Proving the game.
+    GameProving { game_address: Address },
+}
+
+/// How far a pending operation got.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OperationStatus {
+    Started,
+    /// The aggregation proof was generated, but not yet accepted on-chain.
+    ProofGenerated {
+        proof: Bytes,
+    },
+}
+
+/// A single entry of the journal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub operation: PendingOperation,
+    pub status: OperationStatus,
+    /// The unix timestamp in seconds of the last status transition.
+    pub updated_at: u64,
+}
+
+/// An on-disk journal of the proposer's in-flight operations, used to resume them after a crash.
+///
+/// The journal is rewritten on every status transition, by writing a temporary file and renaming
+/// it over the previous journal, so that a crash never leaves a partially written journal.
+#[derive(Debug)]
+pub struct ProposerJournal {
+    path: PathBuf,
+    entries: Mutex<Vec<JournalEntry>>,
+}
+
+impl ProposerJournal {
+    /// Opens the journal in `state_dir`, creating the directory if needed, and loads the entries
+    /// left by the previous run.
+    pub fn open(state_dir: &Path) -> Result<Self> {
+        fs::create_dir_all(state_dir)
+            .with_context(|| format!("Failed to create state directory {}", state_dir.display()))?;
+
+        let path = state_dir.join(JOURNAL_FILE_NAME);
+        let entries = if path.exists() {
+            let contents = fs::read(&path)
+                .with_context(|| format!("Failed to read journal {}", path.display()))?;
+            serde_json::from_slice(&contents)
+                .with_context(|| format!("Failed to parse journal {}", path.display()))?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self { path, entries: Mutex::new(entries) })
+    }
+
+    /// Returns the entries of the journal.
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    /// Returns the proof generated for the game, if it was journaled but not yet accepted on-chain.
+    pub fn generated_proof(&self, game_address: Address) -> Option<Bytes> {
+        self.entries.lock().unwrap().iter().find_map(|entry| {
+            match (&entry.operation, &entry.status) {
+                (
+                    PendingOperation::GameProving { game_address: address },
+                    OperationStatus::ProofGenerated { proof },
+                ) if *address == game_address => Some(proof.clone()),
+                _ => None,
+            }
+        })
+    }
+
+    /// Records the status of an operation, replacing its previous status.
+    pub fn record(&self, operation: PendingOperation, status: OperationStatus) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|entry| entry.operation != operation);
+        entries.push(JournalEntry { operation, status, updated_at: unix_timestamp() });
+        self.persist(&entries);
+    }
+
+    /// Removes a completed or abandoned operation from the journal.
+    pub fn complete(&self, operation: &PendingOperation) {
+        let mut entries = self.entries.lock().unwrap();
+        let len = entries.len();
+        entries.retain(|entry| entry.operation != *operation);
+        if entries.len() != len {
+            self.persist(&entries);
+        }
+    }
+
+    /// Writes the entries to disk. Failures are logged rather than returned, as losing the journal
+    /// only costs redoing work after a crash.
+    fn persist(&self, entries: &[JournalEntry]) {
+        let tmp_path = self.path.with_extension("json.tmp");
+        let result = serde_json::to_vec_pretty(entries)
+            .map_err(anyhow::Error::from)
+            .and_then(|contents| fs::write(&tmp_path, contents).map_err(anyhow::Error::from))
+            .and_then(|()| fs::rename(&tmp_path, &self.path).map_err(anyhow::Error::from));
+        if let Err(e) = result {
+            tracing::warn!("Failed to write journal {}: {:?}", self.path.display(), e);
+        }
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    /// A fresh state directory for a test.
+    fn state_dir(name: &str) -> PathBuf {
+        let dir =
+            env::temp_dir().join(format!("op-succinct-journal-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn proving(byte: u8) -> PendingOperation {
+        PendingOperation::GameProving { game_address: Address::repeat_byte(byte) }
+    }
+
+    #[test]
+    fn test_open_without_journal() {
+        let dir = state_dir("empty");
+        let journal = ProposerJournal::open(&dir).unwrap();
+        assert!(journal.entries().is_empty());
+        assert!(dir.is_dir());
+    }
+
+    #[test]
+    fn test_replays_pending_operations_after_reopen() {
+        let dir = state_dir("replay");
+        let creation = PendingOperation::GameCreation { l2_block_number: U256::from(100) };
+        let proof = Bytes::from(vec![1, 2, 3]);
+        {
+            let journal = ProposerJournal::open(&dir).unwrap();
+            journal.record(creation.clone(), OperationStatus::Started);
+            journal.record(proving(1), OperationStatus::Started);
+            journal.record(proving(1), OperationStatus::ProofGenerated { proof: proof.clone() });
+            journal.record(proving(2), OperationStatus::Started);
+            journal.complete(&creation);
+        }
+
+        let journal = ProposerJournal::open(&dir).unwrap();
+        let operations: Vec<_> =
+            journal.entries().into_iter().map(|entry| entry.operation).collect();
+        assert_eq!(operations, vec![proving(1), proving(2)]);
+        assert_eq!(journal.generated_proof(Address::repeat_byte(1)), Some(proof));
+        assert_eq!(journal.generated_proof(Address::repeat_byte(2)), None);
+    }
+
+    #[test]
+    fn test_ignores_partially_written_journal_update() {
+        let dir = state_dir("partial");
+        {
+            let journal = ProposerJournal::open(&dir).unwrap();
+            journal.record(proving(1), OperationStatus::Started);
+        }
+
+        // A crash while writing the next update leaves a truncated temporary file behind.
+        let path = dir.join(JOURNAL_FILE_NAME);
+        fs::write(path.with_extension("json.tmp"), br#"[{"operation":{"GameProving""#).unwrap();
+
+        let journal = ProposerJournal::open(&dir).unwrap();
+        let operations: Vec<_> =
+            journal.entries().into_iter().map(|entry| entry.operation).collect();
+        assert_eq!(operations, vec![proving(1)]);
+
+        // The next update overwrites the temporary file and is replayed after reopening.
+        journal.record(proving(2), OperationStatus::Started);
+        let journal = ProposerJournal::open(&dir).unwrap();
+        let operations: Vec<_> =
+            journal.entries().into_iter().map(|entry| entry.operation).collect();
+        assert_eq!(operations, vec![proving(1), proving(2)]);
+    }
+
+    #[test]
+    fn test_rejects_corrupt_journal() {
+        let dir = state_dir("corrupt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(JOURNAL_FILE_NAME), b"[{").unwrap();
+        assert!(ProposerJournal::open(&dir).is_err());
+    }
+}
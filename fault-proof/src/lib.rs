@@ -1,36 +1,111 @@
+pub mod audit;
+pub mod cache;
 pub mod config;
 pub mod contract;
+pub mod correctness;
+pub mod health;
+pub mod journal;
+pub mod notify;
+pub mod output_root;
 pub mod prometheus;
 pub mod proposer;
+pub mod reorg;
+pub mod rpc_limit;
 pub mod utils;
+pub mod witness_validation;
+
+use std::{collections::HashSet, future::Future, ops::Deref, time::Instant};
 
 use alloy_eips::BlockNumberOrTag;
-use alloy_primitives::{address, keccak256, Address, FixedBytes, B256, U256};
+use alloy_primitives::{address, Address, FixedBytes, B256, U256};
 use alloy_provider::{Provider, RootProvider};
-use alloy_rpc_types_eth::Block;
-use alloy_sol_types::SolValue;
+use alloy_rpc_types_eth::{Block, Filter};
+use alloy_sol_types::SolEvent;
 use alloy_transport_http::reqwest::Url;
 use anyhow::{bail, Result};
 use async_trait::async_trait;
+use futures::{future::try_join_all, stream, StreamExt};
 use op_alloy_network::Optimism;
 use op_alloy_rpc_types::Transaction;
 use op_succinct_signer_utils::Signer;
+use serde::Deserialize;
+use strum_macros::EnumString;
 
 use crate::{
     contract::{
-        AnchorStateRegistry, DisputeGameFactory::DisputeGameFactoryInstance, GameStatus, L2Output,
-        OPSuccinctFaultDisputeGame, ProposalStatus,
+        AnchorStateRegistry, ClaimData,
+        DisputeGameFactory::{DisputeGameCreated, DisputeGameFactoryInstance},
+        GameSearchResult, GameStatus,
+        OPSuccinctFaultDisputeGame::{self, Challenged, Proved, Resolved},
+        ProposalStatus,
+    },
+    output_root::L2OutputRootHasher,
+    prometheus::{
+        record_gas_spent_wei, ChallengerGauge, ChallengerHistogram, ProposerGauge,
+        ProposerHistogram, ProposerMetrics, TxOperation,
     },
-    prometheus::{ChallengerGauge, ProposerGauge},
+    utils::{gas_cost_wei, log_dry_run_transaction, send_with_retry, RetryPolicy},
 };
-use op_succinct_host_utils::metrics::MetricsGauge;
+use op_succinct_host_utils::metrics::{MetricsGauge, MetricsHistogram};
 
 pub type L1Provider = RootProvider;
-pub type L2Provider = RootProvider<Optimism>;
 pub type L2NodeProvider = RootProvider<Optimism>;
 
-pub const NUM_CONFIRMATIONS: u64 = 3;
-pub const TIMEOUT_SECONDS: u64 = 60;
+#[deprecated(note = "Configured by `NUM_CONFIRMATIONS`, see `RetryPolicy::num_confirmations`")]
+pub const NUM_CONFIRMATIONS: u64 = utils::DEFAULT_NUM_CONFIRMATIONS;
+#[deprecated(note = "Configured by `TX_TIMEOUT_SECS`, see `RetryPolicy::timeout`")]
+pub const TIMEOUT_SECONDS: u64 = utils::DEFAULT_TX_TIMEOUT_SECS;
+
+/// The number of games fetched per `findLatestGames` call when searching for the latest valid
+/// proposal.
+const LATEST_GAMES_PAGE_SIZE: u64 = 16;
+
+/// The Succinct Prover Network private key used when none is configured, which is only valid in
+/// mock mode.
+pub const DEFAULT_NETWORK_PRIVATE_KEY: &str =
+    "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+/// The `L2ToL1MessagePasser` predeploy, whose storage root is committed to in the output root.
+pub const L2_TO_L1_MESSAGE_PASSER: Address = address!("0x4200000000000000000000000000000000000016");
+
+/// An L2 RPC provider, along with the address of the `L2ToL1MessagePasser` whose storage root is
+/// committed to in the output roots it computes.
+///
+/// Dereferences to the underlying provider for all other RPC calls.
+#[derive(Debug, Clone)]
+pub struct L2Provider {
+    provider: RootProvider<Optimism>,
+    l2_to_l1_message_passer: Address,
+    /// The mode whose metrics storage root fetch latencies are recorded in.
+    mode: Mode,
+    /// The factory whose proposer metrics storage root fetch latencies are labeled with.
+    factory: Address,
+}
+
+impl L2Provider {
+    pub fn new(
+        provider: RootProvider<Optimism>,
+        l2_to_l1_message_passer: Address,
+        mode: Mode,
+        factory: Address,
+    ) -> Self {
+        Self { provider, l2_to_l1_message_passer, mode, factory }
+    }
+
+    /// Returns a provider sharing the connection of this one, whose storage root fetch latencies
+    /// are labeled with `factory` instead.
+    pub fn for_factory(&self, factory: Address) -> Self {
+        Self { factory, ..self.clone() }
+    }
+}
+
+impl Deref for L2Provider {
+    type Target = RootProvider<Optimism>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.provider
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 pub enum Mode {
@@ -44,6 +119,65 @@ pub enum Action {
     Skipped,
 }
 
+/// An event emitted over the lifecycle of a dispute game.
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    /// The game was created by the factory.
+    Created { root_claim: B256 },
+    /// The game was challenged.
+    Challenged { challenger: Address },
+    /// A proof was submitted for the game.
+    Proved { prover: Address },
+    /// The game was resolved.
+    Resolved { status: GameStatus },
+}
+
+/// A game event along with the L1 block it was emitted in.
+#[derive(Debug, Clone)]
+pub struct GameEventLog {
+    pub block_number: u64,
+    pub event: GameEvent,
+}
+
+/// The bonds locked in in-progress games.
+#[derive(Debug, Clone, Default)]
+pub struct BondEscrowState {
+    pub proposer_bonds_locked: U256,
+    pub challenger_bonds_locked: U256,
+    pub total_locked: U256,
+    pub proposals_with_proposer_bond: u32,
+    pub proposals_with_challenger_bond: u32,
+    /// The bonds posted by the given owner, as the proposer or the challenger of a game.
+    pub own_bonds_locked: U256,
+}
+
+/// The response of the `optimism_outputAtBlock` RPC method, of which only the output root is used.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OutputAtBlockResponse {
+    output_root: B256,
+}
+
+/// Fetches the output root at a given L2 block number from the rollup node with the
+/// `optimism_outputAtBlock` RPC method.
+///
+/// This is faster than computing the output root from the L2 RPC, but fails on rollup nodes whose
+/// execution client has pruned the state at the block.
+pub async fn fetch_output_root_at_block(
+    l2_node_provider: &L2NodeProvider,
+    l2_block_number: U256,
+) -> Result<B256> {
+    let response: OutputAtBlockResponse = l2_node_provider
+        .raw_request("optimism_outputAtBlock".into(), (format!("0x{l2_block_number:x}"),))
+        .await?;
+    Ok(response.output_root)
+}
+
+/// Returns the game indices from `start` to `end`, inclusive.
+pub fn game_indices_between(start: U256, end: U256) -> Vec<U256> {
+    (start.to::<u64>()..=end.to::<u64>()).map(U256::from).collect()
+}
+
 #[async_trait]
 pub trait L2ProviderTrait {
     /// Get the L2 block by number.
@@ -59,12 +193,41 @@ pub trait L2ProviderTrait {
         block_number: BlockNumberOrTag,
     ) -> Result<B256>;
 
+    /// The address of the `L2ToL1MessagePasser` whose storage root is committed to in the output
+    /// root.
+    fn l2_to_l1_message_passer(&self) -> Address;
+
     /// Compute the output root at a given L2 block number.
     async fn compute_output_root_at_block(&self, l2_block_number: U256) -> Result<FixedBytes<32>>;
+
+    /// Compute the output root at a given L2 block number, recording how long it took in the
+    /// mode-specific metrics, labeled with `factory` in proposer mode.
+    async fn compute_output_root_at_block_timed(
+        &self,
+        l2_block_number: U256,
+        mode: Mode,
+        factory: Address,
+    ) -> Result<FixedBytes<32>> {
+        let start = Instant::now();
+        let output_root = self.compute_output_root_at_block(l2_block_number).await?;
+        let elapsed_secs = start.elapsed().as_secs_f64();
+        match mode {
+            Mode::Proposer => ProposerMetrics::new(factory)
+                .record(ProposerHistogram::OutputRootComputationTimeSecs, elapsed_secs),
+            Mode::Challenger => {
+                ChallengerHistogram::OutputRootComputationTimeSecs.record(elapsed_secs)
+            }
+        }
+        Ok(output_root)
+    }
 }
 
 #[async_trait]
 impl L2ProviderTrait for L2Provider {
+    fn l2_to_l1_message_passer(&self) -> Address {
+        self.l2_to_l1_message_passer
+    }
+
     /// Get the L2 block by number.
     async fn get_l2_block_by_number(
         &self,
@@ -78,14 +241,27 @@ impl L2ProviderTrait for L2Provider {
         }
     }
 
-    /// Get the L2 storage root for an address at a given block number.
+    /// Get the L2 storage root for an address at a given block number, recording how long the
+    /// `eth_getProof` call took in the mode-specific metrics.
+    ///
+    /// A high latency here compared to the output root computation as a whole points at slow trie
+    /// traversal on the L2 node rather than slow block retrieval.
     async fn get_l2_storage_root(
         &self,
         address: Address,
         block_number: BlockNumberOrTag,
     ) -> Result<B256> {
+        let start = Instant::now();
         let storage_root =
             self.get_proof(address, Vec::new()).block_id(block_number.into()).await?.storage_hash;
+        let elapsed_secs = start.elapsed().as_secs_f64();
+        match self.mode {
+            Mode::Proposer => ProposerMetrics::new(self.factory)
+                .record(ProposerHistogram::L2StorageRootFetchLatencySecs, elapsed_secs),
+            Mode::Challenger => {
+                ChallengerHistogram::L2StorageRootFetchLatencySecs.record(elapsed_secs)
+            }
+        }
         Ok(storage_root)
     }
 
@@ -103,22 +279,81 @@ impl L2ProviderTrait for L2Provider {
         let l2_claim_hash = l2_block.header.hash;
         let l2_storage_root = self
             .get_l2_storage_root(
-                address!("0x4200000000000000000000000000000000000016"),
+                self.l2_to_l1_message_passer,
                 BlockNumberOrTag::Number(l2_block_number.to::<u64>()),
             )
             .await?;
 
-        let l2_claim_encoded = L2Output {
-            zero: 0,
-            l2_state_root: l2_state_root.0.into(),
-            l2_storage_hash: l2_storage_root.0.into(),
-            l2_claim_hash: l2_claim_hash.0.into(),
-        };
-        let l2_output_root = keccak256(l2_claim_encoded.abi_encode());
-        Ok(l2_output_root)
+        Ok(L2OutputRootHasher::hash(l2_state_root, l2_storage_root, l2_claim_hash))
     }
 }
 
+/// The outcome of a search for the latest valid proposal, from which the next search resumes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValidProposalSearch {
+    /// The L2 block number and game index of the latest valid proposal, if any.
+    pub latest_valid_proposal: Option<(U256, U256)>,
+    /// The index of the latest game at the time of the search, if any games exist.
+    pub latest_checked_index: Option<U256>,
+}
+
+/// How [`FactoryTrait::get_latest_valid_proposal`] searches the games for the latest valid
+/// proposal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum ValidProposalSearchStrategy {
+    /// Checks every game from the latest game backwards.
+    #[default]
+    Linear,
+    /// Steps back from the latest game in doubling strides until a valid game is found, then
+    /// binary searches for the last valid game. This checks O(log N) games instead of O(N) for a
+    /// long run of invalid games, but assumes that the valid games since the anchor come before
+    /// all invalid ones.
+    Exponential,
+}
+
+/// The order in which a challenger challenges the games of different proposers.
+#[derive(Debug, Clone, Default)]
+pub struct ProposerPriority {
+    /// Proposers whose games are challenged before those of other proposers, regardless of age.
+    pub prioritized: HashSet<Address>,
+    /// Proposers whose games are never challenged.
+    pub ignored: HashSet<Address>,
+}
+
+impl ProposerPriority {
+    /// Returns the priority of the games of `proposer`, lower first, or `None` if they are
+    /// ignored.
+    pub fn of(&self, proposer: Address) -> Option<u8> {
+        if self.ignored.contains(&proposer) {
+            None
+        } else if self.prioritized.contains(&proposer) {
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+}
+
+/// A game of a chain of games linked by their parent index, as returned by
+/// [`FactoryTrait::get_game_chain`].
+#[derive(Debug, Clone)]
+pub struct GameChainEntry {
+    pub index: U256,
+    pub address: Address,
+    pub l2_block_number: U256,
+    pub game_creator: Address,
+    pub status: GameStatus,
+    pub claim_data: ClaimData,
+}
+
+/// The error returned when none of the games checked for the latest valid proposal is valid.
+#[derive(Debug, thiserror::Error)]
+#[error("No valid proposal found in the latest {max_games_to_check} games")]
+pub struct ValidProposalScanExhausted {
+    pub max_games_to_check: u64,
+}
+
 #[async_trait]
 pub trait FactoryTrait<P>
 where
@@ -136,14 +371,44 @@ where
     /// Fetches the game address by index.
     async fn fetch_game_address_by_index(&self, game_index: U256) -> Result<Address>;
 
+    /// Fetches the game address by index, or `None` if `gameAtIndex` reverts because the index is
+    /// not below the game count.
+    ///
+    /// Transport errors are returned as errors, so that a failing RPC isn't mistaken for the end of
+    /// the games.
+    async fn try_fetch_game_address_by_index(&self, game_index: U256) -> Result<Option<Address>>;
+
+    /// Fetches the addresses of the games at the given indices.
+    ///
+    /// The addresses are fetched concurrently in batches of `batch_size` requests. Fails if any of
+    /// the indices is not below the game count, instead of surfacing the revert of `gameAtIndex`.
+    async fn fetch_game_addresses_by_index(
+        &self,
+        game_indices: &[U256],
+        batch_size: usize,
+    ) -> Result<Vec<Address>>;
+
+    /// Returns the chain of games from the root game, which has no parent, to the game at
+    /// `game_index`, by following the parent index of each game.
+    async fn get_game_chain(&self, game_index: U256) -> Result<Vec<GameChainEntry>>;
+
     /// Get the latest valid proposal.
     ///
-    /// This function checks from the latest game to the earliest game, returning the latest valid
-    /// proposal.
-    async fn get_latest_valid_proposal(
+    /// This function checks from the latest game to the earliest game of the game type, returning
+    /// the latest valid proposal. Given the `previous` search, only the games created since are
+    /// checked. The search stops at the first game behind the anchor, and is repeated once if the
+    /// anchor moves past the proposal found. Fails with [`ValidProposalScanExhausted`] if none of
+    /// `max_games_to_check` games is valid. The games are checked in the order of `strategy`.
+    async fn get_latest_valid_proposal<L>(
         &self,
-        l2_provider: L2Provider,
-    ) -> Result<Option<(U256, U256)>>;
+        game_type: u32,
+        l2_provider: L,
+        previous: Option<ValidProposalSearch>,
+        max_games_to_check: u64,
+        strategy: ValidProposalSearchStrategy,
+    ) -> Result<ValidProposalSearch>
+    where
+        L: L2ProviderTrait + Send + Sync;
 
     /// Get the anchor state registry address.
     async fn get_anchor_state_registry_address(&self, game_type: u32) -> Result<Address>;
@@ -153,6 +418,30 @@ where
     /// This function returns the L2 block number of the anchor game for a given game type.
     async fn get_anchor_l2_block_number(&self, game_type: u32) -> Result<U256>;
 
+    /// Get the access manager address.
+    async fn get_access_manager_address(&self, game_type: u32) -> Result<Address>;
+
+    /// Get all events emitted for the game at the given index since `from_block`.
+    ///
+    /// Returns the factory's creation event along with all events emitted by the game itself,
+    /// sorted by the order in which they were emitted.
+    async fn get_all_game_events(
+        &self,
+        game_index: U256,
+        from_block: u64,
+    ) -> Result<Vec<GameEventLog>>;
+
+    /// Compute the total bonds locked in in-progress games, and those of them posted by `owner`.
+    ///
+    /// This function checks a window of recent games, starting from.
+    /// (latest_game_index - max_games_to_check) up to latest_game_index.
+    async fn compute_total_bonds_locked(
+        &self,
+        game_type: u32,
+        max_games_to_check: u64,
+        owner: Address,
+    ) -> Result<BondEscrowState>;
+
     /// Check if a game is finalized.
     async fn is_game_finalized(&self, game_type: u32, game_address: Address) -> Result<bool>;
 
@@ -164,28 +453,78 @@ where
         claimant: Address,
     ) -> Result<bool>;
 
-    /// Get the oldest game address with a given condition.
-    async fn get_oldest_game_address<S, O>(
+    /// Get the addresses of the games with a given condition.
+    ///
+    /// `creator_priority` returns the priority of the games created by an address, or `None` to
+    /// skip them. Games of the lowest priority value come first, oldest first among them. Up to
+    /// `concurrency` games are checked at once.
+    #[allow(clippy::too_many_arguments)]
+    async fn get_game_addresses<L, S, O, C>(
         &self,
+        mode: Mode,
         max_games_to_check: u64,
-        l2_provider: L2Provider,
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
         status_check: S,
         output_root_check: O,
+        creator_priority: C,
+    ) -> Result<Vec<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync,
+        S: Fn(ProposalStatus) -> bool + Send + Sync,
+        O: Fn(B256, B256) -> bool + Send + Sync,
+        C: Fn(Address) -> Option<u8> + Send + Sync;
+
+    /// Get the oldest game address with a given condition, the first of
+    /// [`Self::get_game_addresses`].
+    #[allow(clippy::too_many_arguments)]
+    async fn get_oldest_game_address<L, S, O, C>(
+        &self,
+        mode: Mode,
+        max_games_to_check: u64,
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+        status_check: S,
+        output_root_check: O,
+        creator_priority: C,
         log_message: &str,
     ) -> Result<Option<Address>>
     where
+        L: L2ProviderTrait + Send + Sync,
         S: Fn(ProposalStatus) -> bool + Send + Sync,
-        O: Fn(B256, B256) -> bool + Send + Sync;
+        O: Fn(B256, B256) -> bool + Send + Sync,
+        C: Fn(Address) -> Option<u8> + Send + Sync;
 
     /// Get the oldest challengable game address.
     ///
     /// This function checks a window of recent games, starting from.
-    /// (latest_game_index - max_games_to_check_for_challenge) up to latest_game_index.
-    async fn get_oldest_challengable_game_address(
+    /// (latest_game_index - max_games_to_check_for_challenge) up to latest_game_index. The games
+    /// of prioritized proposers are returned first, and those of ignored proposers never.
+    async fn get_oldest_challengable_game_address<L>(
         &self,
         max_games_to_check_for_challenge: u64,
-        l2_provider: L2Provider,
-    ) -> Result<Option<Address>>;
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+        proposer_priority: &ProposerPriority,
+    ) -> Result<Option<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync;
+
+    /// Get the addresses of all challengable games in the window checked by
+    /// [`Self::get_oldest_challengable_game_address`], in the order it would return them.
+    async fn get_challengable_game_addresses<L>(
+        &self,
+        max_games_to_check_for_challenge: u64,
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+        proposer_priority: &ProposerPriority,
+    ) -> Result<Vec<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync;
 
     /// Get the oldest defensible game address.
     ///
@@ -194,11 +533,15 @@ where
     ///
     /// This function checks a window of recent games, starting from
     /// (latest_game_index - max_games_to_check_for_defense) up to latest_game_index.
-    async fn get_oldest_defensible_game_address(
+    async fn get_oldest_defensible_game_address<L>(
         &self,
         max_games_to_check_for_defense: u64,
-        l2_provider: L2Provider,
-    ) -> Result<Option<Address>>;
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+    ) -> Result<Option<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync;
 
     /// Get the oldest game address with claimable bonds.
     ///
@@ -228,28 +571,37 @@ where
     /// Attempts to resolve a challenged game.
     ///
     /// This function checks if the game is in progress and challenged, and if so, attempts to
-    /// resolve it.
+    /// resolve it. In challenger mode, resolution is additionally delayed by
+    /// `resolution_grace_secs` past the deadline.
+    #[allow(clippy::too_many_arguments)]
     async fn try_resolve_games(
         &self,
         index: U256,
         mode: Mode,
+        resolution_grace_secs: u64,
+        dry_run: bool,
         signer: Signer,
         l1_rpc: Url,
+        tx_retry_policy: &RetryPolicy,
         l1_provider: L1Provider,
         l2_provider: L2Provider,
     ) -> Result<Action>;
 
     /// Attempts to resolve all challenged games that the challenger won, up to
-    /// `max_games_to_check_for_resolution`.
+    /// `max_games_to_check_for_resolution`. Returns the number of games resolved.
+    #[allow(clippy::too_many_arguments)]
     async fn resolve_games(
         &self,
         mode: Mode,
         max_games_to_check_for_resolution: u64,
+        resolution_grace_secs: u64,
+        dry_run: bool,
         signer: Signer,
         l1_rpc: Url,
+        tx_retry_policy: &RetryPolicy,
         l1_provider: L1Provider,
         l2_provider: L2Provider,
-    ) -> Result<()>;
+    ) -> Result<u32>;
 }
 
 #[async_trait]
@@ -288,77 +640,144 @@ where
 
     /// Fetches the game address by index.
     async fn fetch_game_address_by_index(&self, game_index: U256) -> Result<Address> {
-        let game = self.gameAtIndex(game_index).call().await?.proxy;
-        Ok(game)
+        match self.try_fetch_game_address_by_index(game_index).await? {
+            Some(game) => Ok(game),
+            None => bail!("Game index {game_index} is out of range"),
+        }
+    }
+
+    /// Fetches the game address by index, or `None` if the index is out of range.
+    async fn try_fetch_game_address_by_index(&self, game_index: U256) -> Result<Option<Address>> {
+        match self.gameAtIndex(game_index).call().await {
+            Ok(game) => Ok(Some(game.proxy)),
+            // Only an out of range index makes the `gameAtIndex` call revert.
+            Err(e) if e.as_revert_data().is_some() => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Fetches the addresses of the games at the given indices.
+    async fn fetch_game_addresses_by_index(
+        &self,
+        game_indices: &[U256],
+        batch_size: usize,
+    ) -> Result<Vec<Address>> {
+        if game_indices.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let game_count = self.gameCount().call().await?;
+        if let Some(game_index) = game_indices.iter().find(|index| **index >= game_count) {
+            bail!("Game index {game_index} is out of range, the factory has {game_count} games");
+        }
+
+        let mut game_addresses = Vec::with_capacity(game_indices.len());
+        for batch in game_indices.chunks(batch_size.max(1)) {
+            game_addresses.extend(
+                try_join_all(batch.iter().map(|index| self.fetch_game_address_by_index(*index)))
+                    .await?,
+            );
+        }
+        Ok(game_addresses)
+    }
+
+    /// Returns the chain of games from the root game to the game at `game_index`.
+    async fn get_game_chain(&self, game_index: U256) -> Result<Vec<GameChainEntry>> {
+        let mut chain = Vec::new();
+        let mut index = game_index;
+        loop {
+            let address = self.fetch_game_address_by_index(index).await?;
+            let game = OPSuccinctFaultDisputeGame::new(address, self.provider());
+            let claim_data = game.claimData().call().await?;
+            let parent_index = claim_data.parentIndex;
+            chain.push(GameChainEntry {
+                index,
+                address,
+                l2_block_number: game.l2BlockNumber().call().await?,
+                game_creator: game.gameCreator().call().await?,
+                status: game.status().call().await?,
+                claim_data,
+            });
+
+            if parent_index == u32::MAX {
+                break;
+            }
+            // Parents are always created before their children, which also rules out cycles.
+            let parent_index = U256::from(parent_index);
+            if parent_index >= index {
+                bail!("Game {index} has parent index {parent_index}, which is not an earlier game");
+            }
+            index = parent_index;
+        }
+
+        chain.reverse();
+        Ok(chain)
     }
 
     /// Get the latest valid proposal.
     ///
-    /// This function checks from the latest game to the earliest game, returning the latest valid
-    /// proposal.
-    async fn get_latest_valid_proposal(
+    /// This function checks from the latest game to the earliest game of the game type, returning
+    /// the latest valid proposal. Given the `previous` search, only the games created since are
+    /// checked.
+    async fn get_latest_valid_proposal<L>(
         &self,
-        l2_provider: L2Provider,
-    ) -> Result<Option<(U256, U256)>> {
+        game_type: u32,
+        l2_provider: L,
+        previous: Option<ValidProposalSearch>,
+        max_games_to_check: u64,
+        strategy: ValidProposalSearchStrategy,
+    ) -> Result<ValidProposalSearch>
+    where
+        L: L2ProviderTrait + Send + Sync,
+    {
         // Get latest game index, return None if no games exist.
-        let Some(mut game_index) = self.fetch_latest_game_index().await? else {
+        let Some(latest_game_index) = self.fetch_latest_game_index().await? else {
             tracing::info!("No games exist yet for finding latest valid proposal");
-            return Ok(None);
+            return Ok(ValidProposalSearch::default());
         };
 
-        let mut block_number;
-
-        // Loop through games in reverse order (latest to earliest) to find the most recent valid
-        // game.
-        loop {
-            // Get the game contract for the current index.
-            let game_address = self.fetch_game_address_by_index(game_index).await?;
-            let game = OPSuccinctFaultDisputeGame::new(game_address, self.provider());
+        let mut anchor_l2_block_number = self.get_anchor_l2_block_number(game_type).await?;
+        let mut search = search_latest_valid_proposal(
+            self,
+            strategy,
+            game_type,
+            &l2_provider,
+            previous,
+            max_games_to_check,
+            latest_game_index,
+            anchor_l2_block_number,
+        )
+        .await?;
 
-            // Get the L2 block number the game is proposing output for.
-            block_number = game.l2BlockNumber().call().await?;
+        // The anchor may have advanced during a long scan, past the proposal that was found. Scan
+        // once more from the new anchor, rather than build on a proposal that is already behind it.
+        let current_anchor_l2_block_number = self.get_anchor_l2_block_number(game_type).await?;
+        if current_anchor_l2_block_number != anchor_l2_block_number {
             tracing::debug!(
-                "Checking if game {:?} at block {:?} is valid",
-                game_address,
-                block_number
+                "Anchor L2 block number moved from {:?} to {:?} during the valid proposal search",
+                anchor_l2_block_number,
+                current_anchor_l2_block_number
             );
-
-            // Get the output root the game is proposing.
-            let game_claim = game.rootClaim().call().await?;
-
-            // Compute the actual output root at the L2 block number.
-            let output_root = l2_provider.compute_output_root_at_block(block_number).await?;
-
-            // If the output root matches the game claim, we've found the latest valid proposal.
-            if output_root == game_claim {
-                break;
+            anchor_l2_block_number = current_anchor_l2_block_number;
+            if search
+                .latest_valid_proposal
+                .is_some_and(|(block_number, _)| block_number < anchor_l2_block_number)
+            {
+                search = search_latest_valid_proposal(
+                    self,
+                    strategy,
+                    game_type,
+                    &l2_provider,
+                    None,
+                    max_games_to_check,
+                    latest_game_index,
+                    anchor_l2_block_number,
+                )
+                .await?;
             }
-
-            // If the output root doesn't match the game claim, we need to find earlier games.
-            tracing::info!(
-                "Output root {:?} is not same as game claim {:?}",
-                output_root,
-                game_claim
-            );
-
-            // If we've reached index 0 (the earliest game) and still haven't found a valid
-            // proposal. Return `None` as no valid proposals were found.
-            if game_index == U256::ZERO {
-                tracing::info!("No valid proposals found after checking all games");
-                return Ok(None);
-            }
-
-            // Decrement the game index to check the previous game.
-            game_index -= U256::from(1);
         }
 
-        tracing::info!(
-            "Latest valid proposal at game index {:?} with l2 block number: {:?}",
-            game_index,
-            block_number
-        );
-
-        Ok(Some((block_number, game_index)))
+        Ok(search)
     }
 
     /// Get the anchor state registry address.
@@ -381,6 +800,105 @@ where
         Ok(anchor_l2_block_number)
     }
 
+    /// Get the access manager address.
+    async fn get_access_manager_address(&self, game_type: u32) -> Result<Address> {
+        let game_impl_address = self.gameImpls(game_type).call().await?;
+        let game_impl = OPSuccinctFaultDisputeGame::new(game_impl_address, self.provider());
+        let access_manager_address = game_impl.accessManager().call().await?;
+        Ok(access_manager_address)
+    }
+
+    /// Get all events emitted for the game at the given index since `from_block`.
+    async fn get_all_game_events(
+        &self,
+        game_index: U256,
+        from_block: u64,
+    ) -> Result<Vec<GameEventLog>> {
+        let game_address = self.fetch_game_address_by_index(game_index).await?;
+
+        let creation_filter = Filter::new()
+            .address(*self.address())
+            .event_signature(DisputeGameCreated::SIGNATURE_HASH)
+            .topic1(game_address.into_word())
+            .from_block(from_block);
+        let game_filter = Filter::new()
+            .address(game_address)
+            .event_signature(vec![
+                Challenged::SIGNATURE_HASH,
+                Proved::SIGNATURE_HASH,
+                Resolved::SIGNATURE_HASH,
+            ])
+            .from_block(from_block);
+
+        let mut logs = self.provider().get_logs(&creation_filter).await?;
+        logs.extend(self.provider().get_logs(&game_filter).await?);
+        logs.sort_by_key(|log| (log.block_number, log.log_index));
+
+        let mut events = Vec::with_capacity(logs.len());
+        for log in logs {
+            let event = if let Ok(event) = DisputeGameCreated::decode_log(&log.inner) {
+                GameEvent::Created { root_claim: event.rootClaim }
+            } else if let Ok(event) = Challenged::decode_log(&log.inner) {
+                GameEvent::Challenged { challenger: event.challenger }
+            } else if let Ok(event) = Proved::decode_log(&log.inner) {
+                GameEvent::Proved { prover: event.prover }
+            } else if let Ok(event) = Resolved::decode_log(&log.inner) {
+                GameEvent::Resolved { status: event.status }
+            } else {
+                continue;
+            };
+
+            events.push(GameEventLog { block_number: log.block_number.unwrap_or_default(), event });
+        }
+
+        Ok(events)
+    }
+
+    /// Compute the total bonds locked in in-progress games, and those of them posted by `owner`.
+    async fn compute_total_bonds_locked(
+        &self,
+        game_type: u32,
+        max_games_to_check: u64,
+        owner: Address,
+    ) -> Result<BondEscrowState> {
+        let mut state = BondEscrowState::default();
+        let Some(latest_game_index) = self.fetch_latest_game_index().await? else {
+            return Ok(state);
+        };
+
+        let init_bond = self.fetch_init_bond(game_type).await?;
+        let challenger_bond = self.fetch_challenger_bond(game_type).await?;
+
+        let mut game_index = latest_game_index.saturating_sub(U256::from(max_games_to_check));
+        while game_index <= latest_game_index {
+            let game_address = self.fetch_game_address_by_index(game_index).await?;
+            game_index += U256::from(1);
+
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.provider());
+            if game.status().call().await? != GameStatus::IN_PROGRESS {
+                continue;
+            }
+
+            state.proposer_bonds_locked += init_bond;
+            state.proposals_with_proposer_bond += 1;
+            if game.gameCreator().call().await? == owner {
+                state.own_bonds_locked += init_bond;
+            }
+
+            let countered_by = game.claimData().call().await?.counteredBy;
+            if countered_by != Address::ZERO {
+                state.challenger_bonds_locked += challenger_bond;
+                state.proposals_with_challenger_bond += 1;
+                if countered_by == owner {
+                    state.own_bonds_locked += challenger_bond;
+                }
+            }
+        }
+        state.total_locked = state.proposer_bonds_locked + state.challenger_bonds_locked;
+
+        Ok(state)
+    }
+
     /// Check if a game is finalized.
     async fn is_game_finalized(&self, game_type: u32, game_address: Address) -> Result<bool> {
         let anchor_state_registry_address =
@@ -427,105 +945,225 @@ where
         Ok(true)
     }
 
-    async fn get_oldest_game_address<S, O>(
+    async fn get_game_addresses<L, S, O, C>(
         &self,
+        mode: Mode,
         max_games_to_check: u64,
-        l2_provider: L2Provider,
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
         status_check: S,
         output_root_check: O,
-        log_message: &str,
-    ) -> Result<Option<Address>>
+        creator_priority: C,
+    ) -> Result<Vec<Address>>
     where
+        L: L2ProviderTrait + Send + Sync,
         S: Fn(ProposalStatus) -> bool + Send + Sync,
         O: Fn(B256, B256) -> bool + Send + Sync,
+        C: Fn(Address) -> Option<u8> + Send + Sync,
     {
         let Some(latest_game_index) = self.fetch_latest_game_index().await? else {
             tracing::info!("No games exist yet");
-            return Ok(None);
+            return Ok(Vec::new());
         };
 
-        let mut game_index = latest_game_index.saturating_sub(U256::from(max_games_to_check));
+        let oldest_game_index = latest_game_index.saturating_sub(U256::from(max_games_to_check));
+        let game_indices = game_indices_between(oldest_game_index, latest_game_index);
+        let game_addresses = self.fetch_game_addresses_by_index(&game_indices, batch_size).await?;
 
-        while game_index <= latest_game_index {
-            let game_address = self.fetch_game_address_by_index(game_index).await?;
-            let game = OPSuccinctFaultDisputeGame::new(game_address, self.provider());
-            let claim_data = game.claimData().call().await?;
+        let current_timestamp =
+            l2_provider.get_l2_block_by_number(BlockNumberOrTag::Latest).await?.header.timestamp;
 
-            if !status_check(claim_data.status) {
-                tracing::info!(
-                    "Game {:?} at index {:?} does not match status criteria, skipping",
-                    game_address,
-                    game_index
-                );
-                game_index += U256::from(1);
-                continue;
-            }
+        // Games are checked concurrently, so the matches are ordered once all games are checked.
+        let l2_provider = &l2_provider;
+        let status_check = &status_check;
+        let output_root_check = &output_root_check;
+        let creator_priority = &creator_priority;
+        let matching_games = stream::iter(game_indices.into_iter().zip(game_addresses))
+            .map(|(game_index, game_address)| async move {
+                let game = OPSuccinctFaultDisputeGame::new(game_address, self.provider());
+                let claim_data = game.claimData().call().await?;
+
+                if !status_check(claim_data.status) {
+                    tracing::info!(
+                        "Game {:?} at index {:?} does not match status criteria, skipping",
+                        game_address,
+                        game_index
+                    );
+                    return Ok(None);
+                }
 
-            let current_timestamp = l2_provider
-                .get_l2_block_by_number(BlockNumberOrTag::Latest)
-                .await?
-                .header
-                .timestamp;
-            let deadline = U256::from(claim_data.deadline).to::<u64>();
-            if deadline < current_timestamp {
-                tracing::info!(
-                    "Game {:?} at index {:?} deadline {:?} has passed, skipping",
-                    game_address,
-                    game_index,
-                    deadline
-                );
-                game_index += U256::from(1);
-                continue;
-            }
+                let deadline = U256::from(claim_data.deadline).to::<u64>();
+                if deadline < current_timestamp {
+                    tracing::info!(
+                        "Game {:?} at index {:?} deadline {:?} has passed, skipping",
+                        game_address,
+                        game_index,
+                        deadline
+                    );
+                    return Ok(None);
+                }
 
-            let block_number = game.l2BlockNumber().call().await?;
-            let game_claim = game.rootClaim().call().await?;
-            let output_root = l2_provider.compute_output_root_at_block(block_number).await?;
+                let creator = game.gameCreator().call().await?;
+                let Some(priority) = creator_priority(creator) else {
+                    tracing::debug!(
+                        "Game {:?} at index {:?} was created by ignored proposer {:?}, skipping",
+                        game_address,
+                        game_index,
+                        creator
+                    );
+                    return Ok(None);
+                };
 
-            if output_root_check(output_root, game_claim) {
-                tracing::info!(
-                    "{} {:?} at game index {:?} with L2 block number: {:?}",
-                    log_message,
+                let block_number = game.l2BlockNumber().call().await?;
+                let game_claim = game.rootClaim().call().await?;
+                let output_root = l2_provider
+                    .compute_output_root_at_block_timed(block_number, mode, *self.address())
+                    .await?;
+
+                Ok(output_root_check(output_root, game_claim).then_some((
+                    priority,
+                    game_index,
+                    game_address,
+                    block_number,
+                )))
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<Result<Option<(u8, U256, Address, U256)>>>>()
+            .await;
+
+        let mut matching_games = matching_games
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        sort_matching_games(&mut matching_games);
+
+        Ok(matching_games
+            .into_iter()
+            .map(|(_, game_index, game_address, block_number)| {
+                tracing::debug!(
+                    "Matching game {:?} at game index {:?} with L2 block number: {:?}",
                     game_address,
                     game_index,
                     block_number
                 );
-                return Ok(Some(game_address));
-            }
+                game_address
+            })
+            .collect())
+    }
 
-            game_index += U256::from(1);
-        }
+    async fn get_oldest_game_address<L, S, O, C>(
+        &self,
+        mode: Mode,
+        max_games_to_check: u64,
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+        status_check: S,
+        output_root_check: O,
+        creator_priority: C,
+        log_message: &str,
+    ) -> Result<Option<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync,
+        S: Fn(ProposalStatus) -> bool + Send + Sync,
+        O: Fn(B256, B256) -> bool + Send + Sync,
+        C: Fn(Address) -> Option<u8> + Send + Sync,
+    {
+        let oldest_game_address = self
+            .get_game_addresses(
+                mode,
+                max_games_to_check,
+                batch_size,
+                concurrency,
+                l2_provider,
+                status_check,
+                output_root_check,
+                creator_priority,
+            )
+            .await?
+            .into_iter()
+            .next();
 
-        Ok(None)
+        if let Some(game_address) = oldest_game_address {
+            tracing::info!("{} {:?}", log_message, game_address);
+        }
+        Ok(oldest_game_address)
     }
 
     /// Get the oldest challengable game address.
-    async fn get_oldest_challengable_game_address(
+    async fn get_oldest_challengable_game_address<L>(
         &self,
         max_games_to_check_for_challenge: u64,
-        l2_provider: L2Provider,
-    ) -> Result<Option<Address>> {
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+        proposer_priority: &ProposerPriority,
+    ) -> Result<Option<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync,
+    {
         self.get_oldest_game_address(
+            Mode::Challenger,
             max_games_to_check_for_challenge,
+            batch_size,
+            concurrency,
             l2_provider,
             |status| status == ProposalStatus::Unchallenged,
             |output_root, game_claim| output_root != game_claim,
+            |creator| proposer_priority.of(creator),
             "Oldest challengable game",
         )
         .await
     }
 
+    /// Get the addresses of all challengable games.
+    async fn get_challengable_game_addresses<L>(
+        &self,
+        max_games_to_check_for_challenge: u64,
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+        proposer_priority: &ProposerPriority,
+    ) -> Result<Vec<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync,
+    {
+        self.get_game_addresses(
+            Mode::Challenger,
+            max_games_to_check_for_challenge,
+            batch_size,
+            concurrency,
+            l2_provider,
+            |status| status == ProposalStatus::Unchallenged,
+            |output_root, game_claim| output_root != game_claim,
+            |creator| proposer_priority.of(creator),
+        )
+        .await
+    }
+
     /// Get the oldest defensible game address.
-    async fn get_oldest_defensible_game_address(
+    async fn get_oldest_defensible_game_address<L>(
         &self,
         max_games_to_check_for_defense: u64,
-        l2_provider: L2Provider,
-    ) -> Result<Option<Address>> {
+        batch_size: usize,
+        concurrency: usize,
+        l2_provider: L,
+    ) -> Result<Option<Address>>
+    where
+        L: L2ProviderTrait + Send + Sync,
+    {
         self.get_oldest_game_address(
+            Mode::Proposer,
             max_games_to_check_for_defense,
+            batch_size,
+            concurrency,
             l2_provider,
             |status| status == ProposalStatus::Challenged,
             |output_root, game_claim| output_root == game_claim,
+            |_| Some(0),
             "Oldest defensible game",
         )
         .await
@@ -560,7 +1198,10 @@ where
 
         for i in 0..games_to_check {
             let index = oldest_game_index + U256::from(i);
-            let game_address = self.fetch_game_address_by_index(index).await?;
+            // The RPC answering this call may be behind the one that returned the game count.
+            let Some(game_address) = self.try_fetch_game_address_by_index(index).await? else {
+                break;
+            };
             if self.is_claimable(game_type, game_address, claimant).await? {
                 return Ok(Some(game_address));
             }
@@ -603,8 +1244,11 @@ where
         &self,
         index: U256,
         mode: Mode,
+        resolution_grace_secs: u64,
+        dry_run: bool,
         signer: Signer,
         l1_rpc: Url,
+        tx_retry_policy: &RetryPolicy,
         l1_provider: L1Provider,
         l2_provider: L2Provider,
     ) -> Result<Action> {
@@ -656,15 +1300,53 @@ where
             return Ok(Action::Skipped);
         }
 
+        // Give the proposer extra time for a proof that may have been submitted right before the
+        // deadline but not yet confirmed.
+        if let Mode::Challenger = mode {
+            if deadline + resolution_grace_secs >= current_timestamp {
+                tracing::info!(
+                    "Game {:?} at index {:?} is within the {}s grace period past deadline {:?}, not attempting resolution",
+                    game_address,
+                    index,
+                    resolution_grace_secs,
+                    deadline
+                );
+                return Ok(Action::Skipped);
+            }
+        }
+
         let contract = OPSuccinctFaultDisputeGame::new(game_address, self.provider());
         let transaction_request = contract.resolve().into_transaction_request();
-        let receipt = signer.send_transaction_request(l1_rpc, transaction_request).await?;
+        if dry_run {
+            log_dry_run_transaction("resolve", &transaction_request);
+            return Ok(Action::Performed);
+        }
+
+        let receipt = send_with_retry(
+            &signer,
+            l1_rpc,
+            transaction_request,
+            tx_retry_policy,
+            mode,
+            *self.address(),
+            TxOperation::Resolve,
+        )
+        .await?;
         tracing::info!(
             "\x1b[1mSuccessfully resolved game {:?} at index {:?} with tx {:?}\x1b[0m",
             game_address,
             index,
             receipt.transaction_hash
         );
+        match mode {
+            Mode::Proposer => ProposerMetrics::new(*self.address())
+                .record_gas_spent_wei(TxOperation::Resolve, gas_cost_wei(&receipt)),
+            Mode::Challenger => record_gas_spent_wei(
+                ChallengerGauge::TotalGasSpentWei,
+                TxOperation::Resolve,
+                gas_cost_wei(&receipt),
+            ),
+        }
         Ok(Action::Performed)
     }
 
@@ -675,6 +1357,8 @@ where
             self,
             mode,
             max_games_to_check_for_resolution,
+            resolution_grace_secs,
+            dry_run,
             signer,
             l1_rpc,
             l1_provider,
@@ -685,15 +1369,18 @@ where
         &self,
         mode: Mode,
         max_games_to_check_for_resolution: u64,
+        resolution_grace_secs: u64,
+        dry_run: bool,
         signer: Signer,
         l1_rpc: Url,
+        tx_retry_policy: &RetryPolicy,
         l1_provider: L1Provider,
         l2_provider: L2Provider,
-    ) -> Result<()> {
+    ) -> Result<u32> {
         // Find latest game index, return early if no games exist.
         let Some(latest_game_index) = self.fetch_latest_game_index().await? else {
             tracing::info!("No games exist, skipping resolution");
-            return Ok(());
+            return Ok(0);
         };
 
         // If the oldest game's parent game is not resolved, we'll not attempt resolution.
@@ -705,6 +1392,7 @@ where
         let (should_attempt_resolution, game_address) =
             self.should_attempt_resolution(oldest_game_index).await?;
 
+        let mut resolved = 0;
         if should_attempt_resolution {
             for i in 0..games_to_check.to::<u64>() {
                 let index = oldest_game_index + U256::from(i);
@@ -712,16 +1400,21 @@ where
                     .try_resolve_games(
                         index,
                         mode,
+                        resolution_grace_secs,
+                        dry_run,
                         signer.clone(),
                         l1_rpc.clone(),
+                        tx_retry_policy,
                         l1_provider.clone(),
                         l2_provider.clone(),
                     )
                     .await
                 {
+                    resolved += 1;
                     // Use mode-specific metrics to avoid cross-contamination
                     match mode {
-                        Mode::Proposer => ProposerGauge::GamesResolved.increment(1.0),
+                        Mode::Proposer => ProposerMetrics::new(*self.address())
+                            .increment(ProposerGauge::GamesResolved, 1.0),
                         Mode::Challenger => ChallengerGauge::GamesResolved.increment(1.0),
                     }
                 }
@@ -734,6 +1427,524 @@ where
             );
         }
 
-        Ok(())
+        Ok(resolved)
+    }
+}
+
+/// Searches for the latest valid proposal with the given `strategy`.
+#[allow(clippy::too_many_arguments)]
+async fn search_latest_valid_proposal<P, L>(
+    factory: &DisputeGameFactoryInstance<P>,
+    strategy: ValidProposalSearchStrategy,
+    game_type: u32,
+    l2_provider: &L,
+    previous: Option<ValidProposalSearch>,
+    max_games_to_check: u64,
+    latest_game_index: U256,
+    anchor_l2_block_number: U256,
+) -> Result<ValidProposalSearch>
+where
+    P: Provider + Clone,
+    L: L2ProviderTrait + Send + Sync,
+{
+    match strategy {
+        ValidProposalSearchStrategy::Linear => {
+            scan_latest_valid_proposal(
+                factory,
+                game_type,
+                l2_provider,
+                previous,
+                max_games_to_check,
+                latest_game_index,
+                anchor_l2_block_number,
+            )
+            .await
+        }
+        ValidProposalSearchStrategy::Exponential => {
+            search_latest_valid_proposal_exponentially(
+                |index| find_latest_game_at(factory, game_type, index),
+                l2_provider,
+                previous,
+                max_games_to_check,
+                latest_game_index,
+                anchor_l2_block_number,
+            )
+            .await
+        }
+    }
+}
+
+/// Searches the games up to `latest_game_index` for the latest valid proposal, as described in
+/// [`FactoryTrait::get_latest_valid_proposal`]. The scan stops at the first game behind
+/// `anchor_l2_block_number`, and a previous result behind it is dropped.
+async fn scan_latest_valid_proposal<P, L>(
+    factory: &DisputeGameFactoryInstance<P>,
+    game_type: u32,
+    l2_provider: &L,
+    previous: Option<ValidProposalSearch>,
+    max_games_to_check: u64,
+    latest_game_index: U256,
+    anchor_l2_block_number: U256,
+) -> Result<ValidProposalSearch>
+where
+    P: Provider + Clone,
+    L: L2ProviderTrait + Send + Sync,
+{
+    // Games up to the latest game checked by the previous search don't need to be checked again.
+    let previously_checked_index = previous.and_then(|search| search.latest_checked_index);
+    let previous_result = previous
+        .and_then(|search| search.latest_valid_proposal)
+        .filter(|(block_number, _)| *block_number >= anchor_l2_block_number);
+    if previously_checked_index == Some(latest_game_index) {
+        return Ok(ValidProposalSearch {
+            latest_valid_proposal: previous_result,
+            latest_checked_index: Some(latest_game_index),
+        });
+    }
+
+    let mut start = latest_game_index;
+    let mut games_checked = 0;
+
+    // Page through the games of the game type in reverse order (latest to earliest) to find
+    // the most recent valid game. Each page is a single `findLatestGames` call, which returns
+    // the root claim and extra data of every game, instead of several calls per game.
+    'pages: loop {
+        let games = factory
+            .findLatestGames(game_type, start, U256::from(LATEST_GAMES_PAGE_SIZE))
+            .call()
+            .await?;
+
+        for game in &games {
+            if previously_checked_index.is_some_and(|index| game.index <= index) {
+                tracing::debug!("No valid proposal among the games created since last search");
+                return Ok(ValidProposalSearch {
+                    latest_valid_proposal: previous_result,
+                    latest_checked_index: Some(latest_game_index),
+                });
+            }
+
+            // The extra data starts with the L2 block number the game is proposing output for.
+            let block_number = game.extraData.get(..32).map(U256::from_be_slice);
+
+            // Games behind the anchor are already finalized or superseded by it, and so are all
+            // games created before them.
+            if let Some(block_number) = block_number.filter(|b| *b < anchor_l2_block_number) {
+                tracing::debug!(
+                    "Reached game {:?} at block {:?} behind the anchor",
+                    game.index,
+                    block_number
+                );
+                break 'pages;
+            }
+
+            if games_checked == max_games_to_check {
+                return Err(ValidProposalScanExhausted { max_games_to_check }.into());
+            }
+            games_checked += 1;
+
+            let Some(block_number) = block_number else {
+                tracing::debug!("Skipping game {:?} with malformed extra data", game.index);
+                continue;
+            };
+            tracing::debug!(
+                "Checking if game {:?} at block {:?} is valid",
+                game.index,
+                block_number
+            );
+
+            // Compute the actual output root at the L2 block number.
+            let output_root = l2_provider.compute_output_root_at_block(block_number).await?;
+
+            // If the output root matches the game claim, we've found the latest valid proposal.
+            if output_root == game.rootClaim {
+                tracing::info!(
+                    "Latest valid proposal at game index {:?} with l2 block number: {:?}",
+                    game.index,
+                    block_number
+                );
+                return Ok(ValidProposalSearch {
+                    latest_valid_proposal: Some((block_number, game.index)),
+                    latest_checked_index: Some(latest_game_index),
+                });
+            }
+
+            // If the output root doesn't match the game claim, we need to find earlier games.
+            tracing::info!(
+                "Output root {:?} is not same as game claim {:?}",
+                output_root,
+                game.rootClaim
+            );
+        }
+
+        // A short page means the search reached the earliest game.
+        match games.last() {
+            Some(last)
+                if games.len() as u64 == LATEST_GAMES_PAGE_SIZE && last.index > U256::ZERO =>
+            {
+                start = last.index - U256::from(1);
+            }
+            _ => break,
+        }
+    }
+
+    tracing::info!("No valid proposals found after checking all games");
+    Ok(ValidProposalSearch {
+        latest_valid_proposal: previous_result,
+        latest_checked_index: Some(latest_game_index),
+    })
+}
+
+/// Searches the games up to `latest_game_index` for the latest valid proposal with
+/// [`ValidProposalSearchStrategy::Exponential`].
+///
+/// Steps back from the latest game in doubling strides until a valid game, a game behind the
+/// anchor or a game checked by the `previous` search is found. The last valid game is then binary
+/// searched for between that game and the earliest invalid game found.
+///
+/// Games are looked up with `find_game_at`, which returns the latest game of the game type at or
+/// before an index, as [`find_latest_game_at`] does.
+async fn search_latest_valid_proposal_exponentially<F, Fut, L>(
+    find_game_at: F,
+    l2_provider: &L,
+    previous: Option<ValidProposalSearch>,
+    max_games_to_check: u64,
+    latest_game_index: U256,
+    anchor_l2_block_number: U256,
+) -> Result<ValidProposalSearch>
+where
+    F: Fn(U256) -> Fut,
+    Fut: Future<Output = Result<Option<GameSearchResult>>>,
+    L: L2ProviderTrait + Send + Sync,
+{
+    let previously_checked_index = previous.and_then(|search| search.latest_checked_index);
+    let previous_result = previous
+        .and_then(|search| search.latest_valid_proposal)
+        .filter(|(block_number, _)| *block_number >= anchor_l2_block_number);
+    if previously_checked_index == Some(latest_game_index) {
+        return Ok(ValidProposalSearch {
+            latest_valid_proposal: previous_result,
+            latest_checked_index: Some(latest_game_index),
+        });
+    }
+
+    let mut games_checked = 0;
+    // The index of a game at or after which the latest valid proposal is, and that proposal.
+    let mut lower = None;
+    // The index of the earliest game found to be invalid.
+    let mut upper = None;
+    let mut probe_index = latest_game_index;
+    let mut stride = U256::ZERO;
+    while let Some(game) = find_game_at(probe_index).await? {
+        if let Some(index) = previously_checked_index.filter(|index| game.index <= *index) {
+            lower = Some((index, previous_result));
+            break;
+        }
+
+        match check_game_validity(
+            l2_provider,
+            &game,
+            anchor_l2_block_number,
+            &mut games_checked,
+            max_games_to_check,
+        )
+        .await?
+        {
+            GameValidity::Valid(block_number) => {
+                lower = Some((game.index, Some((block_number, game.index))));
+                break;
+            }
+            GameValidity::BehindAnchor => {
+                lower = Some((game.index, None));
+                break;
+            }
+            GameValidity::Invalid => upper = Some(game.index),
+        }
+
+        if game.index == U256::ZERO {
+            break;
+        }
+        stride = (stride * U256::from(2)).max(U256::from(1));
+        probe_index = game.index.saturating_sub(stride);
+    }
+
+    // Every game stepped back to is invalid, down to the earliest game.
+    let Some((mut lo, mut latest_valid_proposal)) = lower else {
+        tracing::info!("No valid proposals found after searching all games");
+        return Ok(ValidProposalSearch {
+            latest_valid_proposal: None,
+            latest_checked_index: Some(latest_game_index),
+        });
+    };
+
+    if let Some(mut hi) = upper {
+        while hi - lo > U256::from(1) {
+            let mid = lo + (hi - lo) / U256::from(2);
+            match find_game_at(mid).await? {
+                Some(game) if game.index > lo => {
+                    match check_game_validity(
+                        l2_provider,
+                        &game,
+                        anchor_l2_block_number,
+                        &mut games_checked,
+                        max_games_to_check,
+                    )
+                    .await?
+                    {
+                        GameValidity::Valid(block_number) => {
+                            lo = game.index;
+                            latest_valid_proposal = Some((block_number, game.index));
+                        }
+                        GameValidity::BehindAnchor => lo = game.index,
+                        GameValidity::Invalid => hi = game.index,
+                    }
+                }
+                // There is no game of the game type after `lo` up to `mid`.
+                _ => lo = mid,
+            }
+        }
+    }
+
+    match latest_valid_proposal {
+        Some((block_number, game_index)) => tracing::info!(
+            "Latest valid proposal at game index {:?} with l2 block number: {:?}, found after checking {} games",
+            game_index,
+            block_number,
+            games_checked
+        ),
+        None => tracing::info!("No valid proposals found after searching all games"),
+    }
+    Ok(ValidProposalSearch { latest_valid_proposal, latest_checked_index: Some(latest_game_index) })
+}
+
+/// Orders the games matched by [`FactoryTrait::get_game_addresses`], given as their creator's
+/// priority, index, address and L2 block number. Games are ordered by priority, and games of the
+/// same priority from oldest to newest.
+fn sort_matching_games(games: &mut [(u8, U256, Address, U256)]) {
+    games.sort_by_key(|(priority, game_index, _, _)| (*priority, *game_index));
+}
+
+/// Whether a game proposes the correct output root, as checked by the latest valid proposal
+/// search.
+enum GameValidity {
+    /// The game proposes the correct output root for the contained L2 block number.
+    Valid(U256),
+    /// The game proposes an incorrect output root, or has malformed extra data.
+    Invalid,
+    /// The game is behind the anchor, so it is already finalized or superseded by it.
+    BehindAnchor,
+}
+
+/// Returns the latest game of the game type at or before `index`, if any.
+async fn find_latest_game_at<P>(
+    factory: &DisputeGameFactoryInstance<P>,
+    game_type: u32,
+    index: U256,
+) -> Result<Option<GameSearchResult>>
+where
+    P: Provider + Clone,
+{
+    let games = factory.findLatestGames(game_type, index, U256::from(1)).call().await?;
+    Ok(games.into_iter().next())
+}
+
+/// Checks whether `game` proposes the correct output root, counting it towards
+/// `max_games_to_check`.
+async fn check_game_validity<L>(
+    l2_provider: &L,
+    game: &GameSearchResult,
+    anchor_l2_block_number: U256,
+    games_checked: &mut u64,
+    max_games_to_check: u64,
+) -> Result<GameValidity>
+where
+    L: L2ProviderTrait + Send + Sync,
+{
+    if *games_checked == max_games_to_check {
+        return Err(ValidProposalScanExhausted { max_games_to_check }.into());
+    }
+    *games_checked += 1;
+
+    // The extra data starts with the L2 block number the game is proposing output for.
+    let Some(block_number) = game.extraData.get(..32).map(U256::from_be_slice) else {
+        tracing::debug!("Game {:?} has malformed extra data", game.index);
+        return Ok(GameValidity::Invalid);
+    };
+    if block_number < anchor_l2_block_number {
+        return Ok(GameValidity::BehindAnchor);
+    }
+
+    tracing::debug!("Checking if game {:?} at block {:?} is valid", game.index, block_number);
+    let output_root = l2_provider.compute_output_root_at_block(block_number).await?;
+    if output_root == game.rootClaim {
+        Ok(GameValidity::Valid(block_number))
+    } else {
+        Ok(GameValidity::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Bytes;
+    use anyhow::anyhow;
+
+    use super::*;
+
+    /// An L2 provider whose output root at a block is the block number itself.
+    struct MockL2Provider;
+
+    #[async_trait]
+    impl L2ProviderTrait for MockL2Provider {
+        async fn get_l2_block_by_number(
+            &self,
+            _block_number: BlockNumberOrTag,
+        ) -> Result<Block<Transaction>> {
+            Err(anyhow!("not used by this mock"))
+        }
+
+        async fn get_l2_storage_root(
+            &self,
+            _address: Address,
+            _block_number: BlockNumberOrTag,
+        ) -> Result<B256> {
+            Err(anyhow!("not used by this mock"))
+        }
+
+        fn l2_to_l1_message_passer(&self) -> Address {
+            L2_TO_L1_MESSAGE_PASSER
+        }
+
+        async fn compute_output_root_at_block(
+            &self,
+            l2_block_number: U256,
+        ) -> Result<FixedBytes<32>> {
+            Ok(B256::from(l2_block_number.to_be_bytes::<32>()))
+        }
+    }
+
+    /// Games at L2 blocks 100, 200, ..., of which only those in `valid` claim the correct output
+    /// root.
+    fn games(count: u64, valid: impl Fn(u64) -> bool) -> Vec<GameSearchResult> {
+        (0..count)
+            .map(|index| {
+                let block_number = U256::from((index + 1) * 100);
+                GameSearchResult {
+                    index: U256::from(index),
+                    metadata: B256::ZERO,
+                    timestamp: 0,
+                    rootClaim: if valid(index) {
+                        B256::from(block_number.to_be_bytes::<32>())
+                    } else {
+                        B256::repeat_byte(0xff)
+                    },
+                    extraData: Bytes::from(block_number.to_be_bytes::<32>().to_vec()),
+                }
+            })
+            .collect()
+    }
+
+    async fn search(
+        games: &[GameSearchResult],
+        anchor_l2_block_number: u64,
+    ) -> Result<ValidProposalSearch> {
+        let find_game_at = |index: U256| async move {
+            Ok(games.iter().rev().find(|game| game.index <= index).cloned())
+        };
+        search_latest_valid_proposal_exponentially(
+            find_game_at,
+            &MockL2Provider,
+            None,
+            u64::MAX,
+            U256::from(games.len() - 1),
+            U256::from(anchor_l2_block_number),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_exponential_search_finds_latest_valid_game() {
+        for latest_valid in 0..20 {
+            let result = search(&games(20, |index| index <= latest_valid), 0).await.unwrap();
+            assert_eq!(
+                result.latest_valid_proposal,
+                Some((U256::from((latest_valid + 1) * 100), U256::from(latest_valid)))
+            );
+            assert_eq!(result.latest_checked_index, Some(U256::from(19)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exponential_search_with_anchor_in_the_middle() {
+        // The anchor is at game 9, and the games after it up to game 13 are valid.
+        let result = search(&games(20, |index| index <= 13), 1000).await.unwrap();
+        assert_eq!(result.latest_valid_proposal, Some((U256::from(1400), U256::from(13))));
+
+        // Every game after the anchor is invalid, and the valid games behind it don't count.
+        let result = search(&games(20, |index| index < 9), 1000).await.unwrap();
+        assert_eq!(result.latest_valid_proposal, None);
+        assert_eq!(result.latest_checked_index, Some(U256::from(19)));
+    }
+
+    #[tokio::test]
+    async fn test_exponential_search_with_all_games_invalid() {
+        let result = search(&games(20, |_| false), 0).await.unwrap();
+        assert_eq!(result.latest_valid_proposal, None);
+        assert_eq!(result.latest_checked_index, Some(U256::from(19)));
+    }
+
+    #[tokio::test]
+    async fn test_exponential_search_with_single_game() {
+        let result = search(&games(1, |_| true), 0).await.unwrap();
+        assert_eq!(result.latest_valid_proposal, Some((U256::from(100), U256::ZERO)));
+
+        let result = search(&games(1, |_| false), 0).await.unwrap();
+        assert_eq!(result.latest_valid_proposal, None);
+        assert_eq!(result.latest_checked_index, Some(U256::ZERO));
+    }
+
+    fn priority(prioritized: &[u8], ignored: &[u8]) -> ProposerPriority {
+        ProposerPriority {
+            prioritized: prioritized.iter().map(|byte| Address::repeat_byte(*byte)).collect(),
+            ignored: ignored.iter().map(|byte| Address::repeat_byte(*byte)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_proposer_priority_of() {
+        let priority = priority(&[1], &[2]);
+        assert_eq!(priority.of(Address::repeat_byte(1)), Some(0));
+        assert_eq!(priority.of(Address::repeat_byte(2)), None);
+        assert_eq!(priority.of(Address::repeat_byte(3)), Some(1));
+
+        // Without any configured proposers, every proposer ties.
+        let priority = ProposerPriority::default();
+        assert_eq!(priority.of(Address::repeat_byte(1)), priority.of(Address::repeat_byte(2)));
+    }
+
+    #[test]
+    fn test_ignored_takes_precedence_over_prioritized() {
+        assert_eq!(priority(&[1], &[1]).of(Address::repeat_byte(1)), None);
+    }
+
+    #[test]
+    fn test_sort_matching_games_breaks_ties_by_age() {
+        let priority = priority(&[1], &[]);
+        // Games as checked concurrently, in completion order.
+        let mut games: Vec<_> = [(7u64, 3), (2, 1), (5, 3), (9, 1), (4, 2), (6, 2)]
+            .into_iter()
+            .map(|(index, creator)| {
+                (
+                    priority.of(Address::repeat_byte(creator)).unwrap(),
+                    U256::from(index),
+                    Address::repeat_byte(creator),
+                    U256::from(index * 100),
+                )
+            })
+            .collect();
+        sort_matching_games(&mut games);
+
+        let order: Vec<_> = games.iter().map(|(_, index, _, _)| index.to::<u64>()).collect();
+        // The prioritized proposer's games come first, and the games of the other proposers,
+        // which tie, from oldest to newest regardless of their creator.
+        assert_eq!(order, vec![2, 9, 4, 5, 6, 7]);
     }
 }
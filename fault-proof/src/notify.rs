@@ -0,0 +1,71 @@
+use std::{collections::HashSet, time::Duration};
+
+use alloy_transport_http::reqwest::{header::CONTENT_TYPE, Client, Url};
+use serde_json::json;
+use strum_macros::{Display, EnumIter, EnumString};
+use tokio::sync::mpsc;
+
+/// The number of notifications buffered before new notifications are dropped.
+const NOTIFICATION_CHANNEL_CAPACITY: usize = 256;
+/// The maximum time to wait for the webhook to accept a notification.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An event operators can be notified of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationEvent {
+    GameCreated,
+    GameChallenged,
+    GameProven,
+    GameResolved,
+    BondClaimFailed,
+}
+
+/// Posts notifications of the configured events to a Slack or Discord compatible webhook.
+///
+/// Notifications are handed to a background task that posts them, so that notifying never
+/// blocks, and a failing webhook is only logged.
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    sender: mpsc::Sender<(NotificationEvent, String)>,
+    events: HashSet<NotificationEvent>,
+}
+
+impl WebhookNotifier {
+    /// Spawns the background task posting the notifications of `events` to `webhook_url`.
+    pub fn spawn(webhook_url: Url, events: HashSet<NotificationEvent>) -> Self {
+        let client = Client::new();
+        let (sender, mut receiver) =
+            mpsc::channel::<(NotificationEvent, String)>(NOTIFICATION_CHANNEL_CAPACITY);
+        tokio::spawn(async move {
+            while let Some((event, message)) = receiver.recv().await {
+                // Slack reads the `text` field and Discord the `content` field.
+                let payload =
+                    json!({ "event": event.to_string(), "text": message, "content": message });
+                let result = client
+                    .post(webhook_url.clone())
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(payload.to_string())
+                    .timeout(WEBHOOK_TIMEOUT)
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status());
+                if let Err(e) = result {
+                    tracing::warn!("Failed to send {} notification: {:?}", event, e);
+                }
+            }
+        });
+
+        Self { sender, events }
+    }
+
+    /// Sends a notification of `event`, if it is one of the configured events.
+    pub fn notify(&self, event: NotificationEvent, message: String) {
+        if !self.events.contains(&event) {
+            return;
+        }
+        if let Err(e) = self.sender.try_send((event, message)) {
+            tracing::warn!("Failed to queue {} notification: {:?}", event, e);
+        }
+    }
+}
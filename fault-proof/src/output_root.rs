@@ -0,0 +1,93 @@
+use alloy_primitives::{keccak256, B256};
+use alloy_sol_types::SolValue;
+
+use crate::contract::L2Output;
+
+/// Computes L2 output roots from their components, independently of any provider.
+pub struct L2OutputRootHasher;
+
+impl L2OutputRootHasher {
+    /// Hashes a version 0 output root, as defined by the OP Stack:
+    /// `keccak256(abi.encode(0, state_root, storage_hash, block_hash))`, where `storage_hash` is
+    /// the storage root of the `L2ToL1MessagePasser` contract.
+    pub fn hash(state_root: B256, storage_hash: B256, block_hash: B256) -> B256 {
+        let output = L2Output {
+            zero: 0,
+            l2_state_root: state_root,
+            l2_storage_hash: storage_hash,
+            l2_claim_hash: block_hash,
+        };
+        keccak256(output.abi_encode())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{b256, U256};
+
+    use super::*;
+
+    #[test]
+    fn test_hash_known_value() {
+        let output_root = L2OutputRootHasher::hash(
+            B256::repeat_byte(0x11),
+            B256::repeat_byte(0x22),
+            B256::repeat_byte(0x33),
+        );
+        assert_eq!(
+            output_root,
+            b256!("0xd50bf2ff34ced71be0d2f0be7c2433c6b39d9c3b16c95daf1ed6f24b7578a3b2")
+        );
+    }
+
+    #[test]
+    fn test_hash_zero_inputs() {
+        // keccak256 of four zero words.
+        let output_root = L2OutputRootHasher::hash(B256::ZERO, B256::ZERO, B256::ZERO);
+        assert_eq!(
+            output_root,
+            b256!("0x012893657d8eb2efad4de0a91bcd0e39ad9837745dec3ea923737ea803fc8e3d")
+        );
+    }
+
+    #[test]
+    fn test_hash_all_ones_inputs() {
+        let all_ones = B256::repeat_byte(0xff);
+        let output_root = L2OutputRootHasher::hash(all_ones, all_ones, all_ones);
+        assert_eq!(
+            output_root,
+            b256!("0x1a22f83050264b80028d0103004d7cd3764352619db10aac31cdff7170287c1e")
+        );
+    }
+
+    #[test]
+    fn test_hash_matches_abi_encoding() {
+        let (state_root, storage_hash, block_hash) =
+            (B256::repeat_byte(0x01), B256::repeat_byte(0x02), B256::repeat_byte(0x03));
+        let encoded = (U256::ZERO, state_root, storage_hash, block_hash).abi_encode();
+        assert_eq!(encoded.len(), 128);
+        assert_eq!(
+            L2OutputRootHasher::hash(state_root, storage_hash, block_hash),
+            keccak256(encoded)
+        );
+    }
+
+    #[test]
+    fn test_hash_changes_with_each_field() {
+        let base = [B256::repeat_byte(0x11), B256::repeat_byte(0x22), B256::repeat_byte(0x33)];
+        let base_root = L2OutputRootHasher::hash(base[0], base[1], base[2]);
+
+        for field in 0..3 {
+            let mut fields = base;
+            fields[field].0[31] ^= 1;
+            let root = L2OutputRootHasher::hash(fields[0], fields[1], fields[2]);
+            assert_ne!(root, base_root, "changing field {field} did not change the output root");
+        }
+    }
+
+    #[test]
+    fn test_hash_is_order_sensitive() {
+        let (a, b, c) = (B256::repeat_byte(0x11), B256::repeat_byte(0x22), B256::repeat_byte(0x33));
+        assert_ne!(L2OutputRootHasher::hash(a, b, c), L2OutputRootHasher::hash(c, b, a));
+    }
+}
@@ -1,7 +1,17 @@
-use op_succinct_host_utils::metrics::MetricsGauge;
-use strum::EnumMessage;
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
+
+use alloy_primitives::Address;
+use metrics::{describe_gauge, gauge, histogram, Label};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use op_succinct_host_utils::metrics::{MetricsGauge, MetricsHistogram};
+use strum::{EnumMessage, IntoEnumIterator};
 use strum_macros::{Display, EnumIter};
 
+use crate::Mode;
+
 // Define an enum for all fault proof proposer metrics gauges.
 #[derive(Debug, Clone, Copy, Display, EnumIter, EnumMessage)]
 pub enum ProposerGauge {
@@ -36,7 +46,162 @@ pub enum ProposerGauge {
         message = "Total number of games that bonds were claimed by the proposer"
     )]
     GamesBondsClaimed,
+    #[strum(
+        serialize = "op_succinct_fp_fast_finality_success_rate_pct",
+        message = "Percentage of games created in fast finality mode that were proved before being challenged"
+    )]
+    FastFinalitySuccessRatePct,
+    #[strum(
+        serialize = "op_succinct_fp_race_conditions_detected",
+        message = "Total number of times a game for the next block was already created by the proposer"
+    )]
+    RaceConditionsDetected,
+    #[strum(
+        serialize = "op_succinct_fp_skipped_for_finality_count",
+        message = "Total number of ticks without game creation because the finalized L2 head has not reached the next proposal block"
+    )]
+    SkippedForFinalityCount,
+    #[strum(
+        serialize = "op_succinct_fp_skipped_for_chain_id_mismatch_count",
+        message = "Total number of ticks without game creation because of a chain ID mismatch"
+    )]
+    SkippedForChainIdMismatchCount,
+    #[strum(
+        serialize = "op_succinct_fp_skipped_for_duplicate_proposal_count",
+        message = "Total number of ticks without game creation because a game for the next proposal block already exists"
+    )]
+    SkippedForDuplicateProposalCount,
+    #[strum(
+        serialize = "op_succinct_fp_skipped_for_creation_backoff_count",
+        message = "Total number of ticks without game creation because creating a game for the next proposal block failed recently"
+    )]
+    SkippedForCreationBackoffCount,
+    #[strum(
+        serialize = "op_succinct_fp_skipped_for_not_whitelisted_count",
+        message = "Total number of ticks without game creation because the access manager doesn't allow the signer to propose"
+    )]
+    SkippedForNotWhitelistedCount,
+    #[strum(
+        serialize = "op_succinct_fp_skipped_for_rate_limit_count",
+        message = "Total number of ticks without game creation because the minimum proposal interval has not passed"
+    )]
+    SkippedForRateLimitCount,
+    #[strum(
+        serialize = "op_succinct_fp_missed_proposal_opportunities",
+        message = "Total number of ticks without game creation for a reason other than finality not advancing"
+    )]
+    MissedProposalOpportunities,
+    #[strum(
+        serialize = "op_succinct_fp_l1_halt_detected",
+        message = "Total number of ticks skipped because the L1 chain stopped producing blocks"
+    )]
+    L1HaltDetected,
+    #[strum(
+        serialize = "op_succinct_fp_max_agg_proof_size_bytes",
+        message = "Maximum size in bytes of a submitted aggregation proof"
+    )]
+    MaxAggProofSizeBytes,
+    #[strum(
+        serialize = "op_succinct_fp_total_net_position_wei",
+        message = "Signer balance plus unclaimed credit minus bonds in active proposals, in wei"
+    )]
+    TotalNetPositionWei,
+    #[strum(
+        serialize = "op_succinct_fp_spurious_defender_wins",
+        message = "Total number of games resolved as DEFENDER_WINS with an incorrect root claim"
+    )]
+    SpuriousDefenderWins,
+    #[strum(
+        serialize = "op_succinct_fp_last_witness_gen_fetch_secs",
+        message = "Time spent fetching host arguments for the last proven game"
+    )]
+    LastWitnessGenFetchSecs,
+    #[strum(
+        serialize = "op_succinct_fp_last_witness_gen_run_secs",
+        message = "Time spent running the host for the last proven game"
+    )]
+    LastWitnessGenRunSecs,
+    #[strum(
+        serialize = "op_succinct_fp_proof_range_latency_secs",
+        message = "Time spent generating the range proof of the last proven game, labeled by mock mode"
+    )]
+    ProofRangeLatencySecs,
+    #[strum(
+        serialize = "op_succinct_fp_proof_agg_latency_secs",
+        message = "Time spent generating the aggregation proof of the last proven game, labeled by mock mode"
+    )]
+    ProofAggLatencySecs,
+    #[strum(
+        serialize = "op_succinct_fp_total_bonds_locked_wei",
+        message = "Total proposer and challenger bonds locked in in-progress games, in wei"
+    )]
+    TotalBondsLockedWei,
+    #[strum(
+        serialize = "op_succinct_fp_bonds_locked_wei",
+        message = "Bonds posted by the proposer that are locked in in-progress games, in wei"
+    )]
+    BondsLockedWei,
+    #[strum(
+        serialize = "op_succinct_fp_output_root_cache_hits",
+        message = "Total number of output roots served from the output root cache"
+    )]
+    OutputRootCacheHits,
+    #[strum(
+        serialize = "op_succinct_fp_output_root_cache_misses",
+        message = "Total number of output roots computed because they were not in the output root cache"
+    )]
+    OutputRootCacheMisses,
+    #[strum(
+        serialize = "op_succinct_fp_wallet_balance_wei",
+        message = "ETH balance of the proposer signer, in wei"
+    )]
+    WalletBalanceWei,
+    #[strum(
+        serialize = "op_succinct_fp_low_balance",
+        message = "Total number of metrics updates that found the proposer signer balance below the minimum"
+    )]
+    LowBalance,
+    #[strum(
+        serialize = "op_succinct_fp_reorg_detected",
+        message = "Total number of game creations aborted because the proposed L2 block was reorged"
+    )]
+    ReorgDetected,
+    #[strum(
+        serialize = "op_succinct_fp_l1_head_reorged",
+        message = "Total number of proof generations skipped because the L1 head of the game was reorged"
+    )]
+    L1HeadReorged,
+    #[strum(
+        serialize = "op_succinct_fp_total_gas_spent_wei",
+        message = "Total L1 gas cost of proposer transactions, in wei, labeled by operation"
+    )]
+    TotalGasSpentWei,
+    #[strum(
+        serialize = "op_succinct_fp_circuit_breaker_tripped",
+        message = "Whether game creation is paused after too many consecutive failures (1) or not (0)"
+    )]
+    CircuitBreakerTripped,
+    #[strum(
+        serialize = "op_succinct_fp_seconds_until_deadline",
+        message = "Seconds until the prove deadline of the challenged game closest to it, or -1 if there is none"
+    )]
+    SecondsUntilDeadline,
+    #[strum(
+        serialize = "op_succinct_fp_proposals_by_status",
+        message = "Number of recent games in each proposal status, labeled by status"
+    )]
+    ProposalsByStatus,
+    #[strum(
+        serialize = "op_succinct_fp_proposer_not_whitelisted",
+        message = "Whether the access manager doesn't allow the signer to propose (1) or does (0)"
+    )]
+    ProposerNotWhitelisted,
     // Error metrics
+    #[strum(
+        serialize = "op_succinct_fp_proof_timeout",
+        message = "Total number of range or aggregation proofs that timed out"
+    )]
+    ProofTimeout,
     #[strum(
         serialize = "op_succinct_fp_game_creation_error",
         message = "Total number of game creation errors encountered by the proposer"
@@ -57,6 +222,11 @@ pub enum ProposerGauge {
         message = "Total number of bond claiming errors encountered by the proposer"
     )]
     BondClaimingError,
+    #[strum(
+        serialize = "op_succinct_fp_chain_id_mismatch",
+        message = "Total number of times the L1 or L2 RPC reported an unexpected chain ID"
+    )]
+    ChainIdMismatch,
     #[strum(
         serialize = "op_succinct_fp_metrics_error",
         message = "Total number of metrics errors encountered by the proposer"
@@ -66,6 +236,73 @@ pub enum ProposerGauge {
 
 impl MetricsGauge for ProposerGauge {}
 
+/// Bucket upper bounds for L2 node call latencies, in seconds.
+const L2_LATENCY_SECS_BUCKETS: &[f64] =
+    &[0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Bucket upper bounds for aggregation proof sizes, in bytes.
+const PROOF_SIZE_BYTES_BUCKETS: &[f64] =
+    &[128.0, 256.0, 512.0, 1024.0, 2048.0, 4096.0, 8192.0, 16384.0, 32768.0];
+
+/// Bucket upper bounds for L1 transaction confirmation times, in seconds.
+const TX_CONFIRMATION_SECS_BUCKETS: &[f64] =
+    &[2.0, 6.0, 12.0, 24.0, 36.0, 60.0, 120.0, 300.0, 600.0, 1800.0];
+
+/// Bucket upper bounds for the number of games in a resolution batch.
+const BATCH_SIZE_BUCKETS: &[f64] = &[1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0];
+
+/// Bucket upper bounds for L1 gas prices, in gwei.
+const GAS_PRICE_GWEI_BUCKETS: &[f64] =
+    &[0.1, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0];
+
+// Define an enum for all fault proof proposer metrics histograms.
+#[derive(Debug, Clone, Copy, Display, EnumIter, EnumMessage)]
+pub enum ProposerHistogram {
+    #[strum(
+        serialize = "op_succinct_fp_l1_gas_price_paid_gwei",
+        message = "Effective gas price paid for game creation and proof transactions, in gwei"
+    )]
+    L1GasPricePaidGwei,
+    #[strum(
+        serialize = "op_succinct_fp_output_root_computation_time_secs",
+        message = "Time spent computing output roots from the L2 node"
+    )]
+    OutputRootComputationTimeSecs,
+    #[strum(
+        serialize = "op_succinct_fp_aggregation_proof_size_bytes",
+        message = "Size in bytes of submitted aggregation proofs"
+    )]
+    AggregationProofSizeBytes,
+    #[strum(
+        serialize = "op_succinct_fp_l2_storage_root_fetch_latency_secs",
+        message = "Time spent fetching L2 storage roots with eth_getProof"
+    )]
+    L2StorageRootFetchLatencySecs,
+    #[strum(
+        serialize = "op_succinct_fp_tx_confirmation_secs",
+        message = "Time from sending a transaction to its confirmation, labeled by operation"
+    )]
+    TxConfirmationSecs,
+    #[strum(
+        serialize = "op_succinct_fp_sequential_resolution_batch_size",
+        message = "Number of sequential games in each resolution batch"
+    )]
+    SequentialResolutionBatchSize,
+}
+
+impl MetricsHistogram for ProposerHistogram {
+    fn buckets(&self) -> &'static [f64] {
+        match self {
+            Self::L1GasPricePaidGwei => GAS_PRICE_GWEI_BUCKETS,
+            Self::OutputRootComputationTimeSecs => L2_LATENCY_SECS_BUCKETS,
+            Self::AggregationProofSizeBytes => PROOF_SIZE_BYTES_BUCKETS,
+            Self::L2StorageRootFetchLatencySecs => L2_LATENCY_SECS_BUCKETS,
+            Self::TxConfirmationSecs => TX_CONFIRMATION_SECS_BUCKETS,
+            Self::SequentialResolutionBatchSize => BATCH_SIZE_BUCKETS,
+        }
+    }
+}
+
 // Define an enum for all fault proof challenger metrics gauges.
 #[derive(Debug, Clone, Copy, Display, EnumIter, EnumMessage)]
 pub enum ChallengerGauge {
@@ -85,6 +322,86 @@ pub enum ChallengerGauge {
         message = "Total number of games that bonds were claimed by the challenger"
     )]
     GamesBondsClaimed,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_reorg_affected_games",
+        message = "Total number of in-progress games whose L1 head was affected by an L1 reorg"
+    )]
+    ReorgAffectedGames,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_whitelisted_proposer_count",
+        message = "Number of proposers currently whitelisted in the access manager"
+    )]
+    WhitelistedProposerCount,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_total_bonds_locked_wei",
+        message = "Total proposer and challenger bonds locked in in-progress games, in wei"
+    )]
+    TotalBondsLockedWei,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_bonds_locked_wei",
+        message = "Bonds posted by the challenger that are locked in in-progress games, in wei"
+    )]
+    BondsLockedWei,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_avg_first_response_time_secs",
+        message = "Moving average of the time from a game first appearing to its first challenge check"
+    )]
+    AvgFirstResponseTimeSecs,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_max_first_response_time_secs",
+        message = "Maximum time from a game first appearing to its first challenge check"
+    )]
+    MaxFirstResponseTimeSecs,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_output_root_cache_hits",
+        message = "Total number of output roots served from the output root cache"
+    )]
+    OutputRootCacheHits,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_output_root_cache_misses",
+        message = "Total number of output roots computed because they were not in the output root cache"
+    )]
+    OutputRootCacheMisses,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_sweep_resolutions",
+        message = "Total number of expired challenged games resolved by the periodic sweep"
+    )]
+    SweepResolutions,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_rpc_disagreements_detected",
+        message = "Total number of games the primary and secondary L2 RPCs disagreed on the validity of"
+    )]
+    RpcDisagreementsDetected,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_suspicious_proposer_count",
+        message = "Number of proposers with a majority of invalid games in the challenge window"
+    )]
+    SuspiciousProposerCount,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_spurious_challenger_wins",
+        message = "Total number of games resolved as CHALLENGER_WINS with a correct root claim"
+    )]
+    SpuriousChallengerWins,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_wallet_balance_wei",
+        message = "ETH balance of the challenger signer, in wei"
+    )]
+    WalletBalanceWei,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_low_balance",
+        message = "Total number of metrics updates that found the challenger signer balance below the minimum"
+    )]
+    LowBalance,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_total_gas_spent_wei",
+        message = "Total L1 gas cost of challenger transactions, in wei, labeled by operation"
+    )]
+    TotalGasSpentWei,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_seconds_until_deadline",
+        message = "Seconds until the challenge deadline of the oldest challengeable game, or -1 if there is none"
+    )]
+    SecondsUntilDeadline,
     // Error metrics
     #[strum(
         serialize = "op_succinct_fp_challenger_game_challenging_error",
@@ -104,3 +421,309 @@ pub enum ChallengerGauge {
 }
 
 impl MetricsGauge for ChallengerGauge {}
+
+// Define an enum for all fault proof challenger metrics histograms.
+#[derive(Debug, Clone, Copy, Display, EnumIter, EnumMessage)]
+pub enum ChallengerHistogram {
+    #[strum(
+        serialize = "op_succinct_fp_challenger_l1_gas_price_paid_gwei",
+        message = "Effective gas price paid for challenge transactions, in gwei"
+    )]
+    L1GasPricePaidGwei,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_output_root_computation_time_secs",
+        message = "Time spent computing output roots from the L2 node"
+    )]
+    OutputRootComputationTimeSecs,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_l2_storage_root_fetch_latency_secs",
+        message = "Time spent fetching L2 storage roots with eth_getProof"
+    )]
+    L2StorageRootFetchLatencySecs,
+    #[strum(
+        serialize = "op_succinct_fp_challenger_tx_confirmation_secs",
+        message = "Time from sending a transaction to its confirmation, labeled by operation"
+    )]
+    TxConfirmationSecs,
+}
+
+impl MetricsHistogram for ChallengerHistogram {
+    fn buckets(&self) -> &'static [f64] {
+        match self {
+            Self::L1GasPricePaidGwei => GAS_PRICE_GWEI_BUCKETS,
+            Self::OutputRootComputationTimeSecs => L2_LATENCY_SECS_BUCKETS,
+            Self::L2StorageRootFetchLatencySecs => L2_LATENCY_SECS_BUCKETS,
+            Self::TxConfirmationSecs => TX_CONFIRMATION_SECS_BUCKETS,
+        }
+    }
+}
+
+/// The operation an L1 transaction was sent for, exported as the `operation` label of the
+/// `TotalGasSpentWei` gauges.
+#[derive(Debug, Clone, Copy, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum TxOperation {
+    Create,
+    Prove,
+    Challenge,
+    Resolve,
+    ClaimBond,
+    ForwardBond,
+}
+
+/// Adds the L1 gas cost of a transaction to `gauge`, labeled with the operation it was sent for.
+pub fn record_gas_spent_wei(gauge: impl Display, operation: TxOperation, cost_wei: f64) {
+    gauge!(gauge.to_string(), "operation" => operation.to_string()).increment(cost_wei);
+}
+
+/// Records how long a transaction sent for `operation` took to confirm. In proposer mode, it is
+/// labeled with the `factory` the transaction was sent for.
+pub fn record_tx_confirmation_secs(
+    mode: Mode,
+    factory: Address,
+    operation: TxOperation,
+    confirmation_secs: f64,
+) {
+    match mode {
+        Mode::Proposer => {
+            ProposerMetrics::new(factory).record_tx_confirmation_secs(operation, confirmation_secs)
+        }
+        Mode::Challenger => histogram!(
+            ChallengerHistogram::TxConfirmationSecs.to_string(),
+            "operation" => operation.to_string()
+        )
+        .record(confirmation_secs),
+    }
+}
+
+/// The label the proposer metrics carry with the address of the factory they were recorded for.
+const FACTORY_LABEL: &str = "factory";
+
+/// Records the metrics of a proposer instance, labeled with the factory it proposes to.
+///
+/// A single process can propose to several factories, so every proposer gauge and histogram is
+/// exported with the factory address as the `factory` label to keep the instances apart.
+#[derive(Debug, Clone)]
+pub struct ProposerMetrics {
+    factory: String,
+}
+
+impl ProposerMetrics {
+    pub fn new(factory: Address) -> Self {
+        Self { factory: factory.to_string() }
+    }
+
+    /// Sets the value of `gauge`.
+    pub fn set(&self, gauge: ProposerGauge, value: f64) {
+        gauge!(gauge.to_string(), FACTORY_LABEL => self.factory.clone()).set(value);
+    }
+
+    /// Increments the value of `gauge`.
+    pub fn increment(&self, gauge: ProposerGauge, value: f64) {
+        gauge!(gauge.to_string(), FACTORY_LABEL => self.factory.clone()).increment(value);
+    }
+
+    /// Records an observation of `histogram`.
+    pub fn record(&self, histogram: ProposerHistogram, value: f64) {
+        histogram!(histogram.to_string(), FACTORY_LABEL => self.factory.clone()).record(value);
+    }
+
+    /// Initializes all gauges to 0.0.
+    pub fn init_all(&self) {
+        for gauge in ProposerGauge::iter() {
+            self.set(gauge, 0.0);
+        }
+    }
+
+    /// Adds the L1 gas cost of a transaction to the total gas spent, labeled with the operation it
+    /// was sent for.
+    pub fn record_gas_spent_wei(&self, operation: TxOperation, cost_wei: f64) {
+        gauge!(
+            ProposerGauge::TotalGasSpentWei.to_string(),
+            FACTORY_LABEL => self.factory.clone(),
+            "operation" => operation.to_string()
+        )
+        .increment(cost_wei);
+    }
+
+    /// Records how long a transaction sent for `operation` took to confirm.
+    pub fn record_tx_confirmation_secs(&self, operation: TxOperation, confirmation_secs: f64) {
+        histogram!(
+            ProposerHistogram::TxConfirmationSecs.to_string(),
+            FACTORY_LABEL => self.factory.clone(),
+            "operation" => operation.to_string()
+        )
+        .record(confirmation_secs);
+    }
+
+    /// Sets the number of recent games in the proposal `status`.
+    pub fn record_proposals_by_status(&self, status: impl Debug, count: u64) {
+        gauge!(
+            ProposerGauge::ProposalsByStatus.to_string(),
+            FACTORY_LABEL => self.factory.clone(),
+            "status" => format!("{status:?}")
+        )
+        .set(count as f64);
+    }
+
+    /// Sets a proof generation latency `gauge`, labeled with whether the proof was a mock proof.
+    pub fn record_proof_latency_secs(
+        &self,
+        gauge: ProposerGauge,
+        mock_mode: bool,
+        latency_secs: f64,
+    ) {
+        gauge!(
+            gauge.to_string(),
+            FACTORY_LABEL => self.factory.clone(),
+            "mock" => mock_mode.to_string()
+        )
+        .set(latency_secs);
+    }
+}
+
+/// Seconds remaining until the deadline of an in-progress game.
+pub const PROPOSAL_TIME_REMAINING_SECS: &str = "op_succinct_fp_proposal_time_remaining_secs";
+/// The `ProposalStatus` of an in-progress game, as its numeric discriminant.
+pub const PROPOSAL_STATUS: &str = "op_succinct_fp_proposal_status";
+/// The bonds (in wei) held by an in-progress game.
+pub const PROPOSAL_BOND_AT_RISK: &str = "op_succinct_fp_proposal_bond_at_risk";
+
+/// Per-proposal metrics for a single in-progress game.
+///
+/// Unlike the aggregate gauges above, these are exported with the game index as the
+/// `proposal_id` label so that individual high-visibility proposals can be monitored.
+#[derive(Debug, Clone, Default)]
+pub struct ProposalMetrics {
+    pub labels: HashMap<&'static str, String>,
+    pub gauges: HashMap<&'static str, f64>,
+}
+
+impl ProposalMetrics {
+    pub fn new(game_index: impl ToString) -> Self {
+        let mut labels = HashMap::new();
+        labels.insert("proposal_id", game_index.to_string());
+        Self { labels, gauges: HashMap::new() }
+    }
+
+    /// Adds a label to the per-proposal gauges.
+    pub fn with_label(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.labels.insert(key, value.to_string());
+        self
+    }
+
+    /// Sets the value of a per-proposal gauge.
+    pub fn with_gauge(mut self, name: &'static str, value: f64) -> Self {
+        self.gauges.insert(name, value);
+        self
+    }
+
+    fn metric_labels(&self) -> Vec<Label> {
+        self.labels.iter().map(|(key, value)| Label::new(*key, value.clone())).collect()
+    }
+
+    fn export(&self) {
+        for (name, value) in &self.gauges {
+            gauge!(*name, self.metric_labels()).set(*value);
+        }
+    }
+}
+
+/// Maintains the set of per-proposal metrics currently being exported, by factory.
+///
+/// The global Prometheus exporter keeps every series it has seen, so the per-proposal gauges are
+/// kept in a recorder of their own instead, which is replaced on every update. Series of games
+/// that are no longer in progress are thereby removed rather than left at their last value.
+pub struct ProposalMetricsRegistry {
+    proposals: HashMap<Address, Vec<ProposalMetrics>>,
+    handle: PrometheusHandle,
+}
+
+impl Default for ProposalMetricsRegistry {
+    fn default() -> Self {
+        Self {
+            proposals: HashMap::new(),
+            handle: PrometheusBuilder::new().build_recorder().handle(),
+        }
+    }
+}
+
+impl ProposalMetricsRegistry {
+    /// Describe the per-proposal gauges.
+    fn register_all() {
+        describe_gauge!(PROPOSAL_TIME_REMAINING_SECS, "Seconds until the proposal deadline");
+        describe_gauge!(PROPOSAL_STATUS, "Status of the proposal");
+        describe_gauge!(PROPOSAL_BOND_AT_RISK, "Bonds in wei held by the proposal");
+    }
+
+    /// Replaces the exported per-proposal metrics of `factory` with the given ones.
+    pub fn update(&mut self, factory: Address, proposals: Vec<ProposalMetrics>) {
+        self.proposals.insert(factory, proposals);
+
+        let recorder = PrometheusBuilder::new().build_recorder();
+        metrics::with_local_recorder(&recorder, || {
+            Self::register_all();
+            for proposal in self.proposals.values().flatten() {
+                proposal.export();
+            }
+        });
+        self.handle = recorder.handle();
+    }
+
+    /// Renders the per-proposal metrics in the Prometheus text format.
+    pub fn render(&self) -> String {
+        self.handle.render()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proposal(game_index: u64) -> ProposalMetrics {
+        ProposalMetrics::new(game_index).with_gauge(PROPOSAL_STATUS, 1.0)
+    }
+
+    #[test]
+    fn test_update_removes_pruned_proposals() {
+        let factory = Address::repeat_byte(0x11);
+        let mut registry = ProposalMetricsRegistry::default();
+
+        registry.update(factory, vec![proposal(1), proposal(2)]);
+        let rendered = registry.render();
+        assert!(rendered.contains("proposal_id=\"1\""));
+        assert!(rendered.contains("proposal_id=\"2\""));
+
+        registry.update(factory, vec![proposal(2)]);
+        let rendered = registry.render();
+        assert!(!rendered.contains("proposal_id=\"1\""));
+        assert!(rendered.contains("proposal_id=\"2\""));
+    }
+
+    #[test]
+    fn test_update_keeps_other_factories() {
+        let mut registry = ProposalMetricsRegistry::default();
+
+        registry.update(Address::repeat_byte(0x11), vec![proposal(1)]);
+        registry.update(Address::repeat_byte(0x22), vec![proposal(2)]);
+        let rendered = registry.render();
+        assert!(rendered.contains("proposal_id=\"1\""));
+        assert!(rendered.contains("proposal_id=\"2\""));
+    }
+
+    #[test]
+    fn test_proposer_metrics_are_labeled_by_factory() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        metrics::with_local_recorder(&recorder, || {
+            ProposerMetrics::new(Address::repeat_byte(0x11))
+                .increment(ProposerGauge::GamesCreated, 1.0);
+            ProposerMetrics::new(Address::repeat_byte(0x22))
+                .increment(ProposerGauge::GamesCreated, 2.0);
+        });
+
+        let rendered = recorder.handle().render();
+        let factory = |byte: u8| format!("factory=\"{}\"", Address::repeat_byte(byte));
+        assert!(rendered.lines().any(|line| line.contains(&factory(0x11)) && line.ends_with(" 1")));
+        assert!(rendered.lines().any(|line| line.contains(&factory(0x22)) && line.ends_with(" 2")));
+    }
+}
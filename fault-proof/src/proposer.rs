@@ -1,41 +1,98 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     env,
+    future::Future,
+    str::FromStr,
     sync::{
-        atomic::{AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
-use alloy_primitives::{Address, TxHash, U256};
-use alloy_provider::{Provider, ProviderBuilder};
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{Address, Bytes, TxHash, B256, I256, U256};
+use alloy_provider::Provider;
+use alloy_rpc_types_eth::{Filter, Log, TransactionReceipt, TransactionRequest};
 use alloy_sol_types::{SolEvent, SolValue};
-use anyhow::{Context, Result};
-use op_succinct_client_utils::boot::BootInfoStruct;
+use anyhow::{bail, Context, Result};
+use op_succinct_client_utils::{boot::BootInfoStruct, types::u32_to_u8};
 use op_succinct_elfs::AGGREGATION_ELF;
 use op_succinct_host_utils::{
     fetcher::OPSuccinctDataFetcher, get_agg_proof_stdin, host::OPSuccinctHost,
-    metrics::MetricsGauge, witness_generation::WitnessGenerator,
+    witness_generation::WitnessGenerator,
 };
 use op_succinct_proof_utils::get_range_elf_embedded;
 use op_succinct_signer_utils::Signer;
+use serde::Serialize;
 use sp1_sdk::{
-    network::FulfillmentStrategy, NetworkProver, Prover, ProverClient, SP1ProofMode,
-    SP1ProofWithPublicValues, SP1ProvingKey, SP1VerifyingKey, SP1_CIRCUIT_VERSION,
+    HashableKey, NetworkProver, Prover, ProverClient, SP1ProofMode, SP1ProofWithPublicValues,
+    SP1ProvingKey, SP1VerifyingKey, SP1_CIRCUIT_VERSION,
 };
 use tokio::{sync::Mutex, time};
+use tokio_util::sync::CancellationToken;
 
 use crate::{
-    config::ProposerConfig,
+    audit::{AuditAction, AuditEntry, ProposalAuditTrail},
+    cache::CachingL2Provider,
+    config::{OutputRootVerificationMode, ProposerConfig},
     contract::{
+        AccessManager,
         DisputeGameFactory::{DisputeGameCreated, DisputeGameFactoryInstance},
-        OPSuccinctFaultDisputeGame,
+        GameStatus,
+        OPSuccinctFaultDisputeGame::{self, Challenged, Resolved},
+        ProposalStatus,
     },
-    prometheus::ProposerGauge,
-    Action, FactoryTrait, L1Provider, L2Provider, L2ProviderTrait, Mode,
+    correctness::{ProposalCorrectnessMonitor, ResolutionDiscrepancy},
+    game_indices_between,
+    health::HealthState,
+    journal::{OperationStatus, PendingOperation, ProposerJournal},
+    notify::{NotificationEvent, WebhookNotifier},
+    prometheus::{
+        ProposalMetrics, ProposalMetricsRegistry, ProposerGauge, ProposerHistogram,
+        ProposerMetrics, TxOperation, PROPOSAL_BOND_AT_RISK, PROPOSAL_STATUS,
+        PROPOSAL_TIME_REMAINING_SECS,
+    },
+    reorg::is_canonical_l1_block,
+    rpc_limit::init_rpc_limits,
+    utils::{
+        cancel_on_shutdown_signal, claimed_bond_wei, connect_provider, gas_cost_wei,
+        gas_price_paid_gwei, get_logs_paged, jittered_interval, log_dry_run_transaction,
+        send_pipelined_with_retry, send_with_retry, with_1559_fees,
+    },
+    witness_validation::{ProofInputValidator, ProofInputs},
+    Action, FactoryTrait, L1Provider, L2Provider, L2ProviderTrait, Mode, ValidProposalSearch,
+    DEFAULT_NETWORK_PRIVATE_KEY,
 };
 
+/// The number of run loop ticks between chain ID checks.
+const CHAIN_ID_CHECK_INTERVAL_TICKS: u64 = 100;
+
+/// The fast finality success rate below which proof generation is considered too slow.
+const FAST_FINALITY_SUCCESS_RATE_WARN_PCT: f64 = 90.0;
+
+/// The number of recent L1 blocks scanned for games already created by this proposer.
+const RACE_DETECTION_L1_BLOCKS: u64 = 10;
+
+/// The smoothing factor of the proof generation time moving average.
+const PROOF_TIME_EWMA_ALPHA: f64 = 0.2;
+
+/// The L1 block time in seconds, used to estimate how fast games can be created on L1.
+const L1_BLOCK_TIME_SECS: u64 = 12;
+
+/// The number of recent L2 blocks sampled to estimate the average transaction density.
+const TX_DENSITY_SAMPLE_BLOCKS: u64 = 10;
+
+/// The number of seconds in a day.
+const SECS_PER_DAY: u64 = 86_400;
+
+/// The maximum number of sequential games resolved back to back in a single batch.
+const MAX_SEQUENTIAL_RESOLUTION_BATCH_SIZE: usize = 16;
+
+/// The gas limit of the resolve transactions of a batch, as a percentage of the gas estimated for
+/// the first one. The later games can't be estimated before their parents are resolved.
+const RESOLVE_GAS_LIMIT_PERCENT: u64 = 120;
+
 /// Type alias for task ID
 pub type TaskId = u64;
 
@@ -54,6 +111,276 @@ pub enum TaskInfo {
     BondClaim,
 }
 
+/// Returns the address of the game created in `logs`, if any.
+fn find_created_game<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Option<Address> {
+    logs.into_iter().find_map(|log| {
+        DisputeGameCreated::decode_log(&log.inner).ok().map(|event| event.disputeProxy)
+    })
+}
+
+/// The error returned when the prover network does not return a proof within
+/// `proof_timeout_secs`.
+#[derive(Debug, thiserror::Error)]
+#[error("{stage} proof generation timed out after {timeout:?}")]
+pub struct ProofTimeout {
+    /// The proof that timed out, either "Range" or "Aggregation".
+    pub stage: &'static str,
+    pub timeout: Duration,
+}
+
+/// A summary of what a single proposer tick did.
+#[derive(Clone, Debug, Default)]
+pub struct TickReport {
+    /// Whether operations were paused because L1 is halted.
+    pub paused: bool,
+    /// The number of finished tasks that were cleaned up.
+    pub tasks_completed: usize,
+    /// Whether a game creation task was spawned.
+    pub game_creation_spawned: bool,
+    /// Whether a game defense task was spawned.
+    pub game_defense_spawned: bool,
+    /// Whether a game resolution task was spawned.
+    pub game_resolution_spawned: bool,
+    /// Whether a bond claim task was spawned.
+    pub bond_claim_spawned: bool,
+}
+
+/// Timings of a single witness generation run for a range of L2 blocks.
+#[derive(Clone, Debug)]
+pub struct WitnessGenBenchmark {
+    /// Time spent fetching the host arguments.
+    pub fetch_duration: Duration,
+    /// Time spent running the host to generate the witness.
+    pub run_duration: Duration,
+    /// Total time spent generating the witness.
+    pub total_duration: Duration,
+    /// Cycle count of the range program when executed on the witness.
+    pub estimated_cycles: u64,
+}
+
+/// The result of comparing the verification keys of the embedded ELFs with those the game was
+/// deployed with.
+#[derive(Clone, Debug)]
+pub struct ElfCompatibilityReport {
+    /// The verification key of the embedded aggregation ELF.
+    pub elf_aggregation_vkey: B256,
+    /// The aggregation verification key the game was deployed with, if configured.
+    pub deployed_aggregation_vkey: Option<B256>,
+    /// The verification key commitment of the embedded range ELF.
+    pub elf_range_vkey_commitment: B256,
+    /// The range verification key commitment the game was deployed with, if configured.
+    pub deployed_range_vkey_commitment: Option<B256>,
+    /// Whether the configured keys match those of the embedded ELFs.
+    pub compatible: bool,
+}
+
+/// Activity of a single proposer, derived from on-chain events.
+#[derive(Clone, Debug, Default)]
+pub struct ProposerStats {
+    pub address: Address,
+    pub proposals_submitted: u32,
+    pub proposals_resolved: u32,
+    pub proposals_challenged: u32,
+    pub proposals_lost: u32,
+    /// The bonds won minus the bonds lost across resolved games, in wei.
+    pub net_bond_outcome_wei: I256,
+}
+
+/// Moving average of how long it takes to generate and submit a proof for a game.
+#[derive(Clone, Debug, Default)]
+pub struct ProofTimingStats {
+    /// The moving average of the proof generation time, in seconds.
+    pub ewma_secs: Option<f64>,
+}
+
+impl ProofTimingStats {
+    fn record(&mut self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        self.ewma_secs = Some(match self.ewma_secs {
+            Some(ewma) => PROOF_TIME_EWMA_ALPHA * secs + (1.0 - PROOF_TIME_EWMA_ALPHA) * ewma,
+            None => secs,
+        });
+    }
+}
+
+/// Pauses game creation after too many consecutive failures, so that a persistent problem such as
+/// an unreachable RPC or an empty signer doesn't cause a failing creation attempt every tick.
+#[derive(Clone, Debug, Default)]
+struct CreationCircuitBreaker {
+    consecutive_failures: u32,
+    /// When the circuit breaker tripped, if game creation is paused.
+    tripped_at: Option<Instant>,
+}
+
+/// A classification of a failed proof with suggestions for the operator.
+#[derive(Clone, Debug)]
+pub struct ProofFailureDiagnosis {
+    pub likely_cause: String,
+    pub suggested_remediation: Vec<String>,
+    /// Whether retrying without operator intervention may succeed.
+    pub can_retry: bool,
+    pub estimated_retry_time: Option<Duration>,
+}
+
+/// The component limiting the L2 throughput the fault proof system can support.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bottleneck {
+    /// Proofs for a proposal interval cannot be generated faster.
+    ProofGeneration,
+    /// Games cannot be created faster than their creation transactions are confirmed on L1.
+    L1Finality,
+    /// The proposer cannot create games faster than once per tick.
+    ProposalInterval,
+}
+
+/// Why the proposer did not create a game on a tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The finalized L2 head has not reached the next proposal block.
+    FinalityNotAdvanced,
+    /// A game for the next proposal block was already created by this proposer.
+    DuplicateProposal,
+    /// The L1 or L2 RPC reported an unexpected chain ID.
+    ChainIdMismatch,
+    /// Creating a game for the next proposal block failed recently.
+    CreationBackoff,
+    /// The signer is not allowed to propose by the access manager.
+    NotWhitelisted,
+    /// The minimum interval between proposals has not passed since the last game was created.
+    RateLimited,
+}
+
+impl SkipReason {
+    /// Increments the counter for this reason. Every reason other than
+    /// [`SkipReason::FinalityNotAdvanced`] is also counted as a missed proposal opportunity.
+    pub fn record(self, metrics: &ProposerMetrics) {
+        match self {
+            SkipReason::FinalityNotAdvanced => {
+                metrics.increment(ProposerGauge::SkippedForFinalityCount, 1.0);
+                return;
+            }
+            SkipReason::DuplicateProposal => {
+                metrics.increment(ProposerGauge::SkippedForDuplicateProposalCount, 1.0);
+                metrics.increment(ProposerGauge::RaceConditionsDetected, 1.0);
+            }
+            SkipReason::ChainIdMismatch => {
+                metrics.increment(ProposerGauge::SkippedForChainIdMismatchCount, 1.0)
+            }
+            SkipReason::CreationBackoff => {
+                metrics.increment(ProposerGauge::SkippedForCreationBackoffCount, 1.0)
+            }
+            SkipReason::NotWhitelisted => {
+                metrics.increment(ProposerGauge::SkippedForNotWhitelistedCount, 1.0)
+            }
+            SkipReason::RateLimited => {
+                metrics.increment(ProposerGauge::SkippedForRateLimitCount, 1.0)
+            }
+        }
+        metrics.increment(ProposerGauge::MissedProposalOpportunities, 1.0);
+    }
+}
+
+/// The game the proposer would create next, as computed by
+/// [`OPSuccinctProposer::next_proposal_plan`].
+#[derive(Clone, Debug)]
+pub struct ProposalPlan {
+    /// The L2 block number and index of the latest valid game, or `None` if the next game builds
+    /// on the anchor state.
+    pub latest_valid_proposal: Option<(U256, U256)>,
+    /// The L2 block number of the latest valid game, or of the anchor state.
+    pub latest_proposed_block_number: U256,
+    /// The L2 block number the next game would be created for.
+    pub next_l2_block_number: U256,
+    /// The index of the next game's parent, or `u32::MAX` if it builds on the anchor state.
+    pub parent_game_index: u32,
+    /// The finalized L2 head, or `None` if none was found past the latest proposed block.
+    pub finalized_l2_head_block_number: Option<u64>,
+    /// Whether the finalized L2 head is past the next block, so that a game would be created now.
+    pub would_submit: bool,
+}
+
+impl ProposalPlan {
+    /// The number of L2 blocks the finalized head still has to advance before the next game can
+    /// be created, or `None` if no finalized head was found.
+    pub fn finality_gap(&self) -> Option<u64> {
+        self.finalized_l2_head_block_number.map(|finalized_block| {
+            (self.next_l2_block_number + U256::from(1))
+                .saturating_sub(U256::from(finalized_block))
+                .to::<u64>()
+        })
+    }
+}
+
+/// Whether the proposer can switch to new range and aggregation verification keys.
+#[derive(Clone, Debug)]
+pub struct UpgradeReadinessReport {
+    pub current_range_vk: B256,
+    pub new_range_vk: B256,
+    pub current_agg_vk: B256,
+    pub new_agg_vk: B256,
+    /// Whether proofs are being generated, or still need to be generated, for challenged games.
+    pub has_pending_proofs: bool,
+    pub can_upgrade_now: bool,
+    pub reason: String,
+}
+
+/// An estimate of how long the proposer must operate to recoup its deployment costs.
+#[derive(Clone, Debug)]
+pub struct BreakEvenReport {
+    pub deployment_cost_wei: U256,
+    pub expected_daily_gas_cost_wei: U256,
+    pub expected_daily_bond_earnings_wei: U256,
+    /// `None` if the expected daily earnings do not exceed the expected daily gas cost.
+    pub break_even_days: Option<f64>,
+}
+
+/// Timings and costs of a proposer stress test.
+#[derive(Clone, Debug)]
+pub struct StressTestReport {
+    pub proposals_created: u32,
+    /// The average duration of a successful game creation attempt.
+    pub avg_create_latency: Duration,
+    /// The average proof generation and submission time, if games were proved.
+    pub avg_prove_latency: Option<Duration>,
+    pub avg_resolve_latency: Duration,
+    /// The games that weren't resolved before the resolve timeout.
+    pub unresolved_games: Vec<Address>,
+    /// The gas spent on creating, proving and resolving the games, in wei.
+    pub total_gas_spent_wei: U256,
+}
+
+/// The ETH financial position of the proposer.
+#[derive(Clone, Debug, Serialize)]
+pub struct FinancialSummary {
+    pub signer_balance: U256,
+    /// The init bonds of this proposer's in-progress games.
+    pub bonds_in_active_proposals: U256,
+    /// The credit claimable by the prover address from resolved games.
+    pub unclaimed_credit: U256,
+    /// The gas spent on transactions sent by this proposer since it started.
+    pub total_gas_spent: U256,
+    /// `signer_balance + unclaimed_credit - bonds_in_active_proposals`.
+    pub net_position: I256,
+}
+
+/// The liveness of the L1 chain, as seen from its latest block.
+#[derive(Clone, Debug)]
+pub struct L1ChainStatus {
+    pub last_block_number: u64,
+    pub last_block_timestamp: u64,
+    pub secs_since_last_block: u64,
+    pub is_halted: bool,
+}
+
+/// An estimate of the maximum L2 throughput the fault proof system can support.
+#[derive(Clone, Debug)]
+pub struct ThroughputReport {
+    pub max_l2_blocks_per_day: u64,
+    /// Assumes the average transaction density of recent L2 blocks.
+    pub max_txs_per_day: u64,
+    pub bottleneck: Bottleneck,
+}
+
 #[derive(Clone)]
 struct SP1Prover {
     network_prover: Arc<NetworkProver>,
@@ -76,14 +403,41 @@ where
     pub signer: Signer,
     pub l1_provider: L1Provider,
     pub l2_provider: L2Provider,
+    caching_l2_provider: CachingL2Provider,
     pub factory: Arc<DisputeGameFactoryInstance<P>>,
     pub init_bond: U256,
-    pub safe_db_fallback: bool,
     prover: SP1Prover,
     fetcher: Arc<OPSuccinctDataFetcher>,
     host: Arc<H>,
     tasks: Arc<Mutex<TaskMap>>,
     next_task_id: Arc<AtomicU64>,
+    ticks: Arc<AtomicU64>,
+    /// The liveness and readiness reported by the health endpoints.
+    pub health: Arc<HealthState>,
+    /// When game creation last failed, by the L2 block number of the game.
+    creation_failures: Arc<Mutex<HashMap<U256, Instant>>>,
+    creation_circuit_breaker: Arc<Mutex<CreationCircuitBreaker>>,
+    /// Whether the signer was allowed to propose when last checked, and when that was.
+    proposer_allowed: Arc<Mutex<Option<(bool, Instant)>>>,
+    proposal_metrics: Arc<Mutex<ProposalMetricsRegistry>>,
+    /// Records the metrics of this proposer, labeled with its factory.
+    metrics: ProposerMetrics,
+    chain_id_mismatch: Arc<AtomicBool>,
+    l1_halted: Arc<AtomicBool>,
+    max_agg_proof_size_bytes: Arc<AtomicU64>,
+    total_gas_spent_wei: Arc<Mutex<U256>>,
+    /// When this proposer was created, from which its uptime is measured.
+    started_at: Instant,
+    correctness_monitor: Arc<Mutex<ProposalCorrectnessMonitor>>,
+    // Games created in fast finality mode, and how many of them were proved before any challenge.
+    fast_finality_total: Arc<AtomicU64>,
+    fast_finality_successes: Arc<AtomicU64>,
+    proof_timing: Arc<Mutex<ProofTimingStats>>,
+    audit_trail: Option<ProposalAuditTrail>,
+    notifier: Option<WebhookNotifier>,
+    journal: Option<Arc<ProposerJournal>>,
+    /// The previous search for the latest valid proposal, from which the next search resumes.
+    valid_proposal_search: Arc<Mutex<Option<ValidProposalSearch>>>,
 }
 
 impl<P, H> OPSuccinctProposer<P, H>
@@ -102,12 +456,28 @@ where
     ) -> Result<Self> {
         let config = ProposerConfig::from_env()?;
 
+        init_rpc_limits(config.rpc_limits);
+        let l1_provider: L1Provider = connect_provider(&config.l1_rpc).await?;
+        let l2_provider = L2Provider::new(
+            connect_provider(&config.l2_rpc).await?,
+            config.l2_to_l1_message_passer,
+            Mode::Proposer,
+            *factory.address(),
+        );
+        config.validate(&l1_provider, &l2_provider, &factory, signer.address()).await?;
+        if config.bond_recipient.is_some() && prover_address != signer.address() {
+            bail!(
+                "BOND_RECIPIENT requires bonds to be claimed by the signer, but PROVER_ADDRESS {prover_address} differs from the signer address {}",
+                signer.address()
+            );
+        }
+
         // Set a default network private key to avoid an error in mock mode.
         let private_key = env::var("NETWORK_PRIVATE_KEY").unwrap_or_else(|_| {
             tracing::warn!(
                 "Using default NETWORK_PRIVATE_KEY of 0x01. This is only valid in mock mode."
             );
-            "0x0000000000000000000000000000000000000000000000000000000000000001".to_string()
+            DEFAULT_NETWORK_PRIVATE_KEY.to_string()
         });
 
         let network_prover =
@@ -115,15 +485,26 @@ where
         let (range_pk, range_vk) = network_prover.setup(get_range_elf_embedded());
         let (agg_pk, _) = network_prover.setup(AGGREGATION_ELF);
 
-        Ok(Self {
+        let l2_node_provider = match config.l2_node_rpc.as_ref() {
+            Some(url) if config.prefer_output_at_block_rpc => Some(connect_provider(url).await?),
+            _ => None,
+        };
+
+        let proposer = Self {
             config: config.clone(),
             prover_address,
             signer,
-            l1_provider: ProviderBuilder::default().connect_http(config.l1_rpc.clone()),
-            l2_provider: ProviderBuilder::default().connect_http(config.l2_rpc),
+            l1_provider,
+            l2_provider: l2_provider.clone(),
+            caching_l2_provider: CachingL2Provider::new(
+                l2_provider,
+                l2_node_provider,
+                Mode::Proposer,
+                *factory.address(),
+                config.output_root_cache_size,
+            )?,
             factory: Arc::new(factory.clone()),
             init_bond: factory.fetch_init_bond(config.game_type).await?,
-            safe_db_fallback: config.safe_db_fallback,
             prover: SP1Prover {
                 network_prover,
                 range_pk: Arc::new(range_pk),
@@ -134,13 +515,171 @@ where
             host,
             tasks: Arc::new(Mutex::new(HashMap::new())),
             next_task_id: Arc::new(AtomicU64::new(1)),
-        })
+            ticks: Arc::new(AtomicU64::new(0)),
+            health: Arc::new(HealthState::new(Duration::from_secs(config.health_staleness_secs))),
+            creation_failures: Arc::new(Mutex::new(HashMap::new())),
+            creation_circuit_breaker: Arc::new(Mutex::new(CreationCircuitBreaker::default())),
+            proposer_allowed: Arc::new(Mutex::new(None)),
+            proposal_metrics: Arc::new(Mutex::new(ProposalMetricsRegistry::default())),
+            metrics: ProposerMetrics::new(*factory.address()),
+            chain_id_mismatch: Arc::new(AtomicBool::new(false)),
+            l1_halted: Arc::new(AtomicBool::new(false)),
+            max_agg_proof_size_bytes: Arc::new(AtomicU64::new(0)),
+            total_gas_spent_wei: Arc::new(Mutex::new(U256::ZERO)),
+            started_at: Instant::now(),
+            correctness_monitor: Arc::new(Mutex::new(ProposalCorrectnessMonitor::default())),
+            fast_finality_total: Arc::new(AtomicU64::new(0)),
+            fast_finality_successes: Arc::new(AtomicU64::new(0)),
+            proof_timing: Arc::new(Mutex::new(ProofTimingStats::default())),
+            audit_trail: config
+                .audit_log_path
+                .clone()
+                .map(ProposalAuditTrail::spawn)
+                .transpose()?,
+            notifier: config
+                .notification_webhook_url
+                .clone()
+                .map(|url| WebhookNotifier::spawn(url, config.notification_events.clone())),
+            journal: config
+                .state_dir
+                .as_deref()
+                .map(ProposerJournal::open)
+                .transpose()?
+                .map(Arc::new),
+            valid_proposal_search: Arc::new(Mutex::new(None)),
+        };
+
+        let report = proposer.verify_aggregation_elf_compatibility()?;
+        if !report.compatible {
+            bail!(
+                "Embedded ELFs don't match the deployed game: aggregation vkey {:?} (deployed {:?}), range vkey commitment {:?} (deployed {:?})",
+                report.elf_aggregation_vkey,
+                report.deployed_aggregation_vkey,
+                report.elf_range_vkey_commitment,
+                report.deployed_range_vkey_commitment
+            );
+        }
+
+        proposer.reconcile_journal().await?;
+
+        Ok(proposer)
     }
 
-    #[tracing::instrument(name = "[[Proving]]", skip(self), fields(game_address = ?game_address))]
-    pub async fn prove_game(&self, game_address: Address) -> Result<TxHash> {
-        tracing::info!("Attempting to prove game {:?}", game_address);
+    /// Creates a proposer for another factory, sharing the providers, prover, fetcher and host of
+    /// this proposer, so that a single process can propose to several factories.
+    ///
+    /// The in-flight state of the new proposer is separate. Its journal, if enabled, is kept in a
+    /// subdirectory of the state directory named after the factory address.
+    pub async fn for_factory(&self, factory: DisputeGameFactoryInstance<P>) -> Result<Self> {
+        let journal = match &self.config.state_dir {
+            Some(state_dir) => Some(Arc::new(ProposerJournal::open(
+                &state_dir.join(factory.address().to_string()),
+            )?)),
+            None => None,
+        };
+
+        let mut config = self.config.clone();
+        config.factory_address = *factory.address();
+        config.additional_factory_addresses = Vec::new();
+
+        let proposer = Self {
+            config,
+            init_bond: factory.fetch_init_bond(self.config.game_type).await?,
+            factory: Arc::new(factory),
+            tasks: Arc::new(Mutex::new(HashMap::new())),
+            next_task_id: Arc::new(AtomicU64::new(1)),
+            ticks: Arc::new(AtomicU64::new(0)),
+            creation_failures: Arc::new(Mutex::new(HashMap::new())),
+            creation_circuit_breaker: Arc::new(Mutex::new(CreationCircuitBreaker::default())),
+            proposer_allowed: Arc::new(Mutex::new(None)),
+            // Share the registry, so that the proposals of all factories are served together.
+            proposal_metrics: self.proposal_metrics.clone(),
+            metrics: ProposerMetrics::new(*factory.address()),
+            l2_provider: self.l2_provider.for_factory(*factory.address()),
+            caching_l2_provider: self.caching_l2_provider.for_factory(*factory.address()),
+            max_agg_proof_size_bytes: Arc::new(AtomicU64::new(0)),
+            total_gas_spent_wei: Arc::new(Mutex::new(U256::ZERO)),
+            started_at: Instant::now(),
+            correctness_monitor: Arc::new(Mutex::new(ProposalCorrectnessMonitor::default())),
+            fast_finality_total: Arc::new(AtomicU64::new(0)),
+            fast_finality_successes: Arc::new(AtomicU64::new(0)),
+            proof_timing: Arc::new(Mutex::new(ProofTimingStats::default())),
+            journal,
+            valid_proposal_search: Arc::new(Mutex::new(None)),
+            ..self.clone()
+        };
+
+        proposer.reconcile_journal().await?;
+
+        Ok(proposer)
+    }
+
+    /// Reconciles the operations journaled by the previous run with the on-chain state.
+    ///
+    /// Completed and abandoned operations are removed. Generated proofs of games that still need
+    /// one are kept, so that [`Self::prove_game`] submits them instead of generating new ones.
+    async fn reconcile_journal(&self) -> Result<()> {
+        let Some(journal) = &self.journal else {
+            return Ok(());
+        };
+
+        for entry in journal.entries() {
+            match (&entry.operation, &entry.status) {
+                (PendingOperation::GameCreation { l2_block_number }, _) => {
+                    match self.detect_own_proposal_race(*l2_block_number).await? {
+                        Some(game_address) => tracing::info!(
+                            "Game {:?} for L2 block {} was created before the restart",
+                            game_address,
+                            l2_block_number
+                        ),
+                        None => tracing::info!(
+                            "Game creation for L2 block {} did not complete before the restart",
+                            l2_block_number
+                        ),
+                    }
+                }
+                (
+                    PendingOperation::GameProving { game_address },
+                    OperationStatus::ProofGenerated { .. },
+                ) => {
+                    let game =
+                        OPSuccinctFaultDisputeGame::new(*game_address, self.l1_provider.clone());
+                    let status = game.claimData().call().await?.status;
+                    if matches!(status, ProposalStatus::Unchallenged | ProposalStatus::Challenged) {
+                        tracing::info!(
+                            "Keeping the generated proof of game {:?} to submit it without regenerating",
+                            game_address
+                        );
+                        continue;
+                    }
+                    tracing::info!(
+                        "Game {:?} no longer needs the journaled proof, its status is {:?}",
+                        game_address,
+                        status
+                    );
+                }
+                (PendingOperation::GameProving { game_address }, OperationStatus::Started) => {
+                    tracing::info!(
+                        "Proof generation for game {:?} did not complete before the restart",
+                        game_address
+                    );
+                }
+            }
+            journal.complete(&entry.operation);
+        }
 
+        Ok(())
+    }
+
+    /// Generates the aggregation proof for the game at `game_address`.
+    ///
+    /// In `mock_mode`, both programs are only executed and mock proofs are created from their
+    /// public values, without using the Succinct Prover Network.
+    async fn generate_aggregation_proof(
+        &self,
+        game_address: Address,
+        mock_mode: bool,
+    ) -> Result<SP1ProofWithPublicValues> {
         let fetcher = match OPSuccinctDataFetcher::new_with_rollup_config().await {
             Ok(f) => f,
             Err(e) => {
@@ -154,18 +693,25 @@ where
         tracing::debug!("L1 head hash: {:?}", hex::encode(l1_head_hash));
         let l2_block_number = game.l2BlockNumber().call().await?;
 
+        let l2_start_block = l2_block_number.to::<u64>() - self.config.proposal_interval_in_blocks;
+
+        let fetch_start = Instant::now();
         let host_args = self
             .host
             .fetch(
-                l2_block_number.to::<u64>() - self.config.proposal_interval_in_blocks,
+                l2_start_block,
                 l2_block_number.to::<u64>(),
                 Some(l1_head_hash.into()),
-                self.config.safe_db_fallback,
+                self.config.prove_safe_db_fallback,
             )
             .await
             .context("Failed to get host CLI args")?;
+        self.metrics
+            .set(ProposerGauge::LastWitnessGenFetchSecs, fetch_start.elapsed().as_secs_f64());
 
+        let run_start = Instant::now();
         let witness_data = self.host.run(&host_args).await?;
+        self.metrics.set(ProposerGauge::LastWitnessGenRunSecs, run_start.elapsed().as_secs_f64());
 
         let sp1_stdin = match self.host.witness_generator().get_sp1_stdin(witness_data) {
             Ok(stdin) => stdin,
@@ -175,8 +721,25 @@ where
             }
         };
 
+        // Reject malformed witnesses before they waste prover network quota.
+        let validator =
+            ProofInputValidator::new(self.l2_provider.clone(), self.config.max_stdin_bytes);
+        let report = validator
+            .validate_witness_data(&ProofInputs {
+                l2_start_block,
+                l2_end_block: l2_block_number.to::<u64>(),
+                l1_head: l1_head_hash.into(),
+                expected_agreed_output_root: game.startingRootHash().call().await?,
+                sp1_stdin: &sp1_stdin,
+            })
+            .await?;
+        if !report.is_valid() {
+            bail!("Witness validation failed: {:?}", report.failed_checks());
+        }
+
         tracing::info!("Generating Range Proof");
-        let range_proof = if self.config.mock_mode {
+        let range_proof_start = Instant::now();
+        let range_proof = if mock_mode {
             tracing::info!("Using mock mode for range proof generation");
             let (public_values, _) =
                 self.prover.network_prover.execute(get_range_elf_embedded(), &sp1_stdin).run()?;
@@ -189,17 +752,26 @@ where
                 SP1_CIRCUIT_VERSION,
             )
         } else {
-            self.prover
-                .network_prover
-                .prove(&self.prover.range_pk, &sp1_stdin)
-                .compressed()
-                .strategy(FulfillmentStrategy::Hosted)
-                .skip_simulation(true)
-                .cycle_limit(1_000_000_000_000)
-                .run_async()
-                .await?
+            self.with_proof_timeout(
+                "Range",
+                self.prover
+                    .network_prover
+                    .prove(&self.prover.range_pk, &sp1_stdin)
+                    .compressed()
+                    .strategy(self.config.range_proof_strategy)
+                    .skip_simulation(self.config.skip_simulation)
+                    .cycle_limit(self.config.range_proof_cycle_limit)
+                    .run_async(),
+            )
+            .await?
         };
 
+        self.metrics.record_proof_latency_secs(
+            ProposerGauge::ProofRangeLatencySecs,
+            mock_mode,
+            range_proof_start.elapsed().as_secs_f64(),
+        );
+
         tracing::info!("Preparing Stdin for Agg Proof");
         let proof = range_proof.proof.clone();
         let mut public_values = range_proof.public_values.clone();
@@ -232,7 +804,8 @@ where
         };
 
         tracing::info!("Generating Agg Proof");
-        let agg_proof = if self.config.mock_mode {
+        let agg_proof_start = Instant::now();
+        let agg_proof = if mock_mode {
             tracing::info!("Using mock mode for aggregation proof generation");
             let (public_values, _) = self
                 .prover
@@ -245,256 +818,2016 @@ where
             SP1ProofWithPublicValues::create_mock_proof(
                 &self.prover.agg_pk,
                 public_values,
-                SP1ProofMode::Groth16,
+                self.config.agg_proof_mode,
                 SP1_CIRCUIT_VERSION,
             )
         } else {
-            self.prover
-                .network_prover
-                .prove(&self.prover.agg_pk, &sp1_stdin)
-                .groth16()
-                .run_async()
-                .await?
+            self.with_proof_timeout(
+                "Aggregation",
+                self.prover
+                    .network_prover
+                    .prove(&self.prover.agg_pk, &sp1_stdin)
+                    .mode(self.config.agg_proof_mode)
+                    .run_async(),
+            )
+            .await?
+        };
+        self.metrics.record_proof_latency_secs(
+            ProposerGauge::ProofAggLatencySecs,
+            mock_mode,
+            agg_proof_start.elapsed().as_secs_f64(),
+        );
+
+        Ok(agg_proof)
+    }
+
+    /// Awaits a proof from the prover network, failing with [`ProofTimeout`] once
+    /// `proof_timeout_secs` have passed.
+    ///
+    /// On timeout the future is dropped, which stops polling the prover network for the proof.
+    /// The request itself cannot be cancelled and may still be fulfilled by the network.
+    async fn with_proof_timeout<T, E>(
+        &self,
+        stage: &'static str,
+        proof: impl Future<Output = Result<T, E>>,
+    ) -> Result<T>
+    where
+        E: Into<anyhow::Error>,
+    {
+        let timeout = Duration::from_secs(self.config.proof_timeout_secs);
+        match time::timeout(timeout, proof).await {
+            Ok(proof) => proof.map_err(Into::into),
+            Err(_) => {
+                self.metrics.increment(ProposerGauge::ProofTimeout, 1.0);
+                Err(ProofTimeout { stage, timeout }.into())
+            }
+        }
+    }
+
+    #[tracing::instrument(name = "[[Proving]]", skip(self), fields(game_address = ?game_address))]
+    pub async fn prove_game(&self, game_address: Address) -> Result<TxHash> {
+        tracing::info!("Attempting to prove game {:?}", game_address);
+        let prove_start = Instant::now();
+
+        // A proof against an L1 head that was reorged out can not be verified, so don't spend
+        // prover resources on it. The game is proved on a later attempt if the block comes back.
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        let l1_head_hash = game.l1Head().call().await?;
+        if !is_canonical_l1_block(&self.l1_provider, l1_head_hash).await? {
+            self.metrics.increment(ProposerGauge::L1HeadReorged, 1.0);
+            bail!(
+                "L1 head {:?} of game {:?} is no longer canonical, skipping proof generation",
+                l1_head_hash,
+                game_address
+            );
+        }
+
+        let operation = PendingOperation::GameProving { game_address };
+        let journaled_proof =
+            self.journal.as_ref().and_then(|journal| journal.generated_proof(game_address));
+        let proof = match journaled_proof {
+            Some(proof) => {
+                tracing::info!("Submitting the journaled proof of game {:?}", game_address);
+                proof
+            }
+            None => {
+                self.journal_operation(operation.clone(), OperationStatus::Started);
+                let agg_proof =
+                    self.generate_aggregation_proof(game_address, self.config.mock_mode).await?;
+                let proof = Bytes::from(agg_proof.bytes());
+                self.journal_operation(
+                    operation.clone(),
+                    OperationStatus::ProofGenerated { proof: proof.clone() },
+                );
+                proof
+            }
+        };
+
+        let transaction_request = game.prove(proof.clone()).into_transaction_request();
+        if self.config.dry_run {
+            log_dry_run_transaction("prove", &transaction_request);
+            return Ok(TxHash::ZERO);
+        }
+
+        let transaction_request = with_1559_fees(
+            &self.l1_provider,
+            transaction_request,
+            self.config.l1_fee_percentile,
+            self.config.max_fee_per_gas_gwei,
+        )
+        .await?;
+        let receipt = send_with_retry(
+            &self.signer,
+            self.config.l1_rpc.clone(),
+            transaction_request,
+            &self.config.tx_retry_policy,
+            Mode::Proposer,
+            *self.factory.address(),
+            TxOperation::Prove,
+        )
+        .await?;
+        self.metrics.record(ProposerHistogram::L1GasPricePaidGwei, gas_price_paid_gwei(&receipt));
+        self.complete_journaled_operation(&operation);
+        self.proof_timing.lock().await.record(prove_start.elapsed());
+        self.record_agg_proof_size(proof.len() as u64);
+        self.record_audit(AuditEntry::performed(
+            AuditAction::Prove,
+            game_address,
+            self.signer.address(),
+            &receipt,
+        ));
+        self.notify(
+            NotificationEvent::GameProven,
+            format!("Proposer proved game {game_address} with tx {}", receipt.transaction_hash),
+        );
+        self.record_gas_spent(&receipt, TxOperation::Prove).await;
+
+        if self.config.fast_finality_mode &&
+            game.claimData().call().await?.status ==
+                ProposalStatus::UnchallengedAndValidProofProvided
+        {
+            self.fast_finality_successes.fetch_add(1, Ordering::Relaxed);
+            self.update_fast_finality_success_rate();
+        }
+
+        Ok(receipt.transaction_hash)
+    }
+
+    /// Exercises the mock proof flow end-to-end for the game proposing `l2_block_number`.
+    ///
+    /// Generates a mock aggregation proof without using the Succinct Prover Network and simulates
+    /// the `prove` call against the game, without broadcasting it. This checks the proof encoding
+    /// and the verifier interface of a deployment configured with a mock verifier. Only the last
+    /// `MAX_GAMES_TO_CHECK_FOR_DEFENSE` games are searched.
+    pub async fn test_mock_proof_generation(&self, l2_block_number: u64) -> Result<()> {
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            bail!("No games exist");
+        };
+
+        let oldest_game_index = latest_game_index
+            .saturating_sub(U256::from(self.config.max_games_to_check_for_defense));
+        let mut index = latest_game_index;
+        let game_address = loop {
+            let game_address = self.factory.fetch_game_address_by_index(index).await?;
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            if game.l2BlockNumber().call().await? == U256::from(l2_block_number) {
+                break game_address;
+            }
+            if index == oldest_game_index {
+                bail!("No game found for L2 block {}", l2_block_number);
+            }
+            index -= U256::from(1);
         };
+        tracing::info!("Testing mock proof generation for game {:?}", game_address);
+
+        let agg_proof = self.generate_aggregation_proof(game_address, true).await?;
+
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        game.prove(agg_proof.bytes().into())
+            .from(self.signer.address())
+            .call()
+            .await
+            .context("Simulated prove call reverted")?;
+
+        Ok(())
+    }
+
+    /// Generates and submits a proof for the game at `game_index`, for manually proving a game
+    /// outside of the defense loop.
+    ///
+    /// Only games that are not yet resolved and have no proof can be proven.
+    pub async fn prove_game_at_index(&self, game_index: U256) -> Result<TxHash> {
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            bail!("No games exist");
+        };
+        if game_index > latest_game_index {
+            bail!("Game index {} does not exist, the latest is {}", game_index, latest_game_index);
+        }
+
+        let game_address = self.factory.fetch_game_address_by_index(game_index).await?;
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        let status = game.claimData().call().await?.status;
+        if !matches!(status, ProposalStatus::Unchallenged | ProposalStatus::Challenged) {
+            bail!(
+                "Game {:?} at index {} can not be proven in status {:?}",
+                game_address,
+                game_index,
+                status
+            );
+        }
+
+        self.prove_game(game_address).await
+    }
+
+    /// Records the size of a submitted aggregation proof, and warns if it exceeds the expected
+    /// maximum.
+    fn record_agg_proof_size(&self, proof_size_bytes: u64) {
+        self.metrics.record(ProposerHistogram::AggregationProofSizeBytes, proof_size_bytes as f64);
+        let max_proof_size_bytes =
+            self.max_agg_proof_size_bytes.fetch_max(proof_size_bytes, Ordering::Relaxed);
+        self.metrics.set(
+            ProposerGauge::MaxAggProofSizeBytes,
+            max_proof_size_bytes.max(proof_size_bytes) as f64,
+        );
+
+        if proof_size_bytes > self.config.max_expected_proof_size_bytes {
+            tracing::warn!(
+                "Aggregation proof size {} bytes exceeds the expected maximum of {} bytes",
+                proof_size_bytes,
+                self.config.max_expected_proof_size_bytes
+            );
+        }
+    }
+
+    /// Gets the latest valid proposal, only checking the games created since the previous call.
+    async fn latest_valid_proposal(&self) -> Result<Option<(U256, U256)>> {
+        let mut previous = self.valid_proposal_search.lock().await;
+        let search = self
+            .factory
+            .get_latest_valid_proposal(
+                self.config.game_type,
+                self.caching_l2_provider.clone(),
+                *previous,
+                self.config.max_games_to_check_for_valid_proposal,
+                self.config.valid_proposal_search_strategy,
+            )
+            .await?;
+        *previous = Some(search);
+        Ok(search.latest_valid_proposal)
+    }
+
+    /// Adds the gas cost of a transaction to the total gas spent, and to the gas spent metric of
+    /// the operation it was sent for.
+    async fn record_gas_spent(&self, receipt: &TransactionReceipt, operation: TxOperation) {
+        *self.total_gas_spent_wei.lock().await +=
+            U256::from(receipt.gas_used) * U256::from(receipt.effective_gas_price);
+        self.metrics.record_gas_spent_wei(operation, gas_cost_wei(receipt));
+    }
+
+    /// Records an entry in the audit trail, if enabled.
+    fn record_audit(&self, entry: AuditEntry) {
+        if let Some(audit_trail) = &self.audit_trail {
+            audit_trail.record(entry);
+        }
+    }
+
+    /// Posts a notification of the event to the webhook, if enabled.
+    fn notify(&self, event: NotificationEvent, message: String) {
+        if let Some(notifier) = &self.notifier {
+            notifier.notify(event, message);
+        }
+    }
+
+    /// Records the status of an in-flight operation in the journal, if enabled.
+    fn journal_operation(&self, operation: PendingOperation, status: OperationStatus) {
+        if let Some(journal) = &self.journal {
+            journal.record(operation, status);
+        }
+    }
+
+    /// Removes an operation from the journal, if enabled.
+    fn complete_journaled_operation(&self, operation: &PendingOperation) {
+        if let Some(journal) = &self.journal {
+            journal.complete(operation);
+        }
+    }
+
+    /// Updates the fast finality success rate gauge.
+    ///
+    /// A low success rate means proofs are not generated before games get challenged, so the
+    /// proposal interval should be increased or the prover upgraded.
+    fn update_fast_finality_success_rate(&self) {
+        let total = self.fast_finality_total.load(Ordering::Relaxed);
+        if total == 0 {
+            return;
+        }
+
+        let successes = self.fast_finality_successes.load(Ordering::Relaxed);
+        let success_rate = successes as f64 / total as f64 * 100.0;
+        self.metrics.set(ProposerGauge::FastFinalitySuccessRatePct, success_rate);
+
+        if success_rate < FAST_FINALITY_SUCCESS_RATE_WARN_PCT {
+            tracing::warn!(
+                "Fast finality success rate is {:.1}% ({}/{}), consider increasing the proposal interval or upgrading the prover",
+                success_rate,
+                successes,
+                total
+            );
+        }
+    }
+
+    /// Creates a new game with the given parameters.
+    ///
+    /// `l2_block_number`: the L2 block number we are proposing the output root for.
+    /// `parent_game_index`: the index of the parent game.
+    ///
+    /// Returns the address of the created game, or `None` in dry run mode.
+    pub async fn create_game(
+        &self,
+        l2_block_number: U256,
+        parent_game_index: u32,
+    ) -> Result<Option<Address>> {
+        tracing::info!(
+            "Creating game at L2 block number: {:?}, with parent game index: {:?}",
+            l2_block_number,
+            parent_game_index
+        );
+
+        let extra_data = <(U256, u32)>::abi_encode_packed(&(l2_block_number, parent_game_index));
+
+        let (output_root, l2_block_hash) =
+            self.compute_output_root_for_proposal(l2_block_number).await?;
+        let transaction_request = self
+            .factory
+            .create(self.config.game_type, output_root, extra_data.into())
+            .value(self.init_bond)
+            .into_transaction_request();
+        if self.config.dry_run {
+            log_dry_run_transaction("create game", &transaction_request);
+            return Ok(None);
+        }
+
+        // Journaled until the receipt is read, so that a game created right before a crash is
+        // detected on restart.
+        let operation = PendingOperation::GameCreation { l2_block_number };
+        self.journal_operation(operation.clone(), OperationStatus::Started);
+        let result: Result<(TransactionReceipt, Address)> = async {
+            let transaction_request = with_1559_fees(
+                &self.l1_provider,
+                transaction_request,
+                self.config.l1_fee_percentile,
+                self.config.max_fee_per_gas_gwei,
+            )
+            .await?;
+            self.verify_l2_block_hash_unchanged(l2_block_number, l2_block_hash).await?;
+            let receipt = send_with_retry(
+                &self.signer,
+                self.config.l1_rpc.clone(),
+                transaction_request,
+                &self.config.tx_retry_policy,
+                Mode::Proposer,
+                *self.factory.address(),
+                TxOperation::Create,
+            )
+            .await?;
+            self.metrics
+                .record(ProposerHistogram::L1GasPricePaidGwei, gas_price_paid_gwei(&receipt));
+
+            let game_address = match find_created_game(receipt.inner.logs()) {
+                Some(game_address) => game_address,
+                // Some RPCs return receipts with missing logs, even though the game was created.
+                None => self.fetch_created_game_from_block_logs(&receipt).await?.context(
+                    "Could not find DisputeGameCreated event in transaction receipt or block logs",
+                )?,
+            };
+            Ok((receipt, game_address))
+        }
+        .await;
+        self.complete_journaled_operation(&operation);
+        let (receipt, game_address) = result?;
+
+        tracing::info!(
+            "\x1b[1mNew game at address {:?} created with tx {:?}\x1b[0m",
+            game_address,
+            receipt.transaction_hash
+        );
+        self.record_audit(AuditEntry::performed(
+            AuditAction::Submit,
+            game_address,
+            self.signer.address(),
+            &receipt,
+        ));
+        self.notify(
+            NotificationEvent::GameCreated,
+            format!(
+                "Proposer created game {game_address} for L2 block {l2_block_number} with tx {}",
+                receipt.transaction_hash
+            ),
+        );
+        self.record_gas_spent(&receipt, TxOperation::Create).await;
+        self.track_created_game(game_address).await;
+
+        Ok(Some(game_address))
+    }
+
+    /// Starts tracking a game created by this proposer, spawning its proof generation in fast
+    /// finality mode.
+    async fn track_created_game(&self, game_address: Address) {
+        if self.config.fast_finality_mode {
+            tracing::info!("Fast finality mode enabled: Spawning proof generation task");
+            self.fast_finality_total.fetch_add(1, Ordering::Relaxed);
+
+            // Spawn a tracked proving task for the new game
+            if let Err(e) = self.spawn_game_proving_task(game_address).await {
+                tracing::warn!("Failed to spawn fast finality proof task: {:?}", e);
+            }
+        }
+    }
+
+    /// Finds the game created by the transaction of `receipt` in the logs of its block, for when
+    /// the receipt returned by the RPC is missing the `DisputeGameCreated` event.
+    async fn fetch_created_game_from_block_logs(
+        &self,
+        receipt: &TransactionReceipt,
+    ) -> Result<Option<Address>> {
+        tracing::warn!(
+            "DisputeGameCreated event missing from the receipt of tx {:?}, fetching the block logs",
+            receipt.transaction_hash
+        );
+        let block_number = receipt.block_number.context("Receipt has no block number")?;
+        let filter = Filter::new()
+            .address(*self.factory.address())
+            .event_signature(DisputeGameCreated::SIGNATURE_HASH)
+            .from_block(block_number)
+            .to_block(block_number);
+
+        let logs = self.l1_provider.get_logs(&filter).await?;
+        Ok(find_created_game(
+            logs.iter().filter(|log| log.transaction_hash == Some(receipt.transaction_hash)),
+        ))
+    }
+
+    /// Detects whether this proposer already created a game for the given L2 block number.
+    ///
+    /// In multi-instance deployments sharing a signing key, two instances may race to create the
+    /// same game. This scans `DisputeGameCreated` events from the last `RACE_DETECTION_L1_BLOCKS`
+    /// L1 blocks of this game type and returns the address of a game created by our signer for
+    /// `l2_block_number`, if one exists.
+    ///
+    /// The scan reads up to the latest L1 block, as the other instance's game is usually not
+    /// confirmed yet when the race happens.
+    pub async fn detect_own_proposal_race(&self, l2_block_number: U256) -> Result<Option<Address>> {
+        let latest_l1_block_number = self.l1_provider.get_block_number().await?;
+        let filter = Filter::new()
+            .address(*self.factory.address())
+            .event_signature(DisputeGameCreated::SIGNATURE_HASH)
+            .topic2(B256::from(U256::from(self.config.game_type)))
+            .from_block(latest_l1_block_number.saturating_sub(RACE_DETECTION_L1_BLOCKS))
+            .to_block(BlockNumberOrTag::Latest);
+
+        for log in self.l1_provider.get_logs(&filter).await? {
+            let Ok(event) = DisputeGameCreated::decode_log(&log.inner) else {
+                continue;
+            };
+
+            let game =
+                OPSuccinctFaultDisputeGame::new(event.disputeProxy, self.l1_provider.clone());
+            let created_by_us = game.gameCreator().call().await? == self.signer.address();
+            if created_by_us && game.l2BlockNumber().call().await? == l2_block_number {
+                return Ok(Some(event.disputeProxy));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Computes the L2 block the next game would be created for and whether the finalized L2
+    /// head has reached it, without creating a game.
+    ///
+    /// Two cases based on the result of `get_latest_valid_proposal`:
+    /// 1. With existing valid proposal:
+    ///    - Block number = latest valid proposal's block + proposal interval.
+    ///    - Parent = latest valid game's index.
+    ///
+    /// 2. Without valid proposal (first game or all existing games being faulty):
+    ///    - Block number = anchor L2 block number + proposal interval.
+    ///    - Parent = u32::MAX (special value indicating no parent).
+    pub async fn next_proposal_plan(&self) -> Result<ProposalPlan> {
+        let latest_valid_proposal = self.latest_valid_proposal().await?;
+        let (latest_proposed_block_number, parent_game_index) = match latest_valid_proposal {
+            Some((latest_block, latest_game_idx)) => (latest_block, latest_game_idx.to::<u32>()),
+            None => {
+                let anchor_l2_block_number =
+                    self.factory.get_anchor_l2_block_number(self.config.game_type).await?;
+                tracing::info!("Anchor L2 block number: {:?}", anchor_l2_block_number);
+                (anchor_l2_block_number, u32::MAX)
+            }
+        };
+        let next_l2_block_number = latest_proposed_block_number
+            .checked_add(U256::from(self.config.proposal_interval_in_blocks))
+            .context("Next proposal block number overflows")?;
+
+        let finalized_l2_head_block_number = self
+            .host
+            .get_finalized_l2_block_number(&self.fetcher, latest_proposed_block_number.to::<u64>())
+            .await?;
+
+        // There's always a new game to propose, as the chain is always moving forward from the
+        // genesis block set for the game type. Only create a new game if the finalized L2
+        // head block number is greater than the next L2 block number for proposal.
+        let would_submit = finalized_l2_head_block_number
+            .is_some_and(|finalized_block| U256::from(finalized_block) > next_l2_block_number);
+
+        Ok(ProposalPlan {
+            latest_valid_proposal,
+            latest_proposed_block_number,
+            next_l2_block_number,
+            parent_game_index,
+            finalized_l2_head_block_number,
+            would_submit,
+        })
+    }
+
+    /// Handles the creation of a new game if conditions are met.
+    /// Returns the address of the created game, if one was created. A game another instance
+    /// sharing our signer already created for the next L2 block is returned and tracked as if it
+    /// was created here.
+    #[tracing::instrument(name = "[[Proposing]]", skip(self))]
+    pub async fn handle_game_creation(&self) -> Result<Option<Address>> {
+        let plan = self.next_proposal_plan().await?;
+        let next_l2_block_number_for_proposal = plan.next_l2_block_number;
+
+        if plan.finalized_l2_head_block_number.is_some() {
+            if plan.would_submit {
+                if !self.signer_allowed_to_propose().await? {
+                    SkipReason::NotWhitelisted.record(&self.metrics);
+                    return Ok(None);
+                }
+
+                if let Some(remaining) = self.min_proposal_interval_remaining().await? {
+                    tracing::debug!(
+                        "Minimum proposal interval not elapsed, skipping creation for {:?}",
+                        remaining
+                    );
+                    SkipReason::RateLimited.record(&self.metrics);
+                    return Ok(None);
+                }
+
+                if let Some(game_address) =
+                    self.detect_own_proposal_race(next_l2_block_number_for_proposal).await?
+                {
+                    tracing::warn!(
+                        "Game {:?} for L2 block {:?} was already created by this proposer, tracking it instead",
+                        game_address,
+                        next_l2_block_number_for_proposal
+                    );
+                    SkipReason::DuplicateProposal.record(&self.metrics);
+                    self.record_audit(AuditEntry::skipped(
+                        game_address,
+                        self.signer.address(),
+                        format!(
+                            "Game for L2 block {next_l2_block_number_for_proposal} already created by this proposer"
+                        ),
+                    ));
+                    self.track_created_game(game_address).await;
+                    return Ok(Some(game_address));
+                }
+
+                let result = self
+                    .create_game(next_l2_block_number_for_proposal, plan.parent_game_index)
+                    .await;
+                if result.is_err() {
+                    self.creation_failures
+                        .lock()
+                        .await
+                        .insert(next_l2_block_number_for_proposal, Instant::now());
+                }
+                result
+            } else {
+                tracing::info!("No new game to propose since proposal interval has not elapsed");
+                SkipReason::FinalityNotAdvanced.record(&self.metrics);
+
+                Ok(None)
+            }
+        } else {
+            tracing::info!("No new finalized block number found since last proposed block");
+            SkipReason::FinalityNotAdvanced.record(&self.metrics);
+            Ok(None)
+        }
+    }
+
+    /// Handles claiming bonds from resolved games.
+    #[tracing::instrument(name = "[[Claiming Bonds]]", skip(self))]
+    async fn handle_bond_claiming(&self) -> Result<Action> {
+        if let Some(game_address) = self
+            .factory
+            .get_oldest_claimable_bond_game_address(
+                self.config.game_type,
+                self.config.max_games_to_check_for_bond_claiming,
+                self.prover_address,
+            )
+            .await?
+        {
+            tracing::info!("Attempting to claim bond from game {:?}", game_address);
+
+            // Create a contract instance for the game
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+
+            // The credit is paid out to the address that accrued it, so it can only be forwarded
+            // to the bond recipient after the claim.
+            let credit = game.credit(self.prover_address).call().await?;
+
+            // Create a transaction to claim credit
+            let transaction_request =
+                game.claimCredit(self.prover_address).into_transaction_request();
+            if self.config.dry_run {
+                log_dry_run_transaction("claim bond", &transaction_request);
+                return Ok(Action::Performed);
+            }
+
+            // Sign and send the transaction
+            match send_with_retry(
+                &self.signer,
+                self.config.l1_rpc.clone(),
+                transaction_request,
+                &self.config.tx_retry_policy,
+                Mode::Proposer,
+                *self.factory.address(),
+                TxOperation::ClaimBond,
+            )
+            .await
+            {
+                Ok(receipt) => {
+                    tracing::info!(
+                        "\x1b[1mSuccessfully claimed bond from game {:?} with tx {:?}\x1b[0m",
+                        game_address,
+                        receipt.transaction_hash
+                    );
+                    self.record_audit(AuditEntry::performed(
+                        AuditAction::ClaimBond,
+                        game_address,
+                        self.signer.address(),
+                        &receipt,
+                    ));
+                    self.record_gas_spent(&receipt, TxOperation::ClaimBond).await;
+
+                    if let Some(recipient) = self.config.bond_recipient {
+                        let amount = claimed_bond_wei(
+                            &self.l1_provider,
+                            self.prover_address,
+                            &receipt,
+                            credit,
+                        )
+                        .await?;
+                        self.forward_claimed_bond(game_address, recipient, amount).await?;
+                    }
+
+                    Ok(Action::Performed)
+                }
+                Err(e) => {
+                    self.notify(
+                        NotificationEvent::BondClaimFailed,
+                        format!("Proposer failed to claim bond from game {game_address}: {e}"),
+                    );
+                    Err(anyhow::anyhow!(
+                        "Failed to claim bond from game {:?}: {:?}",
+                        game_address,
+                        e
+                    ))
+                }
+            }
+        } else {
+            tracing::info!("No new games to claim bonds from");
+
+            Ok(Action::Skipped)
+        }
+    }
+
+    /// Sends the bond claimed from a game from the signer to `recipient`.
+    async fn forward_claimed_bond(
+        &self,
+        game_address: Address,
+        recipient: Address,
+        amount: U256,
+    ) -> Result<()> {
+        let transaction_request = with_1559_fees(
+            &self.l1_provider,
+            TransactionRequest::default().to(recipient).value(amount),
+            self.config.l1_fee_percentile,
+            self.config.max_fee_per_gas_gwei,
+        )
+        .await?;
+        let receipt = send_with_retry(
+            &self.signer,
+            self.config.l1_rpc.clone(),
+            transaction_request,
+            &self.config.tx_retry_policy,
+            Mode::Proposer,
+            *self.factory.address(),
+            TxOperation::ForwardBond,
+        )
+        .await
+        .with_context(|| {
+            format!("Failed to forward bond claimed from game {game_address:?} to {recipient:?}")
+        })?;
+        tracing::info!(
+            "Forwarded bond of {} wei claimed from game {:?} to {:?} with tx {:?}",
+            amount,
+            game_address,
+            recipient,
+            receipt.transaction_hash
+        );
+        self.record_gas_spent(&receipt, TxOperation::ForwardBond).await;
+
+        Ok(())
+    }
+
+    /// Computes the ETH financial position of the proposer.
+    ///
+    /// Bonds and credit are only counted for the games within the bond claiming window.
+    pub async fn get_financial_summary(&self) -> Result<FinancialSummary> {
+        let signer_balance = self.l1_provider.get_balance(self.signer.address()).await?;
+
+        let mut bonds_in_active_proposals = U256::ZERO;
+        let mut unclaimed_credit = U256::ZERO;
+        if let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? {
+            let oldest_game_index = latest_game_index
+                .saturating_sub(U256::from(self.config.max_games_to_check_for_bond_claiming));
+            let mut index = oldest_game_index;
+            while index <= latest_game_index {
+                // The RPC answering this call may be behind the one that returned the game count.
+                let Some(game_address) =
+                    self.factory.try_fetch_game_address_by_index(index).await?
+                else {
+                    break;
+                };
+                let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+                if game.status().call().await? == GameStatus::IN_PROGRESS &&
+                    game.gameCreator().call().await? == self.signer.address()
+                {
+                    bonds_in_active_proposals += self.init_bond;
+                }
+                unclaimed_credit += game.credit(self.prover_address).call().await?;
+                index += U256::from(1);
+            }
+        }
+
+        let net_position = I256::try_from(signer_balance)? + I256::try_from(unclaimed_credit)? -
+            I256::try_from(bonds_in_active_proposals)?;
+
+        Ok(FinancialSummary {
+            signer_balance,
+            bonds_in_active_proposals,
+            unclaimed_credit,
+            total_gas_spent: *self.total_gas_spent_wei.lock().await,
+            net_position,
+        })
+    }
+
+    /// Fetch the proposer metrics.
+    ///
+    /// The metrics are updated in independent groups, which run concurrently so that a slow or
+    /// failing group doesn't keep the others from being updated.
+    async fn fetch_proposer_metrics(&self) -> Result<()> {
+        let (
+            block_numbers,
+            bonds_locked,
+            financial_summary,
+            resolved_games,
+            proposal_metrics,
+            by_status,
+            whitelist,
+        ) = tokio::join!(
+            self.update_block_number_metrics(),
+            self.update_bonds_locked_metrics(),
+            self.update_financial_metrics(),
+            self.check_resolved_game_outcomes(),
+            self.update_proposal_metrics(),
+            self.update_proposals_by_status(),
+            // Keep the whitelist status current even while no game is due.
+            async { self.signer_allowed_to_propose().await.map(|_| ()) },
+        );
+        for (group, result) in [
+            ("block number", block_numbers),
+            ("bonds locked", bonds_locked),
+            ("financial summary", financial_summary),
+            ("resolved game outcome", resolved_games),
+            ("per-proposal", proposal_metrics),
+            ("proposals by status", by_status),
+            ("whitelist status", whitelist),
+        ] {
+            if let Err(e) = result {
+                tracing::warn!("Failed to update {} metrics: {:?}", group, e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Updates the metrics for the L2 block numbers of the latest valid proposal, the finalized L2
+    /// head and the anchor game.
+    async fn update_block_number_metrics(&self) -> Result<()> {
+        // Get the latest valid proposal.
+        let latest_proposed_block_number = match self.latest_valid_proposal().await? {
+            Some((l2_block_number, _game_index)) => l2_block_number,
+            None => {
+                tracing::info!("No valid proposals found for metrics");
+                self.factory.get_anchor_l2_block_number(self.config.game_type).await?
+            }
+        };
+
+        // Update metrics for latest game block number.
+        self.metrics.set(
+            ProposerGauge::LatestGameL2BlockNumber,
+            latest_proposed_block_number.to::<u64>() as f64,
+        );
+
+        // Update metrics for finalized L2 block number.
+        if let Some(finalized_l2_block_number) = self
+            .host
+            .get_finalized_l2_block_number(&self.fetcher, latest_proposed_block_number.to::<u64>())
+            .await?
+        {
+            self.metrics
+                .set(ProposerGauge::FinalizedL2BlockNumber, finalized_l2_block_number as f64);
+        }
+
+        // Update metrics for anchor game block number.
+        let anchor_game_l2_block_number =
+            self.factory.get_anchor_l2_block_number(self.config.game_type).await?;
+        self.metrics.set(
+            ProposerGauge::AnchorGameL2BlockNumber,
+            anchor_game_l2_block_number.to::<u64>() as f64,
+        );
+        Ok(())
+    }
+
+    /// Updates the metrics for bonds locked in in-progress games.
+    async fn update_bonds_locked_metrics(&self) -> Result<()> {
+        let bond_escrow_state = self
+            .factory
+            .compute_total_bonds_locked(
+                self.config.game_type,
+                self.config.max_games_to_check_for_defense,
+                self.signer.address(),
+            )
+            .await?;
+        self.metrics.set(
+            ProposerGauge::TotalBondsLockedWei,
+            bond_escrow_state.total_locked.saturating_to::<u128>() as f64,
+        );
+        self.metrics.set(
+            ProposerGauge::BondsLockedWei,
+            bond_escrow_state.own_bonds_locked.saturating_to::<u128>() as f64,
+        );
+        Ok(())
+    }
+
+    /// Updates the metrics for the net ETH position and the signer balance of the proposer.
+    async fn update_financial_metrics(&self) -> Result<()> {
+        let financial_summary = self.get_financial_summary().await?;
+        self.metrics.set(
+            ProposerGauge::TotalNetPositionWei,
+            i128::try_from(financial_summary.net_position)? as f64,
+        );
+
+        // Update metrics for the signer balance, and warn before it gets too low to post bonds.
+        let signer_balance = financial_summary.signer_balance;
+        self.metrics
+            .set(ProposerGauge::WalletBalanceWei, signer_balance.saturating_to::<u128>() as f64);
+        if signer_balance < self.config.min_balance_wei {
+            tracing::warn!(
+                "Proposer balance of {} wei is below the minimum of {} wei",
+                signer_balance,
+                self.config.min_balance_wei
+            );
+            self.metrics.increment(ProposerGauge::LowBalance, 1.0);
+        }
+        Ok(())
+    }
+
+    /// Verifies the outcome of games resolved since the last check.
+    async fn check_resolved_game_outcomes(&self) -> Result<()> {
+        let discrepancies = self
+            .correctness_monitor
+            .lock()
+            .await
+            .check_resolved_games(
+                &self.factory,
+                self.config.max_games_to_check_for_defense,
+                &self.l1_provider,
+                &self.l2_provider,
+            )
+            .await?;
+        for (_, discrepancy) in discrepancies {
+            if discrepancy == ResolutionDiscrepancy::SpuriousDefenderWin {
+                self.metrics.increment(ProposerGauge::SpuriousDefenderWins, 1.0);
+            }
+        }
+        Ok(())
+    }
+
+    /// Updates the per-proposal metrics for in-progress games.
+    async fn update_proposal_metrics(&self) -> Result<()> {
+        let proposal_metrics = self.fetch_proposal_metrics().await?;
+        self.proposal_metrics.lock().await.update(*self.factory.address(), proposal_metrics);
+        Ok(())
+    }
+
+    /// Renders the per-proposal metrics of the last metrics update in the Prometheus text format.
+    pub async fn render_proposal_metrics(&self) -> String {
+        self.proposal_metrics.lock().await.render()
+    }
+
+    /// Counts the recent games by proposal status, within the last
+    /// `max_games_to_count_by_status` games, so that a backlog of e.g. challenged games that
+    /// aren't being proven shows up in the metrics.
+    async fn update_proposals_by_status(&self) -> Result<()> {
+        let mut counts = [
+            (ProposalStatus::Unchallenged, 0),
+            (ProposalStatus::Challenged, 0),
+            (ProposalStatus::UnchallengedAndValidProofProvided, 0),
+            (ProposalStatus::ChallengedAndValidProofProvided, 0),
+            (ProposalStatus::Resolved, 0),
+        ];
+
+        if let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? {
+            let oldest_game_index = latest_game_index
+                .saturating_sub(U256::from(self.config.max_games_to_count_by_status));
+            let game_indices = game_indices_between(oldest_game_index, latest_game_index);
+            let game_addresses = self
+                .factory
+                .fetch_game_addresses_by_index(&game_indices, self.config.game_fetch_batch_size)
+                .await?;
+
+            for game_address in game_addresses {
+                let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+                let status = game.claimData().call().await?.status;
+                if let Some((_, count)) = counts.iter_mut().find(|(s, _)| *s == status) {
+                    *count += 1;
+                }
+            }
+        }
+
+        for (status, count) in counts {
+            self.metrics.record_proposals_by_status(status, count);
+        }
+
+        Ok(())
+    }
+
+    /// Fetch per-proposal metrics for the in-progress games within the defense window.
+    ///
+    /// Also updates the time until the prove deadline of the defensible game closest to it, and
+    /// warns when it drops below `deadline_alert_threshold_secs`.
+    async fn fetch_proposal_metrics(&self) -> Result<Vec<ProposalMetrics>> {
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            return Ok(Vec::new());
+        };
+
+        let current_timestamp = self
+            .l2_provider
+            .get_l2_block_by_number(BlockNumberOrTag::Latest)
+            .await?
+            .header
+            .timestamp;
+
+        let oldest_game_index = latest_game_index
+            .saturating_sub(U256::from(self.config.max_games_to_check_for_defense));
+        let game_indices = game_indices_between(oldest_game_index, latest_game_index);
+        let game_addresses = self
+            .factory
+            .fetch_game_addresses_by_index(&game_indices, self.config.game_fetch_batch_size)
+            .await?;
+
+        let mut proposal_metrics = Vec::new();
+        let mut closest_prove_deadline: Option<(Address, u64)> = None;
+        for (game_index, game_address) in game_indices.into_iter().zip(game_addresses) {
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+
+            if game.status().call().await? == GameStatus::IN_PROGRESS {
+                let claim_data = game.claimData().call().await?;
+                let deadline = U256::from(claim_data.deadline).to::<u64>();
+
+                // Only challenged games with a correct claim are defended.
+                if claim_data.status == ProposalStatus::Challenged &&
+                    deadline >= current_timestamp &&
+                    closest_prove_deadline.is_none_or(|(_, closest)| deadline < closest)
+                {
+                    let output_root = self
+                        .l2_provider
+                        .compute_output_root_at_block_timed(
+                            game.l2BlockNumber().call().await?,
+                            Mode::Proposer,
+                            *self.factory.address(),
+                        )
+                        .await?;
+                    if output_root == game.rootClaim().call().await? {
+                        closest_prove_deadline = Some((game_address, deadline));
+                    }
+                }
+
+                let bond_at_risk = self.l1_provider.get_balance(game_address).await?;
+
+                proposal_metrics.push(
+                    ProposalMetrics::new(game_index)
+                        .with_label("factory", self.factory.address())
+                        .with_gauge(
+                            PROPOSAL_TIME_REMAINING_SECS,
+                            deadline.saturating_sub(current_timestamp) as f64,
+                        )
+                        .with_gauge(PROPOSAL_STATUS, u8::from(claim_data.status) as f64)
+                        .with_gauge(
+                            PROPOSAL_BOND_AT_RISK,
+                            bond_at_risk.saturating_to::<u128>() as f64,
+                        ),
+                );
+            }
+        }
+
+        match closest_prove_deadline {
+            Some((game_address, deadline)) => {
+                let secs_until_deadline = deadline - current_timestamp;
+                self.metrics.set(ProposerGauge::SecondsUntilDeadline, secs_until_deadline as f64);
+                if secs_until_deadline < self.config.deadline_alert_threshold_secs {
+                    tracing::warn!(
+                        "Challenged game {:?} is {} seconds from its prove deadline and still isn't proven",
+                        game_address,
+                        secs_until_deadline
+                    );
+                }
+            }
+            None => self.metrics.set(ProposerGauge::SecondsUntilDeadline, -1.0),
+        }
+
+        Ok(proposal_metrics)
+    }
+
+    /// Computes the output root to propose for the given L2 block, verified according to the
+    /// configured output root verification mode.
+    ///
+    /// Returns the output root and the hash of the L2 block it was computed for.
+    async fn compute_output_root_for_proposal(
+        &self,
+        l2_block_number: U256,
+    ) -> Result<(B256, B256)> {
+        let block = self
+            .l2_provider
+            .get_l2_block_by_number(BlockNumberOrTag::Number(l2_block_number.to::<u64>()))
+            .await?;
+        let output_root = match self.config.output_root_verification_mode {
+            OutputRootVerificationMode::Full => {
+                self.l2_provider
+                    .compute_output_root_at_block_timed(
+                        l2_block_number,
+                        Mode::Proposer,
+                        *self.factory.address(),
+                    )
+                    .await?
+            }
+            OutputRootVerificationMode::BlockHashOnly => {
+                let output =
+                    self.fetcher.get_l2_output_at_block(l2_block_number.to::<u64>()).await?;
+                if output.block_ref.block_info.hash != block.header.hash {
+                    bail!(
+                        "Rollup node block hash {:?} does not match L2 block hash {:?} at block {}",
+                        output.block_ref.block_info.hash,
+                        block.header.hash,
+                        l2_block_number
+                    );
+                }
+                output.output_root
+            }
+            #[cfg(feature = "testing")]
+            OutputRootVerificationMode::Skip => {
+                self.fetcher.get_l2_output_at_block(l2_block_number.to::<u64>()).await?.output_root
+            }
+        };
+        Ok((output_root, block.header.hash))
+    }
+
+    /// Re-fetches the L2 block at `l2_block_number` and checks that its hash is still
+    /// `l2_block_hash`, the hash of the block the output root was computed for.
+    ///
+    /// A reorg between computing the output root and submitting the game would otherwise create a
+    /// game with an invalid claim, which the proposer would then lose its bond on.
+    async fn verify_l2_block_hash_unchanged(
+        &self,
+        l2_block_number: U256,
+        l2_block_hash: B256,
+    ) -> Result<()> {
+        let block = self
+            .l2_provider
+            .get_l2_block_by_number(BlockNumberOrTag::Number(l2_block_number.to::<u64>()))
+            .await?;
+        if block.header.hash != l2_block_hash {
+            self.metrics.increment(ProposerGauge::ReorgDetected, 1.0);
+            bail!(
+                "L2 reorg detected at block {}: output root was computed for block hash {:?}, but the canonical block hash is now {:?}",
+                l2_block_number,
+                l2_block_hash,
+                block.header.hash
+            );
+        }
+        Ok(())
+    }
+
+    /// Finds the longest chain of resolvable games starting at `from_index`, where each game's
+    /// parent is the previous game in the chain.
+    ///
+    /// A game is resolvable if it is in progress and over with the defender winning: either its
+    /// deadline passed unchallenged at `current_timestamp`, or a valid proof was provided. The
+    /// first game's parent must already be resolved. Games after `latest_game_index` aren't
+    /// checked.
+    pub async fn get_sequential_resolvable_batch(
+        &self,
+        from_index: U256,
+        latest_game_index: U256,
+        current_timestamp: u64,
+        max_batch: usize,
+    ) -> Result<Vec<U256>> {
+        let mut batch = Vec::new();
+        let mut index = from_index;
+        while index <= latest_game_index && batch.len() < max_batch {
+            // The RPC answering this call may be behind the one that returned the game count.
+            let Some(game_address) = self.factory.try_fetch_game_address_by_index(index).await?
+            else {
+                break;
+            };
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            if game.status().call().await? != GameStatus::IN_PROGRESS {
+                break;
+            }
+
+            let claim_data = game.claimData().call().await?;
+            let is_game_over = match claim_data.status {
+                ProposalStatus::Unchallenged => claim_data.deadline < current_timestamp,
+                ProposalStatus::UnchallengedAndValidProofProvided |
+                ProposalStatus::ChallengedAndValidProofProvided => true,
+                _ => false,
+            };
+            if !is_game_over {
+                break;
+            }
+
+            let parent_index = claim_data.parentIndex;
+            let is_parent_resolved = match batch.last() {
+                Some(previous_index) => U256::from(parent_index) == *previous_index,
+                None if parent_index == u32::MAX => true,
+                None => {
+                    let parent_game_address =
+                        self.factory.fetch_game_address_by_index(U256::from(parent_index)).await?;
+                    let parent_game = OPSuccinctFaultDisputeGame::new(
+                        parent_game_address,
+                        self.l1_provider.clone(),
+                    );
+                    parent_game.status().call().await? != GameStatus::IN_PROGRESS
+                }
+            };
+            if !is_parent_resolved {
+                break;
+            }
+
+            batch.push(index);
+            index += U256::from(1);
+        }
+
+        Ok(batch)
+    }
+
+    /// Returns the timestamp of the latest L2 block, against which game deadlines are checked.
+    async fn latest_l2_timestamp(&self) -> Result<u64> {
+        Ok(self
+            .l2_provider
+            .get_l2_block_by_number(BlockNumberOrTag::Latest)
+            .await?
+            .header
+            .timestamp)
+    }
+
+    /// Resolves games within the resolution window, in batches of sequential resolvable games.
+    ///
+    /// The games of a batch are resolved in nonce order, so that each game's parent is resolved
+    /// right before it.
+    #[tracing::instrument(name = "[[Resolving]]", skip(self))]
+    async fn handle_game_resolution(&self) -> Result<()> {
+        let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+            tracing::info!("No games exist, skipping resolution");
+            return Ok(());
+        };
+
+        let oldest_game_index = latest_game_index
+            .saturating_sub(U256::from(self.config.max_games_to_check_for_resolution));
+        self.resolve_games_between(oldest_game_index, latest_game_index).await?;
+        Ok(())
+    }
+
+    /// Resolves every resolvable game, repeating passes over all games until a pass resolves
+    /// none, e.g. to clear the backlog after resolution was disabled. Returns the number of games
+    /// resolved.
+    pub async fn resolve_all(&self) -> Result<u32> {
+        let mut total_resolved = 0;
+        loop {
+            let Some(latest_game_index) = self.factory.fetch_latest_game_index().await? else {
+                return Ok(total_resolved);
+            };
+
+            let resolved = self.resolve_games_between(U256::ZERO, latest_game_index).await?;
+            tracing::info!("Resolved {} games in this pass", resolved);
+            total_resolved += resolved;
+
+            // In dry run mode nothing is resolved on-chain, so another pass would find the same
+            // games.
+            if resolved == 0 || self.config.dry_run {
+                return Ok(total_resolved);
+            }
+        }
+    }
+
+    /// Resolves the resolvable games between `oldest_game_index` and `latest_game_index`, in
+    /// sequential batches so that parents are resolved before their children. Returns the number
+    /// of games resolved.
+    async fn resolve_games_between(
+        &self,
+        oldest_game_index: U256,
+        latest_game_index: U256,
+    ) -> Result<u32> {
+        let mut index = oldest_game_index;
+
+        // No game in the window is resolvable while the parent of the oldest one is in progress,
+        // so skip the per-game checks.
+        let (should_attempt_resolution, oldest_game_address) =
+            self.factory.should_attempt_resolution(index).await?;
+        if !should_attempt_resolution {
+            tracing::debug!(
+                "Oldest game {:?} at index {:?} has an unresolved parent, skipping resolution",
+                oldest_game_address,
+                index
+            );
+            return Ok(0);
+        }
+
+        let current_timestamp = self.latest_l2_timestamp().await?;
+        let mut resolved = 0;
+        while index <= latest_game_index {
+            let batch = self
+                .get_sequential_resolvable_batch(
+                    index,
+                    latest_game_index,
+                    current_timestamp,
+                    MAX_SEQUENTIAL_RESOLUTION_BATCH_SIZE,
+                )
+                .await?;
+            if batch.is_empty() {
+                index += U256::from(1);
+                continue;
+            }
+
+            self.metrics
+                .record(ProposerHistogram::SequentialResolutionBatchSize, batch.len() as f64);
+            resolved += self.resolve_batch(&batch).await?;
+
+            index += U256::from(batch.len());
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves a batch of sequential games, sending the resolve transactions in nonce order
+    /// without waiting for each to be confirmed before sending the next. A game that fails to
+    /// resolve is logged, and the later games of the batch are still attempted. Returns the
+    /// number of games resolved.
+    async fn resolve_batch(&self, game_indices: &[U256]) -> Result<u32> {
+        let mut game_addresses = Vec::with_capacity(game_indices.len());
+        let mut transaction_requests = Vec::with_capacity(game_indices.len());
+        for game_index in game_indices {
+            let game_address = self.factory.fetch_game_address_by_index(*game_index).await?;
+            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+            let transaction_request = game.resolve().into_transaction_request();
+            if self.config.dry_run {
+                log_dry_run_transaction("resolve", &transaction_request);
+            }
+            game_addresses.push(game_address);
+            transaction_requests.push(transaction_request);
+        }
+        if self.config.dry_run {
+            return Ok(game_indices.len() as u32);
+        }
+
+        let fees = with_1559_fees(
+            &self.l1_provider,
+            TransactionRequest::default(),
+            self.config.l1_fee_percentile,
+            self.config.max_fee_per_gas_gwei,
+        )
+        .await?;
+        let estimated_gas = self
+            .l1_provider
+            .estimate_gas(transaction_requests[0].clone().from(self.signer.address()))
+            .await?;
+        let gas_limit = estimated_gas * RESOLVE_GAS_LIMIT_PERCENT / 100;
+        let transaction_requests = transaction_requests
+            .into_iter()
+            .map(|transaction_request| TransactionRequest {
+                gas: Some(gas_limit),
+                max_fee_per_gas: fees.max_fee_per_gas,
+                max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+                ..transaction_request
+            })
+            .collect();
+
+        let results = send_pipelined_with_retry(
+            &self.signer,
+            self.config.l1_rpc.clone(),
+            transaction_requests,
+            &self.config.tx_retry_policy,
+            Mode::Proposer,
+            *self.factory.address(),
+            TxOperation::Resolve,
+        )
+        .await?;
+
+        let mut resolved = 0;
+        for ((game_index, game_address), result) in
+            game_indices.iter().zip(game_addresses).zip(results)
+        {
+            match result {
+                Ok(receipt) => {
+                    self.record_resolution(*game_index, game_address, &receipt).await;
+                    resolved += 1;
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to resolve game {:?} at index {:?}: {:?}",
+                        game_address,
+                        game_index,
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves the game at `game_index` and returns its address.
+    async fn resolve_game(&self, game_index: U256) -> Result<Address> {
+        let game_address = self.factory.fetch_game_address_by_index(game_index).await?;
+        let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        let transaction_request = game.resolve().into_transaction_request();
+        if self.config.dry_run {
+            log_dry_run_transaction("resolve", &transaction_request);
+            return Ok(game_address);
+        }
+
+        let transaction_request = with_1559_fees(
+            &self.l1_provider,
+            transaction_request,
+            self.config.l1_fee_percentile,
+            self.config.max_fee_per_gas_gwei,
+        )
+        .await?;
+        let receipt = send_with_retry(
+            &self.signer,
+            self.config.l1_rpc.clone(),
+            transaction_request,
+            &self.config.tx_retry_policy,
+            Mode::Proposer,
+            *self.factory.address(),
+            TxOperation::Resolve,
+        )
+        .await?;
+        self.record_resolution(game_index, game_address, &receipt).await;
+
+        Ok(game_address)
+    }
+
+    /// Records the resolution of the game at `game_index` by the transaction of `receipt`.
+    async fn record_resolution(
+        &self,
+        game_index: U256,
+        game_address: Address,
+        receipt: &TransactionReceipt,
+    ) {
+        tracing::info!(
+            "\x1b[1mSuccessfully resolved game {:?} at index {:?} with tx {:?}\x1b[0m",
+            game_address,
+            game_index,
+            receipt.transaction_hash
+        );
+        self.metrics.increment(ProposerGauge::GamesResolved, 1.0);
+        self.record_audit(AuditEntry::performed(
+            AuditAction::Resolve,
+            game_address,
+            self.signer.address(),
+            receipt,
+        ));
+        self.notify(
+            NotificationEvent::GameResolved,
+            format!("Proposer resolved game {game_address} with tx {}", receipt.transaction_hash),
+        );
+        self.record_gas_spent(receipt, TxOperation::Resolve).await;
+    }
+
+    /// Creates `count` games in succession, proves them in mock mode, then resolves them once
+    /// they are resolvable.
+    ///
+    /// Each game is created as soon as finality allows. Proving is skipped outside of mock mode,
+    /// so that load testing does not use the Succinct Prover Network, and the games are resolved
+    /// after their challenge period instead. Games that aren't resolved within `resolve_timeout`
+    /// after the last game was created are reported as unresolved.
+    pub async fn stress_test(
+        &self,
+        count: u32,
+        resolve_timeout: Duration,
+    ) -> Result<StressTestReport> {
+        if self.config.dry_run {
+            bail!("The stress test sends transactions and cannot run in dry run mode");
+        }
+
+        let gas_spent_before = *self.total_gas_spent_wei.lock().await;
+        let poll_interval = Duration::from_secs(self.config.fetch_interval);
+
+        let mut first_game_index = None;
+        let mut pending_games = HashSet::new();
+        let mut create_latencies = Vec::new();
+        let mut prove_latencies = Vec::new();
+        while create_latencies.len() < count as usize {
+            let create_start = Instant::now();
+            let Some(game_address) = self.handle_game_creation().await? else {
+                time::sleep(poll_interval).await;
+                continue;
+            };
+            create_latencies.push(create_start.elapsed());
+            self.metrics.increment(ProposerGauge::GamesCreated, 1.0);
+            if first_game_index.is_none() {
+                first_game_index = self.factory.fetch_latest_game_index().await?;
+            }
+            pending_games.insert(game_address);
+
+            if self.config.mock_mode {
+                let prove_start = Instant::now();
+                self.prove_game(game_address).await?;
+                prove_latencies.push(prove_start.elapsed());
+            }
+        }
+
+        let mut resolve_latencies = Vec::new();
+        let mut index = first_game_index.unwrap_or_default();
+        let resolve_deadline = Instant::now() + resolve_timeout;
+        while !pending_games.is_empty() {
+            if Instant::now() >= resolve_deadline {
+                tracing::warn!(
+                    "{} games were not resolved within {:?}",
+                    pending_games.len(),
+                    resolve_timeout
+                );
+                break;
+            }
+
+            let latest_game_index =
+                self.factory.fetch_latest_game_index().await?.unwrap_or_default();
+            let current_timestamp = self.latest_l2_timestamp().await?;
+            let batch = self
+                .get_sequential_resolvable_batch(
+                    index,
+                    latest_game_index,
+                    current_timestamp,
+                    MAX_SEQUENTIAL_RESOLUTION_BATCH_SIZE,
+                )
+                .await?;
+            if batch.is_empty() {
+                time::sleep(poll_interval).await;
+                continue;
+            }
+
+            for game_index in &batch {
+                let resolve_start = Instant::now();
+                let game_address = self.resolve_game(*game_index).await?;
+                if pending_games.remove(&game_address) {
+                    resolve_latencies.push(resolve_start.elapsed());
+                }
+            }
+            index += U256::from(batch.len());
+        }
+
+        Ok(StressTestReport {
+            proposals_created: count,
+            avg_create_latency: average_duration(&create_latencies),
+            avg_prove_latency: (!prove_latencies.is_empty())
+                .then(|| average_duration(&prove_latencies)),
+            avg_resolve_latency: average_duration(&resolve_latencies),
+            unresolved_games: pending_games.into_iter().collect(),
+            total_gas_spent_wei: *self.total_gas_spent_wei.lock().await - gas_spent_before,
+        })
+    }
+
+    /// Ranks all proposers of this game type by the number of games they created since
+    /// `from_block`.
+    ///
+    /// Bond outcomes are computed with the current init and challenger bonds, so they are
+    /// approximate if the bonds changed since `from_block`.
+    pub async fn get_proposer_leaderboard(&self, from_block: u64) -> Result<Vec<ProposerStats>> {
+        let to_block = self.l1_provider.get_block_number().await?;
+        let creation_filter = Filter::new()
+            .address(*self.factory.address())
+            .event_signature(DisputeGameCreated::SIGNATURE_HASH)
+            .topic2(B256::from(U256::from(self.config.game_type)));
+
+        let mut game_creators = HashMap::new();
+        for log in get_logs_paged(&self.l1_provider, &creation_filter, from_block, to_block).await?
+        {
+            let Ok(event) = DisputeGameCreated::decode_log(&log.inner) else {
+                continue;
+            };
+            let game =
+                OPSuccinctFaultDisputeGame::new(event.disputeProxy, self.l1_provider.clone());
+            game_creators.insert(event.disputeProxy, game.gameCreator().call().await?);
+        }
+
+        let mut stats: HashMap<Address, ProposerStats> = HashMap::new();
+        for creator in game_creators.values() {
+            let entry = stats
+                .entry(*creator)
+                .or_insert_with(|| ProposerStats { address: *creator, ..Default::default() });
+            entry.proposals_submitted += 1;
+        }
+
+        if game_creators.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Only the events of the games created by the factory count.
+        let game_filter = Filter::new()
+            .address(game_creators.keys().copied().collect::<Vec<_>>())
+            .event_signature(vec![Challenged::SIGNATURE_HASH, Resolved::SIGNATURE_HASH]);
+        let init_bond = I256::try_from(self.init_bond)?;
+        let challenger_bond =
+            I256::try_from(self.factory.fetch_challenger_bond(self.config.game_type).await?)?;
+
+        for log in get_logs_paged(&self.l1_provider, &game_filter, from_block, to_block).await? {
+            let Some(creator) = game_creators.get(&log.address()) else {
+                continue;
+            };
+            let Some(entry) = stats.get_mut(creator) else {
+                continue;
+            };
+
+            if Challenged::decode_log(&log.inner).is_ok() {
+                entry.proposals_challenged += 1;
+            } else if let Ok(event) = Resolved::decode_log(&log.inner) {
+                entry.proposals_resolved += 1;
+                match event.status {
+                    GameStatus::CHALLENGER_WINS => {
+                        entry.proposals_lost += 1;
+                        entry.net_bond_outcome_wei -= init_bond;
+                    }
+                    GameStatus::DEFENDER_WINS => {
+                        // The challenger's bond goes to the prover, which is only the proposer
+                        // if it proved its own game.
+                        let game = OPSuccinctFaultDisputeGame::new(
+                            log.address(),
+                            self.l1_provider.clone(),
+                        );
+                        let claim_data = game.claimData().call().await?;
+                        if claim_data.counteredBy != Address::ZERO && claim_data.prover == *creator
+                        {
+                            entry.net_bond_outcome_wei += challenger_bond;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut leaderboard: Vec<ProposerStats> = stats.into_values().collect();
+        leaderboard.sort_by(|a, b| b.proposals_submitted.cmp(&a.proposals_submitted));
+        Ok(leaderboard)
+    }
+
+    /// Estimates the maximum L2 throughput the fault proof system can support with the current
+    /// configuration.
+    ///
+    /// Each game covers `proposal_interval_in_blocks` L2 blocks, so the throughput is bounded by
+    /// the slowest of: generating a proof per game, confirming a game creation transaction on L1,
+    /// and creating at most one game per tick. Without observed proofs, the proof generation time
+    /// is assumed to take the full prove window.
+    pub async fn compute_theoretical_max_tps(&self) -> Result<ThroughputReport> {
+        let game_impl_address = self.factory.gameImpls(self.config.game_type).call().await?;
+        let game_impl =
+            OPSuccinctFaultDisputeGame::new(game_impl_address, self.l1_provider.clone());
+        let max_prove_secs = game_impl.maxProveDuration().call().await?;
+
+        let proof_secs = self.proof_timing.lock().await.ewma_secs.unwrap_or(max_prove_secs as f64);
+        let games_per_day_by_proofs = (SECS_PER_DAY as f64 / proof_secs.max(1.0)) as u64;
+        let num_confirmations = self.config.tx_retry_policy.num_confirmations.max(1);
+        let games_per_day_by_l1 = SECS_PER_DAY / (L1_BLOCK_TIME_SECS * num_confirmations);
+        let games_per_day_by_interval = SECS_PER_DAY / self.config.fetch_interval.max(1);
+
+        let (games_per_day, bottleneck) = [
+            (games_per_day_by_proofs, Bottleneck::ProofGeneration),
+            (games_per_day_by_l1, Bottleneck::L1Finality),
+            (games_per_day_by_interval, Bottleneck::ProposalInterval),
+        ]
+        .into_iter()
+        .min_by_key(|(games_per_day, _)| *games_per_day)
+        .unwrap();
+        let max_l2_blocks_per_day = games_per_day * self.config.proposal_interval_in_blocks;
+
+        // Estimate the average transaction density from recent L2 blocks.
+        let latest_block_number = self.l2_provider.get_block_number().await?;
+        let mut sampled_txs = 0;
+        for block_number in
+            latest_block_number.saturating_sub(TX_DENSITY_SAMPLE_BLOCKS - 1)..=latest_block_number
+        {
+            let block = self
+                .l2_provider
+                .get_l2_block_by_number(BlockNumberOrTag::Number(block_number))
+                .await?;
+            sampled_txs += block.transactions.len() as u64;
+        }
+        let max_txs_per_day = max_l2_blocks_per_day * sampled_txs / TX_DENSITY_SAMPLE_BLOCKS;
+
+        Ok(ThroughputReport { max_l2_blocks_per_day, max_txs_per_day, bottleneck })
+    }
+
+    /// Checks whether the proposer can be restarted with new range and aggregation verification
+    /// keys.
+    ///
+    /// Games created before the upgrade must be proven with the current circuits, so the upgrade
+    /// has to wait while proofs are in flight or challenged games still need to be defended.
+    pub async fn check_circuit_upgrade_readiness(
+        &self,
+        new_range_vk: B256,
+        new_agg_vk: B256,
+    ) -> Result<UpgradeReadinessReport> {
+        let current_range_vk = B256::from(u32_to_u8(self.prover.range_vk.vk.hash_u32()));
+        let current_agg_vk = B256::from_str(&self.prover.agg_pk.vk.bytes32())?;
+
+        let has_active_proving_tasks = self
+            .has_active_task_of_type(&TaskInfo::GameProving { game_address: Address::ZERO })
+            .await;
+        let defensible_game = self
+            .factory
+            .get_oldest_defensible_game_address(
+                self.config.max_games_to_check_for_defense,
+                self.config.game_fetch_batch_size,
+                self.config.scan_concurrency,
+                self.caching_l2_provider.clone(),
+            )
+            .await?;
+        let has_pending_proofs = has_active_proving_tasks || defensible_game.is_some();
+
+        let reason = if has_active_proving_tasks {
+            "Proofs are being generated with the current circuits".to_string()
+        } else if let Some(game_address) = defensible_game {
+            format!(
+                "Challenged game {game_address:?} still needs a proof from the current circuits"
+            )
+        } else if current_range_vk == new_range_vk && current_agg_vk == new_agg_vk {
+            "The new verification keys match the current ones".to_string()
+        } else {
+            "No proofs are pending".to_string()
+        };
+
+        Ok(UpgradeReadinessReport {
+            current_range_vk,
+            new_range_vk,
+            current_agg_vk,
+            new_agg_vk,
+            has_pending_proofs,
+            can_upgrade_now: !has_pending_proofs,
+            reason,
+        })
+    }
+
+    /// Estimates how many days the proposer must operate to recoup `deployment_cost_wei`.
+    ///
+    /// Each proposal is assumed to earn half of the init bond, at the rate of one proposal per
+    /// `proposal_interval_in_blocks`. The daily gas cost is the gas spent by this proposer since
+    /// it was started, divided by its uptime in days.
+    pub async fn compute_break_even_period(
+        &self,
+        deployment_cost_wei: U256,
+    ) -> Result<BreakEvenReport> {
+        let l2_block_time =
+            self.fetcher.rollup_config.as_ref().map_or(2, |config| config.block_time);
+        let proposals_per_day =
+            SECS_PER_DAY / (self.config.proposal_interval_in_blocks * l2_block_time).max(1);
+        let expected_daily_bond_earnings_wei =
+            self.init_bond / U256::from(2) * U256::from(proposals_per_day);
+
+        let uptime_secs = self.started_at.elapsed().as_secs().max(1);
+        let total_gas_spent_wei = *self.total_gas_spent_wei.lock().await;
+        let expected_daily_gas_cost_wei =
+            total_gas_spent_wei * U256::from(SECS_PER_DAY) / U256::from(uptime_secs);
+
+        let break_even_days = (expected_daily_bond_earnings_wei > expected_daily_gas_cost_wei)
+            .then(|| {
+                let daily_profit_wei =
+                    expected_daily_bond_earnings_wei - expected_daily_gas_cost_wei;
+                deployment_cost_wei.saturating_to::<u128>() as f64
+                    / daily_profit_wei.saturating_to::<u128>() as f64
+            });
+
+        Ok(BreakEvenReport {
+            deployment_cost_wei,
+            expected_daily_gas_cost_wei,
+            expected_daily_bond_earnings_wei,
+            break_even_days,
+        })
+    }
+
+    /// Benchmarks witness generation for the L2 block range `[l2_start, l2_end]`.
+    ///
+    /// Runs the host pipeline and executes the range program locally to estimate its cycle count.
+    /// Nothing is submitted to the prover network.
+    pub async fn benchmark_witness_generation(
+        &self,
+        l2_start: u64,
+        l2_end: u64,
+    ) -> Result<WitnessGenBenchmark> {
+        let fetch_start = Instant::now();
+        let host_args = self
+            .host
+            .fetch(l2_start, l2_end, None, self.config.safe_db_fallback)
+            .await
+            .context("Failed to get host CLI args")?;
+        let fetch_duration = fetch_start.elapsed();
 
-        let transaction_request = game.prove(agg_proof.bytes().into()).into_transaction_request();
+        let run_start = Instant::now();
+        let witness_data = self.host.run(&host_args).await?;
+        let run_duration = run_start.elapsed();
 
-        let receipt = self
-            .signer
-            .send_transaction_request(self.config.l1_rpc.clone(), transaction_request)
-            .await?;
+        let sp1_stdin = self.host.witness_generator().get_sp1_stdin(witness_data)?;
+        let (_, report) =
+            self.prover.network_prover.execute(get_range_elf_embedded(), &sp1_stdin).run()?;
 
-        Ok(receipt.transaction_hash)
+        Ok(WitnessGenBenchmark {
+            fetch_duration,
+            run_duration,
+            total_duration: fetch_duration + run_duration,
+            estimated_cycles: report.total_instruction_count(),
+        })
     }
 
-    /// Creates a new game with the given parameters.
-    ///
-    /// `l2_block_number`: the L2 block number we are proposing the output root for.
-    /// `parent_game_index`: the index of the parent game.
-    pub async fn create_game(
-        &self,
-        l2_block_number: U256,
-        parent_game_index: u32,
-    ) -> Result<Address> {
-        tracing::info!(
-            "Creating game at L2 block number: {:?}, with parent game index: {:?}",
-            l2_block_number,
-            parent_game_index
-        );
-
-        let extra_data = <(U256, u32)>::abi_encode_packed(&(l2_block_number, parent_game_index));
-
-        let transaction_request = self
-            .factory
-            .create(
-                self.config.game_type,
-                self.l2_provider.compute_output_root_at_block(l2_block_number).await?,
-                extra_data.into(),
-            )
-            .value(self.init_bond)
-            .into_transaction_request();
+    /// Checks that the verification keys of the embedded ELFs match the `aggregation_vkey` and
+    /// `range_vkey_commitment` the game was deployed with, as proofs from other ELFs fail to
+    /// verify. Keys that aren't configured aren't checked.
+    pub fn verify_aggregation_elf_compatibility(&self) -> Result<ElfCompatibilityReport> {
+        let elf_aggregation_vkey = B256::from_str(&self.prover.agg_pk.vk.bytes32())?;
+        let elf_range_vkey_commitment = B256::from(u32_to_u8(self.prover.range_vk.vk.hash_u32()));
+        let deployed_aggregation_vkey = self.config.aggregation_vkey;
+        let deployed_range_vkey_commitment = self.config.range_vkey_commitment;
+
+        if deployed_aggregation_vkey.is_none() && deployed_range_vkey_commitment.is_none() {
+            tracing::info!(
+                "AGGREGATION_VKEY and RANGE_VKEY_COMMITMENT not set, not checking the embedded ELFs"
+            );
+        }
+        let compatible = deployed_aggregation_vkey.is_none_or(|vkey| vkey == elf_aggregation_vkey) &&
+            deployed_range_vkey_commitment
+                .is_none_or(|commitment| commitment == elf_range_vkey_commitment);
+
+        Ok(ElfCompatibilityReport {
+            elf_aggregation_vkey,
+            deployed_aggregation_vkey,
+            elf_range_vkey_commitment,
+            deployed_range_vkey_commitment,
+            compatible,
+        })
+    }
 
-        let receipt = self
-            .signer
-            .send_transaction_request(self.config.l1_rpc.clone(), transaction_request)
-            .await?;
+    /// Checks whether the L1 RPC reports the expected L1 chain ID.
+    ///
+    /// Always returns true when no expected L1 chain ID is configured.
+    pub async fn verify_l1_chain_id(&self) -> Result<bool> {
+        let Some(expected_chain_id) = self.config.expected_l1_chain_id else {
+            return Ok(true);
+        };
 
-        let game_address = receipt
-            .inner
-            .logs()
-            .iter()
-            .find_map(|log| {
-                DisputeGameCreated::decode_log(&log.inner).ok().map(|event| event.disputeProxy)
-            })
-            .context("Could not find DisputeGameCreated event in transaction receipt logs")?;
+        let chain_id = self.l1_provider.get_chain_id().await?;
+        if chain_id != expected_chain_id {
+            tracing::error!(
+                "L1 chain ID mismatch: expected {}, L1 RPC reports {}",
+                expected_chain_id,
+                chain_id
+            );
+            return Ok(false);
+        }
 
-        tracing::info!(
-            "\x1b[1mNew game at address {:?} created with tx {:?}\x1b[0m",
-            game_address,
-            receipt.transaction_hash
-        );
+        Ok(true)
+    }
 
-        if self.config.fast_finality_mode {
-            tracing::info!("Fast finality mode enabled: Spawning proof generation task");
+    /// Checks whether the L2 RPC reports the expected L2 chain ID.
+    ///
+    /// If the L2 node is restarted against a different chain, every computed output root would
+    /// differ from the canonical one and all new games would be challengeable. Always returns
+    /// true when no expected L2 chain ID is configured.
+    pub async fn verify_l2_chain_id(&self) -> Result<bool> {
+        let Some(expected_chain_id) = self.config.expected_l2_chain_id else {
+            return Ok(true);
+        };
 
-            // Spawn a tracked proving task for the new game
-            if let Err(e) = self.spawn_game_proving_task(game_address).await {
-                tracing::warn!("Failed to spawn fast finality proof task: {:?}", e);
-            }
+        let chain_id = self.l2_provider.get_chain_id().await?;
+        if chain_id != expected_chain_id {
+            tracing::error!(
+                "L2 chain ID mismatch: expected {}, L2 RPC reports {}",
+                expected_chain_id,
+                chain_id
+            );
+            return Ok(false);
         }
 
-        Ok(game_address)
+        Ok(true)
     }
 
-    /// Handles the creation of a new game if conditions are met.
-    /// Returns the address of the created game, if one was created.
-    #[tracing::instrument(name = "[[Proposing]]", skip(self))]
-    pub async fn handle_game_creation(&self) -> Result<Option<Address>> {
-        // Get the latest valid proposal.
-        let latest_valid_proposal =
-            self.factory.get_latest_valid_proposal(self.l2_provider.clone()).await?;
-
-        // Determine next block number and parent game index.
-        //
-        // Two cases based on the result of `get_latest_valid_proposal`:
-        // 1. With existing valid proposal:
-        //    - Block number = latest valid proposal's block + proposal interval.
-        //    - Parent = latest valid game's index.
-        //
-        // 2. Without valid proposal (first game or all existing games being faulty):
-        //    - Block number = anchor L2 block number + proposal interval.
-        //    - Parent = u32::MAX (special value indicating no parent).
-        let (latest_proposed_block_number, next_l2_block_number_for_proposal, parent_game_index) =
-            match latest_valid_proposal {
-                Some((latest_block, latest_game_idx)) => (
-                    latest_block,
-                    latest_block + U256::from(self.config.proposal_interval_in_blocks),
-                    latest_game_idx.to::<u32>(),
-                ),
-                None => {
-                    let anchor_l2_block_number =
-                        self.factory.get_anchor_l2_block_number(self.config.game_type).await?;
-                    tracing::info!("Anchor L2 block number: {:?}", anchor_l2_block_number);
-                    (
-                        anchor_l2_block_number,
-                        anchor_l2_block_number
-                            .checked_add(U256::from(self.config.proposal_interval_in_blocks))
-                            .unwrap(),
-                        u32::MAX,
-                    )
-                }
-            };
+    /// Verifies both chain IDs and pauses game creation while either of them mismatches.
+    async fn check_chain_ids(&self) -> Result<()> {
+        let matches = self.verify_l1_chain_id().await? && self.verify_l2_chain_id().await?;
 
-        let finalized_l2_head_block_number = self
-            .host
-            .get_finalized_l2_block_number(&self.fetcher, latest_proposed_block_number.to::<u64>())
-            .await?;
+        if !matches {
+            self.metrics.increment(ProposerGauge::ChainIdMismatch, 1.0);
+            tracing::error!("Pausing game creation until the chain IDs match the configuration");
+        } else if self.chain_id_mismatch.load(Ordering::Relaxed) {
+            tracing::info!("Chain IDs match the configuration again, resuming game creation");
+        }
 
-        // There's always a new game to propose, as the chain is always moving forward from the
-        // genesis block set for the game type. Only create a new game if the finalized L2
-        // head block number is greater than the next L2 block number for proposal.
-        if let Some(finalized_block) = finalized_l2_head_block_number {
-            if U256::from(finalized_block) > next_l2_block_number_for_proposal {
-                let game_address =
-                    self.create_game(next_l2_block_number_for_proposal, parent_game_index).await?;
+        self.chain_id_mismatch.store(!matches, Ordering::Relaxed);
+        Ok(())
+    }
 
-                Ok(Some(game_address))
-            } else {
-                tracing::info!("No new game to propose since proposal interval has not elapsed");
+    /// Detects whether the L1 chain stopped producing blocks, by comparing the timestamp of the
+    /// latest L1 block against the wall clock.
+    pub async fn detect_l1_halt(&self) -> Result<L1ChainStatus> {
+        let block = self
+            .l1_provider
+            .get_block_by_number(BlockNumberOrTag::Latest)
+            .await?
+            .context("Latest L1 block not found")?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let secs_since_last_block = now.saturating_sub(block.header.timestamp);
+
+        Ok(L1ChainStatus {
+            last_block_number: block.header.number,
+            last_block_timestamp: block.header.timestamp,
+            secs_since_last_block,
+            is_halted: secs_since_last_block > self.config.l1_halt_threshold_secs,
+        })
+    }
 
-                Ok(None)
-            }
-        } else {
-            tracing::info!("No new finalized block number found since last proposed block");
-            Ok(None)
+    /// Checks the liveness of the L1 chain and returns whether operations should be paused.
+    ///
+    /// Games are deadline-sensitive, so nothing is created, proven or resolved while L1 is halted.
+    /// Operations resume automatically once L1 produces blocks again.
+    async fn handle_l1_chain_halt(&self) -> Result<bool> {
+        let status = self.detect_l1_halt().await?;
+
+        if status.is_halted {
+            self.metrics.increment(ProposerGauge::L1HaltDetected, 1.0);
+            tracing::error!(
+                "L1 chain halted: no block since block {} at timestamp {} ({}s ago), pausing all operations",
+                status.last_block_number,
+                status.last_block_timestamp,
+                status.secs_since_last_block
+            );
+        } else if self.l1_halted.load(Ordering::Relaxed) {
+            tracing::info!(
+                "L1 chain produced block {}, resuming operations",
+                status.last_block_number
+            );
         }
+
+        self.l1_halted.store(status.is_halted, Ordering::Relaxed);
+        Ok(status.is_halted)
     }
 
-    /// Handles claiming bonds from resolved games.
-    #[tracing::instrument(name = "[[Claiming Bonds]]", skip(self))]
-    async fn handle_bond_claiming(&self) -> Result<Action> {
-        if let Some(game_address) = self
-            .factory
-            .get_oldest_claimable_bond_game_address(
-                self.config.game_type,
-                self.config.max_games_to_check_for_bond_claiming,
-                self.prover_address,
-            )
-            .await?
-        {
-            tracing::info!("Attempting to claim bond from game {:?}", game_address);
+    /// Runs the proposer until `shutdown` is cancelled or the process receives SIGINT or SIGTERM.
+    pub async fn run(self: Arc<Self>, shutdown: Option<CancellationToken>) -> Result<()> {
+        tracing::info!("OP Succinct Proposer running...");
+        let shutdown = shutdown.unwrap_or_default();
+        cancel_on_shutdown_signal(shutdown.clone());
+        let mut interval = time::interval(Duration::from_secs(self.config.fetch_interval));
 
-            // Create a contract instance for the game
-            let game = OPSuccinctFaultDisputeGame::new(game_address, self.l1_provider.clone());
+        self.metrics.init_all();
 
-            // Create a transaction to claim credit
-            let transaction_request =
-                game.claimCredit(self.prover_address).into_transaction_request();
+        // Spawn a dedicated task for continuous metrics collection
+        self.spawn_metrics_collector();
 
-            // Sign and send the transaction
-            match self
-                .signer
-                .send_transaction_request(self.config.l1_rpc.clone(), transaction_request)
-                .await
-            {
-                Ok(receipt) => {
-                    tracing::info!(
-                        "\x1b[1mSuccessfully claimed bond from game {:?} with tx {:?}\x1b[0m",
-                        game_address,
-                        receipt.transaction_hash
-                    );
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {}
+                _ = shutdown.cancelled() => break,
+            }
 
-                    Ok(Action::Performed)
+            match self.tick().await {
+                Ok(report) => {
+                    self.health.record_successful_tick();
+                    if report.paused {
+                        continue;
+                    }
+                    tracing::debug!("Proposer tick: {:?}", report);
                 }
-                Err(e) => Err(anyhow::anyhow!(
-                    "Failed to claim bond from game {:?}: {:?}",
-                    game_address,
-                    e
-                )),
+                Err(e) => tracing::warn!("Proposer tick failed: {:?}", e),
             }
-        } else {
-            tracing::info!("No new games to claim bonds from");
-
-            Ok(Action::Skipped)
-        }
-    }
 
-    /// Fetch the proposer metrics.
-    async fn fetch_proposer_metrics(&self) -> Result<()> {
-        // Get the latest valid proposal.
-        let latest_proposed_block_number =
-            match self.factory.get_latest_valid_proposal(self.l2_provider.clone()).await? {
-                Some((l2_block_number, _game_index)) => l2_block_number,
-                None => {
-                    tracing::info!("No valid proposals found for metrics");
-                    self.factory.get_anchor_l2_block_number(self.config.game_type).await?
+            // Schedule the next tick based on how far we are from the next proposal.
+            let next_check_interval = match self.compute_optimal_next_check_interval().await {
+                Ok(next_check_interval) => next_check_interval,
+                Err(e) => {
+                    tracing::warn!("Failed to compute next check interval: {:?}", e);
+                    Duration::from_secs(self.config.fetch_interval)
                 }
             };
+            interval.reset_after(jittered_interval(
+                next_check_interval,
+                self.config.fetch_interval_jitter_pct,
+            ));
+        }
 
-        // Update metrics for latest game block number.
-        ProposerGauge::LatestGameL2BlockNumber.set(latest_proposed_block_number.to::<u64>() as f64);
+        self.shutdown().await;
+        Ok(())
+    }
 
-        // Update metrics for finalized L2 block number.
-        if let Some(finalized_l2_block_number) = self
-            .host
-            .get_finalized_l2_block_number(&self.fetcher, latest_proposed_block_number.to::<u64>())
-            .await?
-        {
-            ProposerGauge::FinalizedL2BlockNumber.set(finalized_l2_block_number as f64);
+    /// Runs a single proposer cycle: cleans up finished tasks and spawns the game creation,
+    /// defense, resolution and bond claiming tasks that are due.
+    ///
+    /// [`Self::run`] calls this on every interval. It can also be driven by an external scheduler,
+    /// in which case the spawned tasks are cleaned up by the next call.
+    pub async fn tick(&self) -> Result<TickReport> {
+        let mut report = TickReport::default();
+
+        // Verify the chain IDs on the first tick and periodically afterwards.
+        if self.ticks.fetch_add(1, Ordering::Relaxed) % CHAIN_ID_CHECK_INTERVAL_TICKS == 0 {
+            if let Err(e) = self.check_chain_ids().await {
+                tracing::warn!("Failed to check chain IDs: {:?}", e);
+            }
         }
 
-        // Update metrics for anchor game block number.
-        let anchor_game_l2_block_number =
-            self.factory.get_anchor_l2_block_number(self.config.game_type).await?;
-        ProposerGauge::AnchorGameL2BlockNumber.set(anchor_game_l2_block_number.to::<u64>() as f64);
+        match self.handle_l1_chain_halt().await {
+            Ok(true) => {
+                report.paused = true;
+                return Ok(report);
+            }
+            Ok(false) => {}
+            Err(e) => tracing::warn!("Failed to check L1 chain liveness: {:?}", e),
+        }
 
-        Ok(())
-    }
+        // 1. Handle completed tasks
+        match self.handle_completed_tasks().await {
+            Ok(tasks_completed) => report.tasks_completed = tasks_completed,
+            Err(e) => tracing::warn!("Failed to handle completed tasks: {:?}", e),
+        }
 
-    /// Runs the proposer indefinitely.
-    pub async fn run(self: Arc<Self>) -> Result<()> {
-        tracing::info!("OP Succinct Proposer running...");
-        let mut interval = time::interval(Duration::from_secs(self.config.fetch_interval));
+        // 2. Spawn new work (non-blocking)
+        if let Err(e) = self.spawn_pending_operations(&mut report).await {
+            tracing::warn!("Failed to spawn pending operations: {:?}", e);
+        }
 
-        // Spawn a dedicated task for continuous metrics collection
-        self.spawn_metrics_collector();
+        // 3. Log task statistics
+        self.log_task_stats().await;
 
-        loop {
-            interval.tick().await;
+        Ok(report)
+    }
 
-            // 1. Handle completed tasks
-            if let Err(e) = self.handle_completed_tasks().await {
-                tracing::warn!("Failed to handle completed tasks: {:?}", e);
+    /// Waits for in-flight game creation, resolution and bond claiming tasks to finish, so that no
+    /// transaction is left unconfirmed, and flushes the metrics.
+    ///
+    /// Proving tasks are aborted instead, as proof generation can take hours. The games they were
+    /// proving are picked up again by the next run.
+    async fn shutdown(&self) {
+        let tasks: Vec<(TaskHandle, TaskInfo)> =
+            self.tasks.lock().await.drain().map(|(_, task)| task).collect();
+        for (handle, info) in tasks {
+            if let TaskInfo::GameProving { .. } = info {
+                tracing::info!("Aborting task {:?}", info);
+                handle.abort();
+                continue;
             }
 
-            // 2. Spawn new work (non-blocking)
-            if let Err(e) = self.spawn_pending_operations().await {
-                tracing::warn!("Failed to spawn pending operations: {:?}", e);
+            tracing::info!("Waiting for task {:?} to finish before shutting down", info);
+            match handle.await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => tracing::warn!("Task {:?} failed: {:?}", info, e),
+                Err(e) => tracing::error!("Task {:?} panicked: {:?}", info, e),
             }
+        }
 
-            // 3. Log task statistics
-            self.log_task_stats().await;
+        if let Err(e) = self.fetch_proposer_metrics().await {
+            tracing::warn!("Failed to fetch metrics: {:?}", e);
         }
+        tracing::info!("OP Succinct Proposer shut down");
+    }
+
+    /// Computes how long to wait before the next tick.
+    ///
+    /// When the finalized L2 head is far behind the next proposal block, there is nothing to do
+    /// for a while, so the proposer waits half of the estimated time until finality reaches the
+    /// next proposal block. The result is clamped to `[min_fetch_interval, max_fetch_interval]`;
+    /// both default to `fetch_interval`, which keeps the interval static.
+    pub async fn compute_optimal_next_check_interval(&self) -> Result<Duration> {
+        let min_fetch_interval = self.config.min_fetch_interval;
+        let max_fetch_interval = self.config.max_fetch_interval.max(min_fetch_interval);
+        if min_fetch_interval == max_fetch_interval {
+            return Ok(Duration::from_secs(min_fetch_interval));
+        }
+
+        let next_proposal_block = self.get_next_proposal_block().await?.to::<u64>();
+        let latest_proposed_block =
+            next_proposal_block.saturating_sub(self.config.proposal_interval_in_blocks);
+        let finalized_l2_head_block_number = self
+            .host
+            .get_finalized_l2_block_number(&self.fetcher, latest_proposed_block)
+            .await?
+            .unwrap_or(latest_proposed_block);
+
+        let l2_block_time =
+            self.fetcher.rollup_config.as_ref().map_or(2, |config| config.block_time);
+        let blocks_until_next_proposal =
+            next_proposal_block.saturating_sub(finalized_l2_head_block_number);
+        let suggested_interval = blocks_until_next_proposal * l2_block_time / 2;
+
+        Ok(Duration::from_secs(suggested_interval.clamp(min_fetch_interval, max_fetch_interval)))
     }
 
     /// Spawn a dedicated metrics collection task
@@ -506,14 +2839,14 @@ where
                 metrics_timer.tick().await;
                 if let Err(e) = proposer_metrics.fetch_proposer_metrics().await {
                     tracing::warn!("Failed to fetch metrics: {:?}", e);
-                    ProposerGauge::MetricsError.increment(1.0);
+                    proposer_metrics.metrics.increment(ProposerGauge::MetricsError, 1.0);
                 }
             }
         });
     }
 
     /// Handle completed tasks and clean them up
-    async fn handle_completed_tasks(&self) -> Result<()> {
+    async fn handle_completed_tasks(&self) -> Result<usize> {
         let mut tasks = self.tasks.lock().await;
         let mut completed = Vec::new();
 
@@ -525,11 +2858,15 @@ where
         }
 
         // Process completed tasks
+        let completed_count = completed.len();
         for id in completed {
             if let Some((handle, info)) = tasks.remove(&id) {
                 match handle.await {
                     Ok(Ok(())) => {
                         tracing::info!("Task {:?} completed successfully", info);
+                        if matches!(info, TaskInfo::GameCreation { .. }) {
+                            self.record_creation_outcome(true).await;
+                        }
                     }
                     Ok(Err(e)) => {
                         tracing::warn!("Task {:?} failed: {:?}", info, e);
@@ -543,39 +2880,144 @@ where
             }
         }
 
-        Ok(())
+        Ok(completed_count)
+    }
+
+    /// Classifies a proof failure by its error message and suggests remediations.
+    pub fn diagnose_proof_failure(
+        &self,
+        game_address: Address,
+        error: &anyhow::Error,
+    ) -> ProofFailureDiagnosis {
+        let message = format!("{error:#}").to_lowercase();
+        let matches_any = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+
+        let diagnosis = if matches_any(&["cycle limit", "cycle_limit", "cycles exceeded"]) {
+            ProofFailureDiagnosis {
+                likely_cause: "The range program exceeded the cycle limit".to_string(),
+                suggested_remediation: vec![
+                    "Increase the cycle limit of range proofs".to_string(),
+                    "Reduce PROPOSAL_INTERVAL_IN_BLOCKS so each game covers fewer blocks"
+                        .to_string(),
+                ],
+                can_retry: false,
+                estimated_retry_time: None,
+            }
+        } else if matches_any(&["timeout", "timed out", "deadline exceeded"]) {
+            ProofFailureDiagnosis {
+                likely_cause: "A request to the prover network or an RPC timed out".to_string(),
+                suggested_remediation: vec![
+                    "Retry in 5 minutes".to_string(),
+                    "Switch to a different prover network endpoint if timeouts persist".to_string(),
+                ],
+                can_retry: true,
+                estimated_retry_time: Some(Duration::from_secs(300)),
+            }
+        } else if matches_any(&[
+            "witness validation failed",
+            "failed to get proof stdin",
+            "missing trie node",
+            "state is not available",
+        ]) {
+            ProofFailureDiagnosis {
+                likely_cause: "The witness could not be generated from the L2 node".to_string(),
+                suggested_remediation: vec![
+                    "Check the L2 node sync status".to_string(),
+                    "Check that the L2 node retains historical state for the proven range"
+                        .to_string(),
+                ],
+                can_retry: true,
+                estimated_retry_time: Some(Duration::from_secs(60)),
+            }
+        } else if matches_any(&["insufficient balance", "insufficient funds"]) {
+            ProofFailureDiagnosis {
+                likely_cause: "The prover network or L1 account has insufficient funds".to_string(),
+                suggested_remediation: vec![
+                    "Top up the prover network account".to_string(),
+                    "Top up the proposer's L1 account".to_string(),
+                ],
+                can_retry: false,
+                estimated_retry_time: None,
+            }
+        } else if matches_any(&["failed to send transaction", "nonce", "replacement"]) {
+            ProofFailureDiagnosis {
+                likely_cause: "The proof transaction could not be submitted to L1".to_string(),
+                suggested_remediation: vec![
+                    "Check for other transactions pending from the proposer's account".to_string(),
+                ],
+                can_retry: true,
+                estimated_retry_time: Some(Duration::from_secs(self.config.fetch_interval)),
+            }
+        } else {
+            ProofFailureDiagnosis {
+                likely_cause: "Unknown".to_string(),
+                suggested_remediation: vec!["Inspect the full error in the logs".to_string()],
+                can_retry: true,
+                estimated_retry_time: None,
+            }
+        };
+
+        tracing::warn!(
+            "Proof for game {:?} failed. Likely cause: {}. Suggested remediation: {:?} (can retry: {}, retry in: {:?})",
+            game_address,
+            diagnosis.likely_cause,
+            diagnosis.suggested_remediation,
+            diagnosis.can_retry,
+            diagnosis.estimated_retry_time
+        );
+
+        diagnosis
     }
 
     /// Handle task failure based on task type
-    async fn handle_task_failure(&self, info: &TaskInfo, _error: anyhow::Error) -> Result<()> {
+    async fn handle_task_failure(&self, info: &TaskInfo, error: anyhow::Error) -> Result<()> {
         match info {
             TaskInfo::GameCreation { .. } => {
-                ProposerGauge::GameCreationError.increment(1.0);
+                self.metrics.increment(ProposerGauge::GameCreationError, 1.0);
+                self.record_creation_outcome(false).await;
             }
-            TaskInfo::GameProving { .. } => {
-                ProposerGauge::GameProvingError.increment(1.0);
+            TaskInfo::GameProving { game_address } => {
+                self.metrics.increment(ProposerGauge::GameProvingError, 1.0);
+                self.diagnose_proof_failure(*game_address, &error);
             }
             TaskInfo::GameResolution => {
-                ProposerGauge::GameResolutionError.increment(1.0);
+                self.metrics.increment(ProposerGauge::GameResolutionError, 1.0);
             }
             TaskInfo::BondClaim => {
-                ProposerGauge::BondClaimingError.increment(1.0);
+                self.metrics.increment(ProposerGauge::BondClaimingError, 1.0);
             }
         }
         Ok(())
     }
 
     /// Spawn pending operations if not already running
-    async fn spawn_pending_operations(&self) -> Result<()> {
+    async fn spawn_pending_operations(&self, report: &mut TickReport) -> Result<()> {
         // Check if we should create a game and spawn task if needed
-        if !self.has_active_task_of_type(&TaskInfo::GameCreation { block_number: U256::ZERO }).await
+        if self.chain_id_mismatch.load(Ordering::Relaxed) {
+            tracing::warn!("Game creation paused due to chain ID mismatch");
+            SkipReason::ChainIdMismatch.record(&self.metrics);
+        } else if let Some(remaining) = self.creation_circuit_breaker_remaining().await {
+            tracing::warn!(
+                "Game creation paused by the circuit breaker after {} consecutive failures, resuming in {:?}",
+                self.config.max_consecutive_creation_failures,
+                remaining
+            );
+        } else if !self
+            .has_active_task_of_type(&TaskInfo::GameCreation { block_number: U256::ZERO })
+            .await
         {
             match self.spawn_game_creation_task().await {
-                Ok(true) => tracing::info!("Successfully spawned game creation task"),
+                Ok(true) => {
+                    report.game_creation_spawned = true;
+                    tracing::info!("Successfully spawned game creation task");
+                }
                 Ok(false) => {
                     tracing::debug!("No game creation needed - proposal interval not elapsed")
                 }
-                Err(e) => tracing::warn!("Failed to spawn game creation task: {:?}", e),
+                Err(e) => {
+                    tracing::warn!("Failed to spawn game creation task: {:?}", e);
+                    self.record_creation_outcome(false).await;
+                }
             }
         } else {
             tracing::info!("Game creation task already active");
@@ -583,7 +3025,10 @@ where
 
         // Check if we should defend games
         match self.spawn_game_defense_tasks().await {
-            Ok(true) => tracing::info!("Successfully spawned game defense task"),
+            Ok(true) => {
+                report.game_defense_spawned = true;
+                tracing::info!("Successfully spawned game defense task");
+            }
             Ok(false) => tracing::debug!("No games need defense or task already active"),
             Err(e) => tracing::warn!("Failed to spawn game defense tasks: {:?}", e),
         }
@@ -591,7 +3036,10 @@ where
         // Check if we should resolve games
         if !self.has_active_task_of_type(&TaskInfo::GameResolution).await {
             match self.spawn_game_resolution_task().await {
-                Ok(true) => tracing::info!("Successfully spawned game resolution task"),
+                Ok(true) => {
+                    report.game_resolution_spawned = true;
+                    tracing::info!("Successfully spawned game resolution task");
+                }
                 Ok(false) => tracing::debug!("No games need resolution"),
                 Err(e) => tracing::warn!("Failed to spawn game resolution task: {:?}", e),
             }
@@ -602,7 +3050,10 @@ where
         // Check if we should claim bonds
         if !self.has_active_task_of_type(&TaskInfo::BondClaim).await {
             match self.spawn_bond_claim_task().await {
-                Ok(true) => tracing::info!("Successfully spawned bond claim task"),
+                Ok(true) => {
+                    report.bond_claim_spawned = true;
+                    tracing::info!("Successfully spawned bond claim task");
+                }
                 Ok(false) => tracing::debug!("No bonds available to claim"),
                 Err(e) => tracing::warn!("Failed to spawn bond claim task: {:?}", e),
             }
@@ -665,14 +3116,18 @@ where
         let task_id = self.next_task_id.fetch_add(1, Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
-            match proposer.handle_game_creation().await {
-                Ok(Some(_game_address)) => {
-                    ProposerGauge::GamesCreated.increment(1.0);
-                    Ok(())
+            // Each game is created off the latest valid proposal, which is the previous game once
+            // its creation is confirmed. Stop as soon as no further game can be created, e.g.
+            // because the finalized L2 head was reached.
+            for _ in 0..proposer.config.max_proposals_per_tick.max(1) {
+                match proposer.handle_game_creation().await? {
+                    Some(_game_address) => {
+                        proposer.metrics.increment(ProposerGauge::GamesCreated, 1.0)
+                    }
+                    None => break,
                 }
-                Ok(None) => Ok(()),
-                Err(e) => Err(e),
             }
+            Ok(())
         });
 
         // Get the next proposal block for task info
@@ -686,44 +3141,160 @@ where
 
     /// Check if we should create a game
     async fn should_create_game(&self) -> Result<bool> {
-        // Use the existing logic from handle_game_creation
-        let latest_valid_proposal =
-            self.factory.get_latest_valid_proposal(self.l2_provider.clone()).await?;
-
-        let (latest_proposed_block_number, next_l2_block_number_for_proposal, _) =
-            match latest_valid_proposal {
-                Some((latest_block, latest_game_idx)) => (
-                    latest_block,
-                    latest_block + U256::from(self.config.proposal_interval_in_blocks),
-                    latest_game_idx.to::<u32>(),
-                ),
-                None => {
-                    let anchor_l2_block_number =
-                        self.factory.get_anchor_l2_block_number(self.config.game_type).await?;
-                    (
-                        anchor_l2_block_number,
-                        anchor_l2_block_number
-                            .checked_add(U256::from(self.config.proposal_interval_in_blocks))
-                            .unwrap(),
-                        u32::MAX,
-                    )
-                }
-            };
+        let plan = self.next_proposal_plan().await?;
+        if !plan.would_submit {
+            return Ok(false);
+        }
+        let next_l2_block_number_for_proposal = plan.next_l2_block_number;
 
-        let finalized_l2_head_block_number = self
-            .host
-            .get_finalized_l2_block_number(&self.fetcher, latest_proposed_block_number.to::<u64>())
-            .await?;
+        if let Some(remaining) =
+            self.creation_backoff_remaining(next_l2_block_number_for_proposal).await
+        {
+            tracing::info!(
+                "Game creation for L2 block {} failed recently, retrying in {:?}",
+                next_l2_block_number_for_proposal,
+                remaining
+            );
+            SkipReason::CreationBackoff.record(&self.metrics);
+            return Ok(false);
+        }
+
+        if !self.signer_allowed_to_propose().await? {
+            SkipReason::NotWhitelisted.record(&self.metrics);
+            return Ok(false);
+        }
+
+        if let Some(remaining) = self.min_proposal_interval_remaining().await? {
+            tracing::debug!(
+                "Minimum proposal interval not elapsed, skipping creation for {:?}",
+                remaining
+            );
+            SkipReason::RateLimited.record(&self.metrics);
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    /// Returns whether the access manager allows the signer to propose.
+    ///
+    /// The answer is cached for `whitelist_check_interval_secs`, so that a signer whitelisted
+    /// later is picked up without querying the access manager on every tick.
+    async fn signer_allowed_to_propose(&self) -> Result<bool> {
+        let mut proposer_allowed = self.proposer_allowed.lock().await;
+        if let Some((allowed, checked_at)) = *proposer_allowed {
+            if checked_at.elapsed() < Duration::from_secs(self.config.whitelist_check_interval_secs)
+            {
+                return Ok(allowed);
+            }
+        }
+
+        let access_manager_address =
+            self.factory.get_access_manager_address(self.config.game_type).await?;
+        let access_manager = AccessManager::new(access_manager_address, self.l1_provider.clone());
+        let allowed = access_manager.isAllowedProposer(self.signer.address()).call().await?;
+
+        if !allowed {
+            tracing::warn!(
+                "\x1b[31m[NOT WHITELISTED]\x1b[0m Signer {:?} is not allowed to propose by access manager {:?}, skipping game creation",
+                self.signer.address(),
+                access_manager_address
+            );
+        } else if proposer_allowed.is_some_and(|(was_allowed, _)| !was_allowed) {
+            tracing::info!(
+                "Signer {:?} is now allowed to propose, resuming game creation",
+                self.signer.address()
+            );
+        }
+        self.metrics.set(ProposerGauge::ProposerNotWhitelisted, if allowed { 0.0 } else { 1.0 });
+        *proposer_allowed = Some((allowed, Instant::now()));
+
+        Ok(allowed)
+    }
+
+    /// Returns how long until `min_proposal_interval_secs` have elapsed since the last proposal
+    /// of the game type, or `None` if a game can be created now.
+    ///
+    /// The elapsed time is measured against the latest L1 block timestamp, as the contract does,
+    /// so that a game isn't created that would revert.
+    async fn min_proposal_interval_remaining(&self) -> Result<Option<Duration>> {
+        if self.config.min_proposal_interval_secs == 0 {
+            return Ok(None);
+        }
+
+        let access_manager_address =
+            self.factory.get_access_manager_address(self.config.game_type).await?;
+        let access_manager = AccessManager::new(access_manager_address, self.l1_provider.clone());
+        let last_proposal_timestamp =
+            access_manager.getLastProposalTimestamp().call().await?.to::<u64>();
+        let latest_block = self
+            .l1_provider
+            .get_block_by_number(BlockNumberOrTag::Latest)
+            .await?
+            .context("Latest L1 block not found")?;
+
+        let elapsed = latest_block.header.timestamp.saturating_sub(last_proposal_timestamp);
+        let remaining = self.config.min_proposal_interval_secs.saturating_sub(elapsed);
+        Ok((remaining > 0).then(|| Duration::from_secs(remaining)))
+    }
+
+    /// Counts consecutive failed game creations, and trips the circuit breaker once
+    /// `max_consecutive_creation_failures` is reached. Any success resets the count.
+    async fn record_creation_outcome(&self, success: bool) {
+        let mut breaker = self.creation_circuit_breaker.lock().await;
+        if success {
+            breaker.consecutive_failures = 0;
+            return;
+        }
+
+        breaker.consecutive_failures += 1;
+        let max_failures = self.config.max_consecutive_creation_failures;
+        if max_failures > 0 &&
+            breaker.consecutive_failures >= max_failures &&
+            breaker.tripped_at.is_none()
+        {
+            tracing::warn!(
+                "\x1b[31m[CIRCUIT BREAKER]\x1b[0m Game creation failed {} times in a row, pausing it for {}s",
+                breaker.consecutive_failures,
+                self.config.creation_circuit_breaker_cooldown_secs
+            );
+            breaker.tripped_at = Some(Instant::now());
+            self.metrics.set(ProposerGauge::CircuitBreakerTripped, 1.0);
+        }
+    }
+
+    /// Returns how much longer game creation is paused by the circuit breaker, if at all. Once the
+    /// cooldown has elapsed, the circuit breaker is reset and game creation is attempted again.
+    async fn creation_circuit_breaker_remaining(&self) -> Option<Duration> {
+        let mut breaker = self.creation_circuit_breaker.lock().await;
+        let tripped_at = breaker.tripped_at?;
+        let remaining = Duration::from_secs(self.config.creation_circuit_breaker_cooldown_secs)
+            .checked_sub(tripped_at.elapsed())
+            .filter(|remaining| !remaining.is_zero());
+        if remaining.is_none() {
+            tracing::info!("Circuit breaker cooldown elapsed, resuming game creation");
+            *breaker = CreationCircuitBreaker::default();
+            self.metrics.set(ProposerGauge::CircuitBreakerTripped, 0.0);
+        }
+        remaining
+    }
 
-        Ok(finalized_l2_head_block_number
-            .map(|finalized_block| U256::from(finalized_block) > next_l2_block_number_for_proposal)
-            .unwrap_or(false))
+    /// Returns how much longer game creation for `l2_block_number` is backed off after a failed
+    /// attempt, if at all.
+    ///
+    /// Failures for other blocks are forgotten, as the latest valid proposal has moved since.
+    async fn creation_backoff_remaining(&self, l2_block_number: U256) -> Option<Duration> {
+        let mut creation_failures = self.creation_failures.lock().await;
+        creation_failures.retain(|block_number, _| *block_number == l2_block_number);
+        let failed_at = creation_failures.get(&l2_block_number)?;
+        Duration::from_secs(self.config.creation_retry_backoff_secs)
+            .checked_sub(failed_at.elapsed())
+            .filter(|remaining| !remaining.is_zero())
     }
 
     /// Get the next proposal block number
     async fn get_next_proposal_block(&self) -> Result<U256> {
-        let latest_valid_proposal =
-            self.factory.get_latest_valid_proposal(self.l2_provider.clone()).await?;
+        let latest_valid_proposal = self.latest_valid_proposal().await?;
 
         match latest_valid_proposal {
             Some((latest_block, _)) => {
@@ -752,7 +3323,9 @@ where
             .factory
             .get_oldest_defensible_game_address(
                 self.config.max_games_to_check_for_defense,
-                self.l2_provider.clone(),
+                self.config.game_fetch_batch_size,
+                self.config.scan_concurrency,
+                self.caching_l2_provider.clone(),
             )
             .await?
         {
@@ -838,19 +3411,7 @@ where
         let proposer = self.clone();
         let task_id = self.next_task_id.fetch_add(1, Ordering::Relaxed);
 
-        let handle = tokio::spawn(async move {
-            proposer
-                .factory
-                .resolve_games(
-                    Mode::Proposer,
-                    proposer.config.max_games_to_check_for_resolution,
-                    proposer.signer.clone(),
-                    proposer.config.l1_rpc.clone(),
-                    proposer.l1_provider.clone(),
-                    proposer.l2_provider.clone(),
-                )
-                .await
-        });
+        let handle = tokio::spawn(async move { proposer.handle_game_resolution().await });
 
         let task_info = TaskInfo::GameResolution;
         self.tasks.lock().await.insert(task_id, (handle, task_info));
@@ -886,7 +3447,7 @@ where
         let handle = tokio::spawn(async move {
             match proposer.handle_bond_claiming().await {
                 Ok(Action::Performed) => {
-                    ProposerGauge::GamesBondsClaimed.increment(1.0);
+                    proposer.metrics.increment(ProposerGauge::GamesBondsClaimed, 1.0);
                     Ok(())
                 }
                 Ok(Action::Skipped) => Ok(()),
@@ -900,3 +3461,11 @@ where
         Ok(true)
     }
 }
+
+/// Returns the average of `durations`, or zero if empty.
+fn average_duration(durations: &[Duration]) -> Duration {
+    if durations.is_empty() {
+        return Duration::ZERO;
+    }
+    durations.iter().sum::<Duration>() / durations.len() as u32
+}
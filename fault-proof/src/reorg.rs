@@ -0,0 +1,261 @@
+use std::{collections::BTreeMap, future::Future};
+
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::B256;
+use alloy_provider::Provider;
+use anyhow::{Context, Result};
+
+use crate::L1Provider;
+
+/// Returns whether the L1 block with `block_hash` is on the canonical chain.
+///
+/// Nodes may keep serving reorged blocks by hash, so the block is also looked up by its number and
+/// the hashes compared.
+pub async fn is_canonical_l1_block(l1_provider: &L1Provider, block_hash: B256) -> Result<bool> {
+    let block_number = l1_provider.get_block_by_hash(block_hash).await?.map(|b| b.header.number);
+    is_canonical_block(block_hash, block_number, |number| {
+        L1ReorgDetector::fetch_block_hash(l1_provider, number)
+    })
+    .await
+}
+
+/// Returns whether the block with `block_hash`, found at `block_number` when looked up by hash, is
+/// the block at that number on the chain whose block hashes are looked up with
+/// `fetch_block_hash`.
+async fn is_canonical_block<F, Fut>(
+    block_hash: B256,
+    block_number: Option<u64>,
+    fetch_block_hash: F,
+) -> Result<bool>
+where
+    F: FnOnce(u64) -> Fut,
+    Fut: Future<Output = Result<B256>>,
+{
+    let Some(block_number) = block_number else {
+        return Ok(false);
+    };
+    Ok(fetch_block_hash(block_number).await? == block_hash)
+}
+
+/// Returns the latest L1 block that is at least `confirmations` blocks deep, whose logs are safe
+/// to act on.
+pub async fn confirmed_l1_block_number(
+    l1_provider: &L1Provider,
+    confirmations: u64,
+) -> Result<u64> {
+    Ok(l1_provider.get_block_number().await?.saturating_sub(confirmations))
+}
+
+/// Tracks the hashes of recent L1 blocks to detect reorgs between polls.
+#[derive(Debug)]
+pub struct L1ReorgDetector {
+    /// The number of blocks behind the L1 tip to keep track of.
+    depth: u64,
+    /// The last seen hash for each tracked L1 block number.
+    block_hashes: BTreeMap<u64, B256>,
+}
+
+impl L1ReorgDetector {
+    pub fn new(depth: u64) -> Self {
+        Self { depth, block_hashes: BTreeMap::new() }
+    }
+
+    /// Polls the L1 chain and returns the block numbers whose hashes changed since the last poll.
+    ///
+    /// Tracked blocks are re-checked from the tip down. Once a tracked block is found to still be
+    /// canonical, all of its ancestors are canonical as well, so the walk stops there. In the
+    /// common case this costs a single block fetch plus one fetch per newly produced block.
+    pub async fn poll(&mut self, l1_provider: &L1Provider) -> Result<Vec<u64>> {
+        let latest_block_number = l1_provider.get_block_number().await?;
+        self.update(latest_block_number, |number| Self::fetch_block_hash(l1_provider, number)).await
+    }
+
+    /// Re-checks the tracked blocks against a chain with tip `latest_block_number`, whose block
+    /// hashes are looked up with `fetch_block_hash`. See [`Self::poll`].
+    async fn update<F, Fut>(
+        &mut self,
+        latest_block_number: u64,
+        fetch_block_hash: F,
+    ) -> Result<Vec<u64>>
+    where
+        F: Fn(u64) -> Fut,
+        Fut: Future<Output = Result<B256>>,
+    {
+        let mut reorged_blocks = Vec::new();
+
+        let tracked: Vec<(u64, B256)> =
+            self.block_hashes.iter().rev().map(|(number, hash)| (*number, *hash)).collect();
+        for (number, hash) in tracked {
+            // Blocks above the new tip were dropped entirely.
+            if number > latest_block_number {
+                reorged_blocks.push(number);
+                self.block_hashes.remove(&number);
+                continue;
+            }
+
+            let canonical_hash = fetch_block_hash(number).await?;
+            if canonical_hash == hash {
+                break;
+            }
+
+            reorged_blocks.push(number);
+            self.block_hashes.insert(number, canonical_hash);
+        }
+
+        // Start tracking blocks produced since the last poll, bounded by the tracking depth.
+        let oldest_tracked_block_number = latest_block_number.saturating_sub(self.depth);
+        let next_block_number = self
+            .block_hashes
+            .keys()
+            .next_back()
+            .map_or(oldest_tracked_block_number, |number| number + 1)
+            .max(oldest_tracked_block_number);
+        for number in next_block_number..=latest_block_number {
+            let hash = fetch_block_hash(number).await?;
+            self.block_hashes.insert(number, hash);
+        }
+
+        // Drop blocks that fell out of the tracking window.
+        self.block_hashes = self.block_hashes.split_off(&oldest_tracked_block_number);
+
+        reorged_blocks.sort_unstable();
+        Ok(reorged_blocks)
+    }
+
+    async fn fetch_block_hash(l1_provider: &L1Provider, block_number: u64) -> Result<B256> {
+        let block = l1_provider
+            .get_block_by_number(BlockNumberOrTag::Number(block_number))
+            .await?
+            .with_context(|| format!("L1 block {block_number} not found"))?;
+        Ok(block.header.hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use anyhow::anyhow;
+
+    use super::*;
+
+    /// An L1 chain of `len` blocks, numbered from zero, whose hashes are derived from `fork`.
+    fn chain(len: u64, fork: u8) -> BTreeMap<u64, B256> {
+        (0..len)
+            .map(|number| {
+                let mut hash = B256::ZERO;
+                hash[0] = fork;
+                hash[24..].copy_from_slice(&number.to_be_bytes());
+                (number, hash)
+            })
+            .collect()
+    }
+
+    /// Polls `detector` against `chain`, returning the reorged blocks and the number of block
+    /// hashes fetched.
+    async fn poll(
+        detector: &mut L1ReorgDetector,
+        chain: &BTreeMap<u64, B256>,
+    ) -> (Vec<u64>, usize) {
+        let fetches = Cell::new(0);
+        let latest_block_number = *chain.keys().next_back().unwrap();
+        let reorged_blocks = detector
+            .update(latest_block_number, |number| {
+                fetches.set(fetches.get() + 1);
+                let hash = chain.get(&number).copied();
+                async move { hash.ok_or_else(|| anyhow!("L1 block {number} not found")) }
+            })
+            .await
+            .unwrap();
+        (reorged_blocks, fetches.get())
+    }
+
+    #[tokio::test]
+    async fn test_first_poll_tracks_window() {
+        let mut detector = L1ReorgDetector::new(5);
+        let (reorged_blocks, _) = poll(&mut detector, &chain(20, 0)).await;
+
+        assert!(reorged_blocks.is_empty());
+        assert_eq!(
+            detector.block_hashes.keys().copied().collect::<Vec<_>>(),
+            (14..20).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_without_reorg_stops_at_tip() {
+        let mut detector = L1ReorgDetector::new(5);
+        poll(&mut detector, &chain(20, 0)).await;
+
+        // One fetch for the previous tip and one for the new block.
+        let (reorged_blocks, fetches) = poll(&mut detector, &chain(21, 0)).await;
+        assert!(reorged_blocks.is_empty());
+        assert_eq!(fetches, 2);
+        assert_eq!(
+            detector.block_hashes.keys().copied().collect::<Vec<_>>(),
+            (15..21).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_detects_reorged_blocks() {
+        let mut detector = L1ReorgDetector::new(5);
+        poll(&mut detector, &chain(20, 0)).await;
+
+        // Blocks 17 to 19 are replaced, and the new fork has one more block.
+        let mut reorged_chain = chain(20, 0);
+        reorged_chain.extend(chain(21, 1).split_off(&17));
+        let (reorged_blocks, _) = poll(&mut detector, &reorged_chain).await;
+        assert_eq!(reorged_blocks, vec![17, 18, 19]);
+
+        // The new hashes are tracked, so the next poll doesn't report them again.
+        let (reorged_blocks, _) = poll(&mut detector, &reorged_chain).await;
+        assert!(reorged_blocks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_poll_detects_dropped_blocks() {
+        let mut detector = L1ReorgDetector::new(5);
+        poll(&mut detector, &chain(20, 0)).await;
+
+        // The tip moves back to a block replacing block 17.
+        let mut reorged_chain = chain(17, 0);
+        reorged_chain.extend(chain(18, 1).split_off(&17));
+        let (reorged_blocks, _) = poll(&mut detector, &reorged_chain).await;
+        assert_eq!(reorged_blocks, vec![17, 18, 19]);
+        assert_eq!(detector.block_hashes.keys().next_back(), Some(&17));
+    }
+
+    #[tokio::test]
+    async fn test_poll_ignores_reorgs_beyond_depth() {
+        let mut detector = L1ReorgDetector::new(5);
+        poll(&mut detector, &chain(20, 0)).await;
+
+        // Block 10 fell out of the tracking window before it was replaced.
+        let mut reorged_chain = chain(20, 0);
+        reorged_chain.insert(10, B256::repeat_byte(0xff));
+        let (reorged_blocks, _) = poll(&mut detector, &reorged_chain).await;
+        assert!(reorged_blocks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_is_canonical_block() {
+        let canonical_chain = chain(20, 0);
+        let fetch_block_hash = |number: u64| {
+            let hash = canonical_chain.get(&number).copied();
+            async move { hash.ok_or_else(|| anyhow!("L1 block {number} not found")) }
+        };
+
+        // A canonical block.
+        assert!(is_canonical_block(canonical_chain[&10], Some(10), fetch_block_hash)
+            .await
+            .unwrap());
+
+        // A reorged block the node still serves by hash.
+        let reorged_hash = chain(20, 1)[&10];
+        assert!(!is_canonical_block(reorged_hash, Some(10), fetch_block_hash).await.unwrap());
+
+        // A block the node doesn't know of.
+        assert!(!is_canonical_block(reorged_hash, None, fetch_block_hash).await.unwrap());
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    env,
+    sync::{Arc, Mutex, OnceLock},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use alloy_json_rpc::{RequestPacket, ResponsePacket};
+use alloy_transport::{TransportError, TransportFut};
+use anyhow::{bail, Result};
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    time::{self, Instant},
+};
+use tower::{Layer, Service};
+
+/// The limiter shared by every provider the process connects, set once at startup.
+static RPC_LIMIT_LAYER: OnceLock<RpcLimitLayer> = OnceLock::new();
+
+/// Limits on the RPC requests sent to the L1 and L2 nodes, shared across all providers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RpcLimits {
+    /// The maximum number of requests in flight at once. Zero means no limit.
+    pub max_concurrent_rpc: usize,
+    /// The maximum number of requests sent per second, or `None` for no limit.
+    pub rpc_rate_limit: Option<u32>,
+}
+
+impl RpcLimits {
+    pub fn from_env() -> Result<Self> {
+        let limits = Self {
+            max_concurrent_rpc: env::var("MAX_CONCURRENT_RPC")
+                .unwrap_or("0".to_string())
+                .parse()?,
+            rpc_rate_limit: env::var("RPC_RATE_LIMIT").ok().map(|v| v.parse()).transpose()?,
+        };
+        if limits.rpc_rate_limit == Some(0) {
+            bail!("RPC_RATE_LIMIT must be at least 1 request per second");
+        }
+        Ok(limits)
+    }
+}
+
+/// Sets the limits applied to every provider connected afterwards with
+/// [`crate::utils::connect_provider`].
+///
+/// Only the first call takes effect, so that all providers share the same limiter.
+pub fn init_rpc_limits(limits: RpcLimits) {
+    if RPC_LIMIT_LAYER.set(RpcLimitLayer::new(limits)).is_err() {
+        tracing::debug!("RPC limits already initialized, ignoring {:?}", limits);
+    }
+}
+
+/// Returns the shared limiter, or one without limits if [`init_rpc_limits`] wasn't called.
+pub(crate) fn rpc_limit_layer() -> RpcLimitLayer {
+    RPC_LIMIT_LAYER.get().cloned().unwrap_or_else(|| RpcLimitLayer::new(RpcLimits::default()))
+}
+
+/// A transport layer that caps the number of in-flight requests and spaces requests out to a
+/// maximum rate. Clones share the same limits.
+#[derive(Debug, Clone)]
+pub struct RpcLimitLayer {
+    in_flight: Option<Arc<Semaphore>>,
+    min_interval: Option<Duration>,
+    /// The earliest time the next request may be sent.
+    next_slot: Arc<Mutex<Instant>>,
+}
+
+impl RpcLimitLayer {
+    pub fn new(limits: RpcLimits) -> Self {
+        Self {
+            in_flight: (limits.max_concurrent_rpc > 0)
+                .then(|| Arc::new(Semaphore::new(limits.max_concurrent_rpc))),
+            min_interval: limits.rpc_rate_limit.map(|rate| Duration::from_secs(1) / rate.max(1)),
+            next_slot: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Waits for a free in-flight slot and for the next rate limit slot. The returned permit
+    /// holds the in-flight slot until it is dropped.
+    async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        let permit = match &self.in_flight {
+            Some(in_flight) => in_flight.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        if let Some(min_interval) = self.min_interval {
+            let slot = {
+                let mut next_slot = self.next_slot.lock().unwrap();
+                let slot = (*next_slot).max(Instant::now());
+                *next_slot = slot + min_interval;
+                slot
+            };
+            time::sleep_until(slot).await;
+        }
+
+        permit
+    }
+}
+
+impl<S> Layer<S> for RpcLimitLayer {
+    type Service = RpcLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcLimitService { inner, limits: self.clone() }
+    }
+}
+
+/// A transport that waits for the limits of its [`RpcLimitLayer`] before sending each request.
+#[derive(Debug, Clone)]
+pub struct RpcLimitService<S> {
+    inner: S,
+    limits: RpcLimitLayer,
+}
+
+impl<S> Service<RequestPacket> for RpcLimitService<S>
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Clone +
+        Send +
+        'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let mut inner = self.inner.clone();
+        let limits = self.limits.clone();
+        Box::pin(async move {
+            let _permit = limits.acquire().await;
+            inner.call(request).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_flight_requests_are_capped() {
+        let layer = RpcLimitLayer::new(RpcLimits { max_concurrent_rpc: 2, rpc_rate_limit: None });
+
+        let first = layer.acquire().await;
+        let second = layer.acquire().await;
+        assert!(first.is_some() && second.is_some());
+
+        // A third request waits until one of the first two completes.
+        assert!(time::timeout(Duration::from_millis(50), layer.acquire()).await.is_err());
+        drop(first);
+        assert!(time::timeout(Duration::from_millis(50), layer.acquire()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_in_flight_limit() {
+        let layer = RpcLimitLayer::new(RpcLimits { max_concurrent_rpc: 1, rpc_rate_limit: None });
+
+        let _permit = layer.acquire().await;
+        assert!(time::timeout(Duration::from_millis(50), layer.clone().acquire()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_no_limits() {
+        let layer = RpcLimitLayer::new(RpcLimits::default());
+
+        let start = Instant::now();
+        for _ in 0..10 {
+            assert!(layer.acquire().await.is_none());
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_spaced_to_rate_limit() {
+        // 20 requests per second is one slot every 50ms.
+        let layer =
+            RpcLimitLayer::new(RpcLimits { max_concurrent_rpc: 0, rpc_rate_limit: Some(20) });
+
+        let start = Instant::now();
+        for _ in 0..5 {
+            layer.acquire().await;
+        }
+        // The first request is sent immediately and each following one a slot later.
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_take_consecutive_slots() {
+        let layer =
+            RpcLimitLayer::new(RpcLimits { max_concurrent_rpc: 0, rpc_rate_limit: Some(20) });
+
+        let start = Instant::now();
+        let mut sent_at = futures::future::join_all((0..4).map(|_| {
+            let layer = layer.clone();
+            async move {
+                layer.acquire().await;
+                start.elapsed()
+            }
+        }))
+        .await;
+        sent_at.sort();
+
+        for (slot, elapsed) in sent_at.iter().enumerate() {
+            assert!(*elapsed >= Duration::from_millis(50) * slot as u32);
+        }
+    }
+}
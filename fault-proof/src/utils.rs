@@ -1,5 +1,56 @@
+use std::{
+    collections::HashMap,
+    env,
+    path::PathBuf,
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
+};
+
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{Address, TxHash, U256};
+use alloy_provider::{IpcConnect, Provider, RootProvider, WsConnect};
+use alloy_rpc_client::ClientBuilder;
+use alloy_rpc_types_eth::{Filter, Log, TransactionReceipt, TransactionRequest};
+use alloy_transport::TransportError;
+use alloy_transport_http::reqwest::Url;
+use anyhow::{bail, Context, Result};
+use futures::future::join_all;
+use op_alloy_network::Network;
+use op_succinct_signer_utils::Signer;
+use rand::Rng;
+use strum_macros::EnumString;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 use tracing_subscriber::{fmt, EnvFilter};
 
+use crate::{
+    contract::decode_game_error,
+    prometheus::{record_tx_confirmation_secs, TxOperation},
+    rpc_limit::rpc_limit_layer,
+    L1Provider, Mode,
+};
+
+/// The error substring returned when a transaction reuses an already mined nonce.
+const NONCE_TOO_LOW: &str = "nonce too low";
+/// The error substring returned when a transaction does not pay enough to replace a pending one.
+const REPLACEMENT_UNDERPRICED: &str = "replacement transaction underpriced";
+/// The percentage by which the fees of an underpriced transaction are bumped. Nodes require at
+/// least a 10% bump to replace a pending transaction.
+const GAS_BUMP_PERCENT: u128 = 125;
+/// The minimum percentage fee bump nodes accept to replace a pending transaction.
+const MIN_REPLACEMENT_BUMP_PERCENT: u128 = 110;
+/// The interval at which a sent transaction is polled for its receipt.
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// The number of recent L1 blocks whose priority fees are considered when estimating fees.
+const FEE_HISTORY_BLOCKS: u64 = 10;
+/// The number of attempts to re-establish a dropped WebSocket connection before giving up.
+const WS_MAX_RETRIES: u32 = 10;
+/// The delay between attempts to re-establish a dropped WebSocket connection.
+const WS_RETRY_INTERVAL: Duration = Duration::from_secs(3);
+/// The number of L1 blocks queried per `eth_getLogs` call by [`get_logs_paged`], which stays
+/// within the block range limit of common RPC providers.
+const LOGS_PAGE_BLOCKS: u64 = 10_000;
+
 pub fn setup_logging() {
     let format = fmt::format()
         .with_level(true)
@@ -17,3 +68,631 @@ pub fn setup_logging() {
         .event_format(format)
         .init();
 }
+
+/// Connects a provider to `url`, with the transport picked from its scheme.
+///
+/// `http://` and `https://` URLs are polled over HTTP. `ws://` and `wss://` URLs use a WebSocket,
+/// which is re-established up to `WS_MAX_RETRIES` times if it drops. Requests sent while
+/// reconnecting are retried on the new connection. `file://` URLs use the IPC socket at the path.
+///
+/// Requests are subject to the RPC limits shared by all providers of the process, see
+/// [`crate::rpc_limit::init_rpc_limits`].
+pub async fn connect_provider<N: Network>(url: &Url) -> Result<RootProvider<N>> {
+    let client = ClientBuilder::default().layer(rpc_limit_layer());
+    let client = match url.scheme() {
+        "http" | "https" => client.http(url.clone()),
+        "ws" | "wss" => {
+            let ws = WsConnect::new(url.as_str())
+                .with_max_retries(WS_MAX_RETRIES)
+                .with_retry_interval(WS_RETRY_INTERVAL);
+            client.ws(ws).await?
+        }
+        "file" => {
+            let ipc = IpcConnect::new(PathBuf::from(url.path()));
+            client.ipc(ipc).await?
+        }
+        scheme => bail!("Unsupported RPC URL scheme {scheme:?} in {url}"),
+    };
+    Ok(RootProvider::new(client))
+}
+
+/// Cancels `shutdown` once the process receives SIGINT or, on Unix, SIGTERM.
+pub fn cancel_on_shutdown_signal(shutdown: CancellationToken) {
+    tokio::spawn(async move {
+        #[cfg(unix)]
+        let terminate = async {
+            match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+                Ok(mut signal) => {
+                    signal.recv().await;
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to listen for SIGTERM: {:?}", e);
+                    std::future::pending::<()>().await;
+                }
+            }
+        };
+        #[cfg(not(unix))]
+        let terminate = std::future::pending::<()>();
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => {}
+            _ = terminate => {}
+            _ = shutdown.cancelled() => return,
+        }
+        tracing::info!("Received shutdown signal, shutting down after the current operation");
+        shutdown.cancel();
+    });
+}
+
+/// Returns `interval` plus a random jitter of up to `jitter_pct` percent of it.
+///
+/// Jittering the interval between ticks keeps instances sharing an RPC endpoint from polling it
+/// at the same time.
+pub fn jittered_interval(interval: Duration, jitter_pct: f64) -> Duration {
+    if jitter_pct <= 0.0 {
+        return interval;
+    }
+    interval.mul_f64(1.0 + rand::rng().random_range(0.0..=jitter_pct) / 100.0)
+}
+
+/// Returns the effective gas price paid by a transaction, in gwei.
+pub fn gas_price_paid_gwei(receipt: &TransactionReceipt) -> f64 {
+    receipt.effective_gas_price as f64 / 1e9
+}
+
+/// Returns the total L1 gas cost of a transaction, in wei.
+pub fn gas_cost_wei(receipt: &TransactionReceipt) -> f64 {
+    receipt.gas_used as f64 * receipt.effective_gas_price as f64
+}
+
+/// Returns the bond in wei paid to `claimant` by the `claimCredit` transaction of `receipt`.
+///
+/// The game's `credit` read before the claim may be larger than the payout, if the game closes in
+/// refund mode during the claim. The payout is bounded by the balance change of the claimant over
+/// the claim's block, which other transactions of the block can only lower.
+pub async fn claimed_bond_wei(
+    l1_provider: &L1Provider,
+    claimant: Address,
+    receipt: &TransactionReceipt,
+    credit: U256,
+) -> Result<U256> {
+    let block_number = receipt.block_number.context("Claim receipt without a block number")?;
+    let balance_before =
+        l1_provider.get_balance(claimant).block_id((block_number - 1).into()).await?;
+    let balance_after = l1_provider.get_balance(claimant).block_id(block_number.into()).await?;
+    let claim_gas_cost = U256::from(receipt.gas_used) * U256::from(receipt.effective_gas_price);
+    let balance_change = (balance_after + claim_gas_cost).saturating_sub(balance_before);
+    Ok(credit.min(balance_change))
+}
+
+/// Returns the logs matching `filter` between `from_block` and `to_block`, both inclusive, querying
+/// `LOGS_PAGE_BLOCKS` blocks at a time.
+pub async fn get_logs_paged(
+    l1_provider: &L1Provider,
+    filter: &Filter,
+    from_block: u64,
+    to_block: u64,
+) -> Result<Vec<Log>> {
+    let mut logs = Vec::new();
+    let mut page_start = from_block;
+    while page_start <= to_block {
+        let page_end = page_start.saturating_add(LOGS_PAGE_BLOCKS - 1).min(to_block);
+        let page_filter = filter.clone().from_block(page_start).to_block(page_end);
+        logs.extend(l1_provider.get_logs(&page_filter).await?);
+        page_start = page_end + 1;
+    }
+    Ok(logs)
+}
+
+/// Estimates the EIP-1559 max fee and max priority fee per gas from the L1 fee history.
+///
+/// The max priority fee is the average of the priority fees paid at `percentile` in the last
+/// `FEE_HISTORY_BLOCKS` blocks. The max fee leaves room for the base fee to double before the
+/// transaction is included.
+pub async fn estimate_1559_fees(l1_provider: &L1Provider, percentile: f64) -> Result<(u128, u128)> {
+    let fee_history = l1_provider
+        .get_fee_history(FEE_HISTORY_BLOCKS, BlockNumberOrTag::Latest, &[percentile])
+        .await?;
+    let base_fee_per_gas =
+        *fee_history.base_fee_per_gas.last().context("L1 fee history has no base fee")?;
+    let rewards: Vec<u128> = fee_history
+        .reward
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|block_rewards| block_rewards.first().copied())
+        .collect();
+    let max_priority_fee_per_gas = match rewards.len() {
+        0 => 0,
+        len => rewards.iter().sum::<u128>() / len as u128,
+    };
+
+    Ok((2 * base_fee_per_gas + max_priority_fee_per_gas, max_priority_fee_per_gas))
+}
+
+/// Sets the EIP-1559 fees estimated at `percentile` on the transaction request, capping the max
+/// fee at `max_fee_per_gas_gwei`.
+pub async fn with_1559_fees(
+    l1_provider: &L1Provider,
+    mut transaction_request: TransactionRequest,
+    percentile: f64,
+    max_fee_per_gas_gwei: u64,
+) -> Result<TransactionRequest> {
+    let (max_fee_per_gas, max_priority_fee_per_gas) =
+        estimate_1559_fees(l1_provider, percentile).await?;
+    let max_fee_per_gas_ceiling = max_fee_per_gas_gwei as u128 * 1_000_000_000;
+    if max_fee_per_gas > max_fee_per_gas_ceiling {
+        tracing::warn!(
+            "Estimated max fee per gas {} wei exceeds the ceiling of {} gwei, capping it",
+            max_fee_per_gas,
+            max_fee_per_gas_gwei
+        );
+    }
+    let max_fee_per_gas = max_fee_per_gas.min(max_fee_per_gas_ceiling);
+
+    transaction_request.max_fee_per_gas = Some(max_fee_per_gas);
+    transaction_request.max_priority_fee_per_gas =
+        Some(max_priority_fee_per_gas.min(max_fee_per_gas));
+    Ok(transaction_request)
+}
+
+/// Logs a transaction request instead of sending it, for dry run mode.
+pub fn log_dry_run_transaction(description: &str, transaction_request: &TransactionRequest) {
+    tracing::info!(
+        "\x1b[33m[DRY RUN]\x1b[0m Not sending {} transaction to {:?} with value {} and calldata {}",
+        description,
+        transaction_request.to.and_then(|to| to.to().copied()),
+        transaction_request.value.unwrap_or_default(),
+        transaction_request.input.input().cloned().unwrap_or_default()
+    );
+}
+
+/// The number of L1 confirmations a transaction waits for, unless `NUM_CONFIRMATIONS` is set.
+pub const DEFAULT_NUM_CONFIRMATIONS: u64 = 3;
+
+/// How long in seconds to wait for a transaction to be confirmed, unless `TX_TIMEOUT_SECS` is set.
+pub const DEFAULT_TX_TIMEOUT_SECS: u64 = 60;
+
+/// The next nonce tracked for each signer of the process, see [`NonceStrategy`].
+static SIGNER_NONCES: OnceLock<std::sync::Mutex<HashMap<Address, Arc<Mutex<Option<u64>>>>>> =
+    OnceLock::new();
+
+/// How concurrent transactions of the same signer are kept from reusing a nonce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum NonceStrategy {
+    /// Only one transaction per signer is in flight at a time, and it is assigned the signer's
+    /// pending nonce.
+    Serialized,
+    /// Transactions are sent concurrently, each with its own nonce assigned locally from the
+    /// signer's pending nonce and the nonces already handed out.
+    Managed,
+}
+
+/// How transient failures of transaction submission are retried, and how many confirmations a
+/// transaction waits for.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// The delay before the first retry. Each subsequent retry doubles the delay.
+    pub base_delay: Duration,
+    /// The upper bound of the delay between retries, before jitter.
+    pub max_delay: Duration,
+    /// The maximum number of submission attempts, including the first one.
+    pub max_attempts: u32,
+    /// Errors containing any of these substrings are retried. All other errors fail immediately.
+    pub retryable_errors: Vec<String>,
+    /// The number of L1 confirmations to wait for before a transaction counts as sent. Fewer
+    /// confirmations are faster, but act on transactions that a reorg may still drop.
+    pub num_confirmations: u64,
+    /// How long to wait for a sent transaction to be confirmed before the attempt fails.
+    pub timeout: Duration,
+    /// The ceiling in gwei of the max fee per gas that replacements of a transaction are bumped
+    /// to.
+    pub max_fee_per_gas_gwei: u64,
+    /// How concurrent transactions of the same signer are kept from reusing a nonce.
+    pub nonce_strategy: NonceStrategy,
+}
+
+impl RetryPolicy {
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            base_delay: Duration::from_millis(
+                env::var("TX_RETRY_BASE_DELAY_MS").unwrap_or("1000".to_string()).parse()?,
+            ),
+            max_delay: Duration::from_millis(
+                env::var("TX_RETRY_MAX_DELAY_MS").unwrap_or("30000".to_string()).parse()?,
+            ),
+            max_attempts: env::var("TX_RETRY_MAX_ATTEMPTS").unwrap_or("3".to_string()).parse()?,
+            retryable_errors: [
+                NONCE_TOO_LOW,
+                REPLACEMENT_UNDERPRICED,
+                "503",
+                "timeout",
+                "connection reset",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            num_confirmations: env::var("NUM_CONFIRMATIONS")
+                .unwrap_or(DEFAULT_NUM_CONFIRMATIONS.to_string())
+                .parse()?,
+            timeout: Duration::from_secs(
+                env::var("TX_TIMEOUT_SECS")
+                    .unwrap_or(DEFAULT_TX_TIMEOUT_SECS.to_string())
+                    .parse()?,
+            ),
+            max_fee_per_gas_gwei: env::var("MAX_FEE_PER_GAS_GWEI")
+                .unwrap_or("500".to_string())
+                .parse()?,
+            nonce_strategy: env::var("NONCE_STRATEGY")
+                .unwrap_or("serialized".to_string())
+                .parse()
+                .context("NONCE_STRATEGY must be one of serialized or managed")?,
+        })
+    }
+
+    fn is_retryable(&self, error: &str) -> bool {
+        self.retryable_errors.iter().any(|retryable| error.contains(retryable.as_str()))
+    }
+
+    /// The exponential backoff delay before the given retry, with up to 50% random jitter.
+    fn delay(&self, retry: u32) -> Duration {
+        let backoff = self.base_delay.saturating_mul(2u32.saturating_pow(retry - 1));
+        let delay = backoff.min(self.max_delay);
+        let jitter_ms = rand::rng().random_range(0..=delay.as_millis() as u64 / 2);
+        delay + Duration::from_millis(jitter_ms)
+    }
+}
+
+/// Adds the name of the dispute game error that a failed transaction reverted with to `error`,
+/// if the revert data of the RPC error response matches one of the declared errors.
+pub fn with_revert_reason(error: anyhow::Error) -> anyhow::Error {
+    let revert = error.chain().find_map(|source| {
+        let revert_data =
+            source.downcast_ref::<TransportError>()?.as_error_resp()?.as_revert_data()?;
+        decode_game_error(&revert_data)
+    });
+    match revert {
+        Some(revert) => error.context(format!("Transaction reverted with {}", revert.name())),
+        None => error,
+    }
+}
+
+/// The error returned when no transaction sent for a request was confirmed in time.
+#[derive(Debug, thiserror::Error)]
+#[error("Transaction {tx_hash:?} was not confirmed within {timeout:?}")]
+pub struct ConfirmationTimeout {
+    pub tx_hash: TxHash,
+    pub timeout: Duration,
+}
+
+/// Sends a transaction request with the signer, retrying transient failures according to
+/// `policy`.
+///
+/// Once a transaction was sent, retries never send it again under a new nonce, which could
+/// execute it twice. If it isn't confirmed within the timeout, it is replaced under the same
+/// nonce with fees bumped by `GAS_BUMP_PERCENT`, up to `max_fee_per_gas_gwei`. Every transaction
+/// sent for the request is then waited for, as any of them may be included. On "replacement
+/// transaction underpriced" the fees are bumped as well. The nonce is assigned before the first
+/// attempt, and on "nonce too low" it only moves on to the next nonce if none of the sent
+/// transactions was included, i.e. the nonce was used by another transaction. The time the
+/// successful attempt took to confirm is recorded for the `operation` of `mode`, labeled with
+/// `factory` in proposer mode.
+///
+/// Concurrent calls for the same signer either wait for each other or get distinct nonces,
+/// depending on the [`NonceStrategy`] of `policy`.
+pub async fn send_with_retry(
+    signer: &Signer,
+    l1_rpc: Url,
+    mut transaction_request: TransactionRequest,
+    policy: &RetryPolicy,
+    mode: Mode,
+    factory: Address,
+    operation: TxOperation,
+) -> Result<TransactionReceipt> {
+    let provider: L1Provider = connect_provider(&l1_rpc).await?;
+    let signer_nonce = signer_nonce(signer.address());
+    // With the serialized strategy, the lock is held until the transaction is confirmed or fails.
+    let _in_flight = match policy.nonce_strategy {
+        NonceStrategy::Serialized => Some(signer_nonce.clone().lock_owned().await),
+        NonceStrategy::Managed => None,
+    };
+    // The nonce is assigned once, and only moves on if a transaction not sent for the request
+    // used it.
+    if transaction_request.nonce.is_none() {
+        transaction_request.nonce = Some(
+            next_nonce(policy.nonce_strategy, &signer_nonce, &provider, signer.address()).await?,
+        );
+    }
+
+    send_with_assigned_nonce(
+        signer,
+        &l1_rpc,
+        &provider,
+        &signer_nonce,
+        transaction_request,
+        policy,
+        mode,
+        factory,
+        operation,
+    )
+    .await
+}
+
+/// Sends `transaction_requests` of the same signer under consecutive nonces, in order and
+/// without waiting for each to be confirmed before sending the next, so that they are included
+/// in order within a few blocks. Each request is retried as in [`send_with_retry`], and its
+/// result is returned in the same position, so that one failed request doesn't fail the others.
+///
+/// The requests must have their gas limits set, as a request depending on an earlier one would
+/// fail gas estimation before the earlier one is included.
+pub async fn send_pipelined_with_retry(
+    signer: &Signer,
+    l1_rpc: Url,
+    transaction_requests: Vec<TransactionRequest>,
+    policy: &RetryPolicy,
+    mode: Mode,
+    factory: Address,
+    operation: TxOperation,
+) -> Result<Vec<Result<TransactionReceipt>>> {
+    let provider: L1Provider = connect_provider(&l1_rpc).await?;
+    let signer_nonce = signer_nonce(signer.address());
+    // With the serialized strategy, the lock is held until every transaction is confirmed or
+    // fails.
+    let _in_flight = match policy.nonce_strategy {
+        NonceStrategy::Serialized => Some(signer_nonce.clone().lock_owned().await),
+        NonceStrategy::Managed => None,
+    };
+
+    let mut assigned = Vec::with_capacity(transaction_requests.len());
+    let mut previous_nonce = None;
+    for mut transaction_request in transaction_requests {
+        let nonce = match (policy.nonce_strategy, previous_nonce) {
+            (NonceStrategy::Serialized, Some(previous)) => previous + 1,
+            _ => {
+                next_nonce(policy.nonce_strategy, &signer_nonce, &provider, signer.address())
+                    .await?
+            }
+        };
+        previous_nonce = Some(nonce);
+        transaction_request.nonce = Some(nonce);
+        assigned.push(transaction_request);
+    }
+
+    // Nodes hold a transaction back until those with lower nonces arrive, so the transactions
+    // are included in nonce order even if they are sent out of order.
+    Ok(join_all(assigned.into_iter().map(|transaction_request| {
+        send_with_assigned_nonce(
+            signer,
+            &l1_rpc,
+            &provider,
+            &signer_nonce,
+            transaction_request,
+            policy,
+            mode,
+            factory,
+            operation,
+        )
+    }))
+    .await)
+}
+
+/// Sends a transaction request whose nonce is assigned, as described in [`send_with_retry`].
+#[allow(clippy::too_many_arguments)]
+async fn send_with_assigned_nonce(
+    signer: &Signer,
+    l1_rpc: &Url,
+    provider: &L1Provider,
+    signer_nonce: &Mutex<Option<u64>>,
+    mut transaction_request: TransactionRequest,
+    policy: &RetryPolicy,
+    mode: Mode,
+    factory: Address,
+    operation: TxOperation,
+) -> Result<TransactionReceipt> {
+    // The hashes of the transactions sent for the request, which all share the same nonce.
+    let mut sent_hashes = Vec::new();
+    // Whether the next attempt sends the transaction, or only waits for those already sent.
+    let mut broadcast = true;
+    let mut attempt = 1;
+    loop {
+        let send_start = Instant::now();
+        let mut sent = !broadcast;
+        let result = if broadcast {
+            match signer
+                .broadcast_transaction_request(l1_rpc.clone(), transaction_request.clone())
+                .await
+            {
+                Ok((tx_hash, nonce)) => {
+                    transaction_request.nonce = Some(nonce);
+                    sent_hashes.push(tx_hash);
+                    sent = true;
+                    wait_for_receipt(provider, &sent_hashes, policy).await
+                }
+                Err(e) => Err(e),
+            }
+        } else {
+            wait_for_receipt(provider, &sent_hashes, policy).await
+        };
+        let error = match result {
+            Ok(receipt) => {
+                record_tx_confirmation_secs(
+                    mode,
+                    factory,
+                    operation,
+                    send_start.elapsed().as_secs_f64(),
+                );
+                return Ok(receipt);
+            }
+            Err(e) => e,
+        };
+
+        let message = format!("{error:?}");
+        let timed_out = error.downcast_ref::<ConfirmationTimeout>().is_some();
+        if attempt >= policy.max_attempts || !(timed_out || policy.is_retryable(&message)) {
+            if policy.nonce_strategy == NonceStrategy::Managed {
+                // The nonce may never have reached the mempool, so the next transaction starts
+                // over from the pending nonce instead of leaving a gap.
+                *signer_nonce.lock().await = None;
+            }
+            return Err(with_revert_reason(error));
+        }
+
+        if timed_out {
+            broadcast =
+                bump_fees(provider, &mut transaction_request, policy.max_fee_per_gas_gwei).await?;
+            if !broadcast {
+                tracing::warn!(
+                    "Fees of transaction {:?} are at the ceiling, waiting for it instead of replacing it",
+                    sent_hashes.last()
+                );
+            }
+        } else if sent {
+            // Only waiting for the sent transaction failed, so it is waited for again.
+            broadcast = false;
+        } else if message.contains(NONCE_TOO_LOW) {
+            if any_included(provider, &sent_hashes).await? {
+                // A transaction sent earlier for the request was included, so it is waited for
+                // instead of sending the request again.
+                broadcast = false;
+            } else {
+                // The nonce was used by a transaction that wasn't sent for the request, which can
+                // therefore no longer be included.
+                sent_hashes.clear();
+                transaction_request.nonce = Some(
+                    next_nonce(policy.nonce_strategy, signer_nonce, provider, signer.address())
+                        .await?,
+                );
+            }
+        } else if message.contains(REPLACEMENT_UNDERPRICED) {
+            broadcast =
+                bump_fees(provider, &mut transaction_request, policy.max_fee_per_gas_gwei).await?;
+            if !broadcast && sent_hashes.is_empty() {
+                return Err(with_revert_reason(error));
+            }
+        }
+
+        let delay = policy.delay(attempt);
+        tracing::warn!(
+            "Transaction attempt {}/{} failed, retrying in {:?}: {:?}",
+            attempt,
+            policy.max_attempts,
+            delay,
+            error
+        );
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// Returns the next nonce for a transaction of the signer at `address` under `strategy`.
+async fn next_nonce(
+    strategy: NonceStrategy,
+    signer_nonce: &Mutex<Option<u64>>,
+    provider: &L1Provider,
+    address: Address,
+) -> Result<u64> {
+    match strategy {
+        // The serialized strategy holds the lock of `signer_nonce` while sending.
+        NonceStrategy::Serialized => Ok(provider.get_transaction_count(address).pending().await?),
+        NonceStrategy::Managed => next_managed_nonce(signer_nonce, provider, address).await,
+    }
+}
+
+/// Returns whether any of the `sent_hashes` was included in a block.
+async fn any_included(provider: &L1Provider, sent_hashes: &[TxHash]) -> Result<bool> {
+    for tx_hash in sent_hashes {
+        if provider.get_transaction_receipt(*tx_hash).await?.is_some() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Waits up to the timeout of `policy` for any of the `sent_hashes`, which replace each other, to
+/// get the confirmations of `policy`.
+async fn wait_for_receipt(
+    provider: &L1Provider,
+    sent_hashes: &[TxHash],
+    policy: &RetryPolicy,
+) -> Result<TransactionReceipt> {
+    let deadline = Instant::now() + policy.timeout;
+    loop {
+        for tx_hash in sent_hashes {
+            let Some(receipt) = provider.get_transaction_receipt(*tx_hash).await? else {
+                continue;
+            };
+            let Some(block_number) = receipt.block_number else {
+                continue;
+            };
+            let latest_block_number = provider.get_block_number().await?;
+            if latest_block_number + 1 >= block_number + policy.num_confirmations.max(1) {
+                return Ok(receipt);
+            }
+        }
+
+        if Instant::now() >= deadline {
+            return Err(ConfirmationTimeout {
+                tx_hash: sent_hashes.last().copied().unwrap_or_default(),
+                timeout: policy.timeout,
+            }
+            .into());
+        }
+        tokio::time::sleep(RECEIPT_POLL_INTERVAL).await;
+    }
+}
+
+/// Bumps the fees of `transaction_request` by `GAS_BUMP_PERCENT`, with the max fee per gas capped
+/// at `max_fee_per_gas_gwei`, so that it replaces the pending transaction with the same nonce.
+///
+/// Returns `false` if the cap leaves less of a bump than nodes accept for a replacement, in which
+/// case the fees are left unchanged.
+async fn bump_fees(
+    provider: &L1Provider,
+    transaction_request: &mut TransactionRequest,
+    max_fee_per_gas_gwei: u64,
+) -> Result<bool> {
+    let (max_fee_per_gas, max_priority_fee_per_gas) =
+        match (transaction_request.max_fee_per_gas, transaction_request.max_priority_fee_per_gas) {
+            (Some(max_fee), Some(max_priority_fee)) => (max_fee, max_priority_fee),
+            _ => {
+                let estimate = provider.estimate_eip1559_fees().await?;
+                (estimate.max_fee_per_gas, estimate.max_priority_fee_per_gas)
+            }
+        };
+
+    let max_fee_per_gas_ceiling = max_fee_per_gas_gwei as u128 * 1_000_000_000;
+    let bumped_max_fee_per_gas =
+        (max_fee_per_gas * GAS_BUMP_PERCENT / 100).min(max_fee_per_gas_ceiling);
+    let bumped_max_priority_fee_per_gas =
+        (max_priority_fee_per_gas * GAS_BUMP_PERCENT / 100).min(bumped_max_fee_per_gas);
+    if bumped_max_fee_per_gas * 100 < max_fee_per_gas * MIN_REPLACEMENT_BUMP_PERCENT ||
+        bumped_max_priority_fee_per_gas * 100
+            < max_priority_fee_per_gas * MIN_REPLACEMENT_BUMP_PERCENT
+    {
+        return Ok(false);
+    }
+
+    transaction_request.max_fee_per_gas = Some(bumped_max_fee_per_gas);
+    transaction_request.max_priority_fee_per_gas = Some(bumped_max_priority_fee_per_gas);
+    Ok(true)
+}
+
+/// Returns the nonce state shared by all transactions of the signer at `address`.
+fn signer_nonce(address: Address) -> Arc<Mutex<Option<u64>>> {
+    let nonces = SIGNER_NONCES.get_or_init(Default::default);
+    nonces.lock().unwrap().entry(address).or_default().clone()
+}
+
+/// Hands out the next nonce of the signer at `address`, which is its pending nonce unless a
+/// higher nonce was already handed out for a transaction that isn't pending yet.
+async fn next_managed_nonce(
+    signer_nonce: &Mutex<Option<u64>>,
+    provider: &L1Provider,
+    address: Address,
+) -> Result<u64> {
+    let mut next_nonce = signer_nonce.lock().await;
+    let pending_nonce = provider.get_transaction_count(address).pending().await?;
+    let nonce = next_nonce.map_or(pending_nonce, |next| next.max(pending_nonce));
+    *next_nonce = Some(nonce + 1);
+    Ok(nonce)
+}
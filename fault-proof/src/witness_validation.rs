@@ -0,0 +1,93 @@
+use alloy_primitives::{B256, U256};
+use anyhow::Result;
+use sp1_sdk::SP1Stdin;
+
+use crate::{L2Provider, L2ProviderTrait};
+
+/// The inputs of a range proof to validate before requesting the proof.
+#[derive(Debug, Clone)]
+pub struct ProofInputs<'a> {
+    /// The L2 block the range starts from, whose output root is agreed upon.
+    pub l2_start_block: u64,
+    /// The L2 block the range ends at.
+    pub l2_end_block: u64,
+    /// The L1 head used to derive the range.
+    pub l1_head: B256,
+    /// The output root the range is expected to start from.
+    pub expected_agreed_output_root: B256,
+    /// The stdin that will be sent to the prover.
+    pub sp1_stdin: &'a SP1Stdin,
+}
+
+/// The results of the individual witness validation checks.
+#[derive(Debug, Clone)]
+pub struct WitnessValidationReport {
+    /// Whether the L2 block range is non-empty.
+    pub block_range_non_empty: bool,
+    /// Whether the L1 head is set.
+    pub l1_head_non_zero: bool,
+    /// Whether the output root at the start of the range matches the expected agreed output root.
+    pub agreed_output_root_matches: bool,
+    /// The total size of the stdin buffers, in bytes.
+    pub stdin_size_bytes: usize,
+    /// Whether the stdin is below the configured maximum size.
+    pub stdin_size_within_limit: bool,
+}
+
+impl WitnessValidationReport {
+    /// Whether all checks passed.
+    pub fn is_valid(&self) -> bool {
+        self.block_range_non_empty &&
+            self.l1_head_non_zero &&
+            self.agreed_output_root_matches &&
+            self.stdin_size_within_limit
+    }
+
+    /// The names of the checks that failed.
+    pub fn failed_checks(&self) -> Vec<&'static str> {
+        [
+            (self.block_range_non_empty, "block_range_non_empty"),
+            (self.l1_head_non_zero, "l1_head_non_zero"),
+            (self.agreed_output_root_matches, "agreed_output_root_matches"),
+            (self.stdin_size_within_limit, "stdin_size_within_limit"),
+        ]
+        .into_iter()
+        .filter(|(passed, _)| !passed)
+        .map(|(_, name)| name)
+        .collect()
+    }
+}
+
+/// Checks range proof inputs for known-bad patterns before they are submitted to the prover
+/// network, where a malformed witness wastes quota.
+#[derive(Debug, Clone)]
+pub struct ProofInputValidator {
+    l2_provider: L2Provider,
+    max_stdin_bytes: usize,
+}
+
+impl ProofInputValidator {
+    pub fn new(l2_provider: L2Provider, max_stdin_bytes: usize) -> Self {
+        Self { l2_provider, max_stdin_bytes }
+    }
+
+    /// Runs all checks against the given proof inputs.
+    pub async fn validate_witness_data(
+        &self,
+        inputs: &ProofInputs<'_>,
+    ) -> Result<WitnessValidationReport> {
+        let agreed_output_root = self
+            .l2_provider
+            .compute_output_root_at_block(U256::from(inputs.l2_start_block))
+            .await?;
+        let stdin_size_bytes = inputs.sp1_stdin.buffer.iter().map(Vec::len).sum();
+
+        Ok(WitnessValidationReport {
+            block_range_non_empty: inputs.l2_end_block > inputs.l2_start_block,
+            l1_head_non_zero: inputs.l1_head != B256::ZERO,
+            agreed_output_root_matches: agreed_output_root == inputs.expected_agreed_output_root,
+            stdin_size_bytes,
+            stdin_size_within_limit: stdin_size_bytes <= self.max_stdin_bytes,
+        })
+    }
+}
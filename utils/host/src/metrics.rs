@@ -4,8 +4,8 @@ use std::{
     time::Duration,
 };
 
-use metrics::{describe_gauge, gauge};
-use metrics_exporter_prometheus::PrometheusBuilder;
+use metrics::{describe_gauge, describe_histogram, gauge, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use metrics_process::Collector;
 use strum::{EnumMessage, IntoEnumIterator};
 use tracing::warn;
@@ -42,8 +42,47 @@ pub trait MetricsGauge: Sized + IntoEnumIterator + EnumMessage + ToString {
     }
 }
 
+/// Trait for metrics histogram that provides common functionality.
+pub trait MetricsHistogram: Sized + IntoEnumIterator + EnumMessage + ToString {
+    /// The upper bounds of the histogram buckets.
+    fn buckets(&self) -> &'static [f64];
+
+    /// Describe the histogram metric.
+    fn describe(&self) {
+        describe_histogram!(self.to_string(), self.get_message().unwrap());
+    }
+
+    /// Record an observation.
+    fn record(&self, value: f64) {
+        histogram!(self.to_string()).record(value);
+    }
+
+    /// Register all histograms.
+    fn register_all() {
+        for metric in Self::iter() {
+            metric.describe();
+        }
+    }
+}
+
 pub fn init_metrics(port: &u16) {
-    let builder = PrometheusBuilder::new().with_http_listener(SocketAddr::new(
+    install_metrics(port, PrometheusBuilder::new());
+}
+
+/// Like [`init_metrics`], but exports the histograms of `H` with their buckets. Histograms without
+/// configured buckets are exported as summaries.
+pub fn init_metrics_with_histograms<H: MetricsHistogram>(port: &u16) {
+    let mut builder = PrometheusBuilder::new();
+    for metric in H::iter() {
+        builder = builder
+            .set_buckets_for_metric(Matcher::Full(metric.to_string()), metric.buckets())
+            .expect("histogram buckets must not be empty");
+    }
+    install_metrics(port, builder);
+}
+
+fn install_metrics(port: &u16, builder: PrometheusBuilder) {
+    let builder = builder.with_http_listener(SocketAddr::new(
         IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
         port.to_owned(),
     ));
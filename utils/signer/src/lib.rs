@@ -1,9 +1,9 @@
 use std::str::FromStr;
 
-use alloy_consensus::TxEnvelope;
+use alloy_consensus::{Transaction, TxEnvelope};
 use alloy_eips::Decodable2718;
 use alloy_network::{Ethereum, EthereumWallet, TransactionBuilder};
-use alloy_primitives::{Address, Bytes};
+use alloy_primitives::{Address, Bytes, TxHash};
 use alloy_provider::{Provider, ProviderBuilder, Web3Signer};
 use alloy_rpc_types_eth::{TransactionReceipt, TransactionRequest};
 use alloy_signer_local::PrivateKeySigner;
@@ -54,15 +54,78 @@ impl Signer {
     pub async fn send_transaction_request(
         &self,
         l1_rpc: Url,
-        mut transaction_request: TransactionRequest,
+        transaction_request: TransactionRequest,
     ) -> Result<TransactionReceipt> {
+        self.send_transaction_request_with_confirmations(
+            l1_rpc,
+            transaction_request,
+            NUM_CONFIRMATIONS,
+            Duration::from_secs(TIMEOUT_SECONDS),
+        )
+        .await
+    }
+
+    /// Sends a transaction request, signed by the configured `signer`, and waits up to `timeout`
+    /// for `num_confirmations` confirmations.
+    pub async fn send_transaction_request_with_confirmations(
+        &self,
+        l1_rpc: Url,
+        transaction_request: TransactionRequest,
+        num_confirmations: u64,
+        timeout: Duration,
+    ) -> Result<TransactionReceipt> {
+        let tx_envelope = self.sign_transaction_request(&l1_rpc, transaction_request).await?;
+        let provider =
+            ProviderBuilder::new().network::<Ethereum>().connect(l1_rpc.as_str()).await?;
+
+        let receipt = provider
+            .send_tx_envelope(tx_envelope)
+            .await
+            .context("Failed to send transaction")?
+            .with_required_confirmations(num_confirmations)
+            .with_timeout(Some(timeout))
+            .get_receipt()
+            .await?;
+
+        Ok(receipt)
+    }
+
+    /// Sends a transaction request, signed by the configured `signer`, without waiting for it to
+    /// be included.
+    ///
+    /// Returns the hash and the nonce of the sent transaction, so that the caller can wait for it
+    /// or replace it.
+    pub async fn broadcast_transaction_request(
+        &self,
+        l1_rpc: Url,
+        transaction_request: TransactionRequest,
+    ) -> Result<(TxHash, u64)> {
+        let tx_envelope = self.sign_transaction_request(&l1_rpc, transaction_request).await?;
+        let nonce = tx_envelope.nonce();
+        let provider =
+            ProviderBuilder::new().network::<Ethereum>().connect(l1_rpc.as_str()).await?;
+
+        let pending =
+            provider.send_tx_envelope(tx_envelope).await.context("Failed to send transaction")?;
+
+        Ok((*pending.tx_hash(), nonce))
+    }
+
+    /// Fills the transaction request with the gas and nonce information it's missing, and signs
+    /// it with the configured `signer`.
+    async fn sign_transaction_request(
+        &self,
+        l1_rpc: &Url,
+        mut transaction_request: TransactionRequest,
+    ) -> Result<TxEnvelope> {
         match self {
             Signer::Web3Signer(signer_url, signer_address) => {
                 // Set the from address to the signer address.
                 transaction_request.set_from(*signer_address);
 
                 // Fill the transaction request with all of the relevant gas and nonce information.
-                let provider = ProviderBuilder::new().network::<Ethereum>().connect_http(l1_rpc);
+                let provider =
+                    ProviderBuilder::new().network::<Ethereum>().connect(l1_rpc.as_str()).await?;
                 let filled_tx = provider.fill(transaction_request).await?;
 
                 // Sign the transaction request using the Web3Signer.
@@ -76,24 +139,14 @@ impl Signer {
                 let raw: Bytes =
                     signer.provider().client().request("eth_signTransaction", (tx,)).await?;
 
-                let tx_envelope = TxEnvelope::decode_2718(&mut raw.as_ref()).unwrap();
-
-                let receipt = provider
-                    .send_tx_envelope(tx_envelope)
-                    .await
-                    .context("Failed to send transaction")?
-                    .with_required_confirmations(NUM_CONFIRMATIONS)
-                    .with_timeout(Some(Duration::from_secs(TIMEOUT_SECONDS)))
-                    .get_receipt()
-                    .await?;
-
-                Ok(receipt)
+                Ok(TxEnvelope::decode_2718(&mut raw.as_ref()).unwrap())
             }
             Signer::LocalSigner(private_key) => {
                 let provider = ProviderBuilder::new()
                     .network::<Ethereum>()
                     .wallet(EthereumWallet::new(private_key.clone()))
-                    .connect_http(l1_rpc);
+                    .connect(l1_rpc.as_str())
+                    .await?;
 
                 // Set the from address to the Ethereum wallet address.
                 transaction_request.set_from(private_key.address());
@@ -101,16 +154,7 @@ impl Signer {
                 // Fill the transaction request with all of the relevant gas and nonce information.
                 let filled_tx = provider.fill(transaction_request).await?;
 
-                let receipt = provider
-                    .send_tx_envelope(filled_tx.as_envelope().unwrap().clone())
-                    .await
-                    .context("Failed to send transaction")?
-                    .with_required_confirmations(NUM_CONFIRMATIONS)
-                    .with_timeout(Some(Duration::from_secs(TIMEOUT_SECONDS)))
-                    .get_receipt()
-                    .await?;
-
-                Ok(receipt)
+                Ok(filled_tx.as_envelope().unwrap().clone())
             }
         }
     }